@@ -15,7 +15,9 @@
 use std::borrow::Cow;
 
 use itertools::Itertools;
-use risingwave_pb::plan_common::{GeneratedColumnDesc, PbColumnCatalog, PbColumnDesc};
+use risingwave_pb::plan_common::{
+    DefaultColumnDesc, GeneratedColumnDesc, PbColumnCatalog, PbColumnDesc,
+};
 
 use super::row_id_column_desc;
 use crate::catalog::{Field, ROW_ID_COLUMN_ID};
@@ -94,6 +96,7 @@ pub struct ColumnDesc {
     pub field_descs: Vec<ColumnDesc>,
     pub type_name: String,
     pub generated_column: Option<GeneratedColumnDesc>,
+    pub default_column: Option<DefaultColumnDesc>,
 }
 
 impl ColumnDesc {
@@ -105,6 +108,7 @@ impl ColumnDesc {
             field_descs: vec![],
             type_name: String::new(),
             generated_column: None,
+            default_column: None,
         }
     }
 
@@ -122,6 +126,7 @@ impl ColumnDesc {
                 .collect_vec(),
             type_name: self.type_name.clone(),
             generated_column: self.generated_column.clone(),
+            default_column: self.default_column.clone(),
         }
     }
 
@@ -165,6 +170,7 @@ impl ColumnDesc {
             field_descs: vec![],
             type_name: "".to_string(),
             generated_column: None,
+            default_column: None,
         }
     }
 
@@ -185,6 +191,7 @@ impl ColumnDesc {
             field_descs: fields,
             type_name: type_name.to_string(),
             generated_column: None,
+            default_column: None,
         }
     }
 
@@ -200,6 +207,7 @@ impl ColumnDesc {
                 .collect_vec(),
             type_name: field.type_name.clone(),
             generated_column: None,
+            default_column: None,
         }
     }
 
@@ -210,6 +218,10 @@ impl ColumnDesc {
     pub fn is_generated(&self) -> bool {
         self.generated_column.is_some()
     }
+
+    pub fn is_default(&self) -> bool {
+        self.default_column.is_some()
+    }
 }
 
 impl From<PbColumnDesc> for ColumnDesc {
@@ -226,6 +238,7 @@ impl From<PbColumnDesc> for ColumnDesc {
             type_name: prost.type_name,
             field_descs,
             generated_column: prost.generated_column,
+            default_column: prost.default_column,
         }
     }
 }
@@ -245,6 +258,7 @@ impl From<&ColumnDesc> for PbColumnDesc {
             field_descs: c.field_descs.iter().map(ColumnDesc::to_protobuf).collect(),
             type_name: c.type_name.clone(),
             generated_column: c.generated_column.clone(),
+            default_column: c.default_column.clone(),
         }
     }
 }
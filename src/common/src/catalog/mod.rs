@@ -90,6 +90,7 @@ pub fn row_id_column_desc() -> ColumnDesc {
         field_descs: vec![],
         type_name: "".to_string(),
         generated_column: None,
+        default_column: None,
     }
 }
 
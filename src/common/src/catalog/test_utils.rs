@@ -57,6 +57,7 @@ impl ColumnDescTestExt for ColumnDesc {
             type_name: type_name.to_string(),
             field_descs: fields,
             generated_column: None,
+            default_column: None,
         }
     }
 }
@@ -126,6 +126,7 @@ pub enum MetaBackend {
     #[default]
     Mem,
     Etcd,
+    Sql,
 }
 
 /// The section `[meta]` in `risingwave.toml`.
@@ -203,6 +204,24 @@ pub struct MetaConfig {
     #[serde(default = "default::meta::max_compactor_task_multiplier")]
     pub max_compactor_task_multiplier: u32,
 
+    /// Schedule a full GC (object store listing diffed against the hummock version) with this
+    /// interval, in addition to the metadata-only GC driven by `vacuum_interval_sec`.
+    #[serde(default = "default::meta::full_gc_interval_sec")]
+    pub full_gc_interval_sec: u64,
+
+    /// The window, in seconds, for which version deltas are retained even past the point
+    /// `delete_version_deltas` would otherwise vacuum them, so a historical hummock version can
+    /// still be reconstructed for time-travel reads. 0 disables the extra retention.
+    #[serde(default = "default::meta::min_version_retention_duration_sec")]
+    pub min_version_retention_duration_sec: u64,
+
+    /// Whether to disable the automatic expansion of existing streaming jobs onto a compute node
+    /// as soon as it joins the cluster. When disabled (the default), a node joining or rejoining
+    /// (e.g. after a transient network blip) never triggers an unprompted cluster-wide
+    /// reschedule; use `risectl` to rebalance explicitly instead.
+    #[serde(default)]
+    pub disable_automatic_parallelism_control: bool,
+
     #[serde(default, flatten)]
     pub unrecognized: HashMap<String, Value>,
 }
@@ -230,6 +249,20 @@ pub struct ServerConfig {
     #[serde(default = "default::server::telemetry_enabled")]
     pub telemetry_enabled: bool,
 
+    /// The path of the TLS certificate used to serve TLS-encrypted connections to the frontend.
+    /// TLS is disabled if this or `ssl_key` is not set.
+    #[serde(default)]
+    pub ssl_cert: Option<String>,
+
+    /// The path of the private key matching `ssl_cert`.
+    #[serde(default)]
+    pub ssl_key: Option<String>,
+
+    /// The path of the CA certificate used to verify client certificates. If set, clients are
+    /// required to present a certificate signed by this CA when connecting over TLS.
+    #[serde(default)]
+    pub ssl_ca_cert: Option<String>,
+
     #[serde(default, flatten)]
     pub unrecognized: HashMap<String, Value>,
 }
@@ -561,6 +594,14 @@ mod default {
         pub fn max_compactor_task_multiplier() -> u32 {
             2
         }
+
+        pub fn full_gc_interval_sec() -> u64 {
+            3600 // 60min
+        }
+
+        pub fn min_version_retention_duration_sec() -> u64 {
+            3600 // 1h
+        }
     }
 
     pub mod server {
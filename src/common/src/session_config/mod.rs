@@ -34,7 +34,7 @@ use crate::util::epoch::Epoch;
 
 // This is a hack, &'static str is not allowed as a const generics argument.
 // TODO: refine this using the adt_const_params feature.
-const CONFIG_KEYS: [&str; 22] = [
+const CONFIG_KEYS: [&str; 23] = [
     "RW_IMPLICIT_FLUSH",
     "CREATE_COMPACTION_GROUP_FOR_MV",
     "QUERY_MODE",
@@ -57,6 +57,7 @@ const CONFIG_KEYS: [&str; 22] = [
     "INTERVALSTYLE",
     "BATCH_PARALLELISM",
     "RW_STREAMING_ENABLE_BUSHY_JOIN",
+    "QUERY_TIMEOUT",
 ];
 
 // MUST HAVE 1v1 relationship to CONFIG_KEYS. e.g. CONFIG_KEYS[IMPLICIT_FLUSH] =
@@ -83,6 +84,7 @@ const RW_ENABLE_SHARE_PLAN: usize = 18;
 const INTERVAL_STYLE: usize = 19;
 const BATCH_PARALLELISM: usize = 20;
 const STREAMING_ENABLE_BUSHY_JOIN: usize = 21;
+const QUERY_TIMEOUT: usize = 22;
 
 trait ConfigEntry: Default + for<'a> TryFrom<&'a [&'a str], Error = RwError> {
     fn entry_name() -> &'static str;
@@ -285,6 +287,7 @@ type ForceTwoPhaseAgg = ConfigBool<FORCE_TWO_PHASE_AGG, false>;
 type EnableSharePlan = ConfigBool<RW_ENABLE_SHARE_PLAN, true>;
 type IntervalStyle = ConfigString<INTERVAL_STYLE>;
 type BatchParallelism = ConfigU64<BATCH_PARALLELISM, 0>;
+type QueryTimeout = ConfigU64<QUERY_TIMEOUT, 0>;
 
 #[derive(Derivative)]
 #[derivative(Default)]
@@ -366,6 +369,9 @@ pub struct ConfigMap {
     interval_style: IntervalStyle,
 
     batch_parallelism: BatchParallelism,
+
+    /// The max allowed running time for a query in seconds. If 0, there's no timeout.
+    query_timeout: QueryTimeout,
 }
 
 impl ConfigMap {
@@ -426,6 +432,8 @@ impl ConfigMap {
             self.interval_style = val.as_slice().try_into()?;
         } else if key.eq_ignore_ascii_case(BatchParallelism::entry_name()) {
             self.batch_parallelism = val.as_slice().try_into()?;
+        } else if key.eq_ignore_ascii_case(QueryTimeout::entry_name()) {
+            self.query_timeout = val.as_slice().try_into()?;
         } else {
             return Err(ErrorCode::UnrecognizedConfigurationParameter(key.to_string()).into());
         }
@@ -478,6 +486,8 @@ impl ConfigMap {
             Ok(self.interval_style.to_string())
         } else if key.eq_ignore_ascii_case(BatchParallelism::entry_name()) {
             Ok(self.batch_parallelism.to_string())
+        } else if key.eq_ignore_ascii_case(QueryTimeout::entry_name()) {
+            Ok(self.query_timeout.to_string())
         } else {
             Err(ErrorCode::UnrecognizedConfigurationParameter(key.to_string()).into())
         }
@@ -590,6 +600,11 @@ impl ConfigMap {
                 setting : self.batch_parallelism.to_string(),
                 description: String::from("Sets the parallelism for batch. If 0, use default value.")
             },
+            VariableInfo{
+                name : QueryTimeout::entry_name().to_lowercase(),
+                setting : self.query_timeout.to_string(),
+                description: String::from("Sets the maximum allowed time in seconds for a single query to run. If 0, there's no timeout.")
+            },
         ]
     }
 
@@ -689,4 +704,9 @@ impl ConfigMap {
         }
         None
     }
+
+    /// Returns the query timeout in seconds. `0` means there's no timeout.
+    pub fn get_query_timeout(&self) -> u64 {
+        self.query_timeout.0
+    }
 }
@@ -375,6 +375,32 @@ impl Interval {
         self > &Self::from_month_day_usec(0, 0, 0)
     }
 
+    /// Folds `usecs` into whole days, following PostgreSQL's `justify_hours`. Leaves `months`
+    /// untouched.
+    pub fn justify_hours(&self) -> Option<Self> {
+        let extra_days: i32 = (self.usecs / USECS_PER_DAY).try_into().ok()?;
+        let usecs = self.usecs % USECS_PER_DAY;
+        let days = self.days.checked_add(extra_days)?;
+        Some(Self::from_month_day_usec(self.months, days, usecs))
+    }
+
+    /// Folds `days` into whole months (of 30 days each), following PostgreSQL's `justify_days`.
+    /// Leaves `usecs` untouched.
+    pub fn justify_days(&self) -> Option<Self> {
+        let extra_months = self.days / 30;
+        let days = self.days % 30;
+        let months = self.months.checked_add(extra_months)?;
+        Some(Self::from_month_day_usec(months, days, self.usecs))
+    }
+
+    /// Normalizes the interval so that `months`, `days` and `usecs` all share the same sign,
+    /// following PostgreSQL's `justify_interval`. Equivalent to `justify_hours` followed by
+    /// `justify_days`, with any resulting sign mismatch between `months` and `days` resolved by
+    /// further borrowing. Returns [`None`] on overflow.
+    pub fn justify_interval(&self) -> Option<Self> {
+        IntervalCmpValue::from(*self).as_justified()
+    }
+
     /// Truncate the interval to the precision of milliseconds.
     ///
     /// # Example
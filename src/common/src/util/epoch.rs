@@ -78,6 +78,12 @@ impl Epoch {
         UNIX_RISINGWAVE_DATE_SEC * 1000 + self.physical_time()
     }
 
+    /// Builds an [`Epoch`] whose physical time corresponds to the given number of milliseconds
+    /// since the Unix epoch. The inverse of [`Epoch::as_unix_millis`].
+    pub fn from_unix_millis(unix_millis: u64) -> Self {
+        Self::from_physical_time(unix_millis.saturating_sub(UNIX_RISINGWAVE_DATE_SEC * 1000))
+    }
+
     /// Returns the epoch in real system time.
     pub fn as_system_time(&self) -> SystemTime {
         *UNIX_RISINGWAVE_DATE_EPOCH + Duration::from_millis(self.physical_time())
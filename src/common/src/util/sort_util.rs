@@ -198,6 +198,22 @@ impl OrderType {
     pub fn nulls_are_last(&self) -> bool {
         !self.nulls_are_first()
     }
+
+    /// Returns the order type that produces the exact reverse ordering of `self`, i.e. flips
+    /// `ASC`/`DESC` while keeping nulls on the same side (first/last). Used e.g. to turn a
+    /// "pick the first row in this order" aggregation into a "pick the last row" one.
+    pub fn reverse(&self) -> Self {
+        let nulls_first = self.nulls_are_first();
+        let direction = match self.direction {
+            Direction::Ascending => Direction::Descending,
+            Direction::Descending => Direction::Ascending,
+        };
+        if nulls_first {
+            Self::nulls_first(direction)
+        } else {
+            Self::nulls_last(direction)
+        }
+    }
 }
 
 impl fmt::Display for OrderType {
@@ -206,6 +206,9 @@ pub async fn compute_node_serve(
             let read_memory_limiter = Arc::new(MemoryLimiter::new(
                 storage_opts.compactor_memory_limit_mb as u64 * 1024 * 1024 / 2,
             ));
+            let memory_limiter = Arc::new(MemoryLimiter::new(
+                storage_opts.compactor_memory_limit_mb as u64 * 1024 * 1024 / 2,
+            ));
             let compactor_context = Arc::new(CompactorContext {
                 storage_opts,
                 hummock_meta_client: hummock_meta_client.clone(),
@@ -215,6 +218,7 @@ pub async fn compute_node_serve(
                 compaction_executor: Arc::new(CompactionExecutor::new(Some(1))),
                 filter_key_extractor_manager: storage.filter_key_extractor_manager().clone(),
                 read_memory_limiter,
+                memory_limiter,
                 sstable_object_id_manager: storage.sstable_object_id_manager().clone(),
                 task_progress_manager: Default::default(),
                 compactor_runtime_config: Arc::new(tokio::sync::Mutex::new(
@@ -153,6 +153,7 @@ async fn test_table_materialize() -> StreamResult<()> {
             field_descs: vec![],
             type_name: "".to_string(),
             generated_column: None,
+            default_column: None,
         })
         .collect_vec();
     let (barrier_tx, barrier_rx) = unbounded_channel();
@@ -48,6 +48,7 @@ pub(crate) fn avro_field_to_column_desc(
                 field_descs: vec_column,
                 type_name: schema_name.to_string(),
                 generated_column: None,
+                default_column: None,
             })
         }
         _ => {
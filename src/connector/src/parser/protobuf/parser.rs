@@ -155,6 +155,7 @@ impl ProtobufParserConfig {
                 field_descs,
                 type_name: m.full_name().to_string(),
                 generated_column: None,
+                default_column: None,
             })
         } else {
             *index += 1;
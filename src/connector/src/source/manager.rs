@@ -83,6 +83,7 @@ impl From<&SourceColumnDesc> for ColumnDesc {
             field_descs: s.fields.clone(),
             type_name: "".to_string(),
             generated_column: None,
+            default_column: None,
         }
     }
 }
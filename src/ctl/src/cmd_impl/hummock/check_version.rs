@@ -0,0 +1,124 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use risingwave_hummock_sdk::compaction_group::hummock_version_ext::HummockVersionExt;
+use risingwave_hummock_sdk::key_range::KeyRangeCommon;
+use risingwave_hummock_sdk::HummockSstableObjectId;
+use risingwave_pb::hummock::{HummockVersion, LevelType};
+
+use crate::common::HummockServiceOpts;
+use crate::CtlContext;
+
+/// Validates a pinned Hummock version against object store reality, reporting a
+/// machine-readable list of findings instead of panicking, so operators (or scripts) can decide
+/// how to react.
+pub async fn check_version(context: &CtlContext, data_dir: Option<String>) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    let version = meta_client.get_current_version().await?;
+
+    let mut issues = check_key_range_overlap(&version);
+    issues.extend(check_monotonicity(&version));
+    issues.extend(check_object_store(&version, data_dir).await?);
+
+    if issues.is_empty() {
+        println!("OK: version {} passed all consistency checks", version.id);
+    } else {
+        println!(
+            "FOUND {} issue(s) in version {}:",
+            issues.len(),
+            version.id
+        );
+        for issue in &issues {
+            println!("- {issue}");
+        }
+    }
+    Ok(())
+}
+
+/// Within a non-overlapping level, SSTs must be sorted by key range and must not overlap.
+fn check_key_range_overlap(version: &HummockVersion) -> Vec<String> {
+    let mut issues = vec![];
+    for (group_id, levels) in &version.levels {
+        for level in &levels.levels {
+            if level.level_type() != LevelType::Nonoverlapping {
+                continue;
+            }
+            for (prev, next) in level.table_infos.iter().zip(level.table_infos.iter().skip(1)) {
+                let prev_range = prev.key_range.as_ref().unwrap();
+                let next_range = next.key_range.as_ref().unwrap();
+                if prev_range.sstable_overlap(next_range) {
+                    issues.push(format!(
+                        "group {} level {}: sst {} and sst {} have overlapping key ranges in a non-overlapping level",
+                        group_id, level.level_idx, prev.sst_id, next.sst_id
+                    ));
+                }
+            }
+        }
+    }
+    issues
+}
+
+/// `safe_epoch` must never be ahead of `max_committed_epoch`.
+fn check_monotonicity(version: &HummockVersion) -> Vec<String> {
+    let mut issues = vec![];
+    if version.safe_epoch > version.max_committed_epoch {
+        issues.push(format!(
+            "safe_epoch {} is greater than max_committed_epoch {}",
+            version.safe_epoch, version.max_committed_epoch
+        ));
+    }
+    issues
+}
+
+/// Every SST referenced by the version must exist in the object store with a matching file size.
+async fn check_object_store(
+    version: &HummockVersion,
+    data_dir: Option<String>,
+) -> anyhow::Result<Vec<String>> {
+    let mut issues = vec![];
+    let sstable_store = HummockServiceOpts::from_env(data_dir)?
+        .create_sstable_store()
+        .await?;
+
+    let actual_sizes: HashMap<HummockSstableObjectId, u64> = sstable_store
+        .list_ssts_from_object_store()
+        .await?
+        .into_iter()
+        .map(|obj| {
+            (
+                sstable_store.get_object_id_from_path(&obj.key),
+                obj.total_size as u64,
+            )
+        })
+        .collect();
+
+    for level in version.get_combined_levels() {
+        for sst in &level.table_infos {
+            match actual_sizes.get(&sst.object_id) {
+                None => issues.push(format!(
+                    "sst {} (object id {}) is referenced by the version but missing from object store",
+                    sst.sst_id, sst.object_id
+                )),
+                Some(actual_size) if *actual_size != sst.file_size => issues.push(format!(
+                    "sst {} (object id {}) has file_size {} in the version but {} in object store",
+                    sst.sst_id, sst.object_id, sst.file_size, actual_size
+                )),
+                Some(_) => {}
+            }
+        }
+    }
+    Ok(issues)
+}
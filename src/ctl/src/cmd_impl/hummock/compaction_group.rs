@@ -52,6 +52,7 @@ pub fn build_compaction_config_vec(
     compaction_filter_mask: Option<u32>,
     max_sub_compaction: Option<u32>,
     level0_stop_write_threshold_sub_level_number: Option<u64>,
+    compression_algorithm: Option<Vec<String>>,
 ) -> Vec<MutableConfig> {
     let mut configs = vec![];
     if let Some(c) = max_bytes_for_level_base {
@@ -81,6 +82,13 @@ pub fn build_compaction_config_vec(
     if let Some(c) = level0_stop_write_threshold_sub_level_number {
         configs.push(MutableConfig::Level0StopWriteThresholdSubLevelNumber(c));
     }
+    if let Some(c) = compression_algorithm {
+        configs.push(MutableConfig::CompressionAlgorithm(
+            risingwave_pb::hummock::rise_ctl_update_compaction_config_request::CompressionAlgorithm {
+                compression_algorithm: c,
+            },
+        ));
+    }
     configs
 }
 
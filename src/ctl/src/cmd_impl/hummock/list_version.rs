@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use itertools::Itertools;
 use risingwave_pb::hummock::{PinnedSnapshotsSummary, PinnedVersionsSummary};
 use risingwave_rpc_client::HummockMetaClient;
 
@@ -87,3 +88,53 @@ pub async fn list_pinned_snapshots(context: &CtlContext) -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+pub async fn list_compact_tasks(context: &CtlContext) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    let task_assignments = meta_client.risectl_list_compact_tasks().await?;
+    for assignment in task_assignments {
+        let task = assignment.compact_task.unwrap();
+        println!(
+            "task_id {} compaction_group_id {} target_level {} input_ssts {} compactor_context_id {}",
+            task.task_id,
+            task.compaction_group_id,
+            task.target_level,
+            task.input_ssts
+                .iter()
+                .flat_map(|level| level.table_infos.iter().map(|sst| sst.sst_id))
+                .join(", "),
+            assignment.context_id
+        );
+    }
+    Ok(())
+}
+
+pub async fn list_compact_task_progress(context: &CtlContext) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    let task_progress = meta_client.list_compact_task_progress().await?;
+    for progress in task_progress {
+        println!(
+            "task_id {} num_ssts_sealed {} num_ssts_uploaded {} num_bytes_read {} num_bytes_sealed {}",
+            progress.task_id,
+            progress.num_ssts_sealed,
+            progress.num_ssts_uploaded,
+            progress.num_bytes_read,
+            progress.num_bytes_sealed
+        );
+    }
+    Ok(())
+}
+
+pub async fn get_scale_compactor(context: &CtlContext) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    let resp = meta_client.get_scale_compactor().await?;
+    println!(
+        "suggest_cores {} smoothed_suggest_cores {:.2} running_cores {} total_cores {} waiting_compaction_bytes {}",
+        resp.suggest_cores,
+        resp.smoothed_suggest_cores,
+        resp.running_cores,
+        resp.total_cores,
+        resp.waiting_compaction_bytes
+    );
+    Ok(())
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use risingwave_pb::hummock::KeyRange;
 use risingwave_rpc_client::HummockMetaClient;
 
 use crate::CtlContext;
@@ -21,10 +22,24 @@ pub async fn trigger_manual_compaction(
     compaction_group_id: u64,
     table_id: u32,
     level: u32,
+    sst_ids: Vec<u64>,
+    key_range_left: Option<String>,
+    key_range_right: Option<String>,
+    key_range_right_exclusive: bool,
 ) -> anyhow::Result<()> {
+    let key_range = match (key_range_left, key_range_right) {
+        (Some(left), Some(right)) => Some(KeyRange {
+            left: hex::decode(left)?,
+            right: hex::decode(right)?,
+            right_exclusive: key_range_right_exclusive,
+        }),
+        (None, None) => None,
+        _ => anyhow::bail!("--key-range-left and --key-range-right must be set together"),
+    };
+
     let meta_client = context.meta_client().await?;
     let result = meta_client
-        .trigger_manual_compaction(compaction_group_id, table_id, level)
+        .trigger_manual_compaction(compaction_group_id, table_id, level, sst_ids, key_range)
         .await;
     println!("{:#?}", result);
     Ok(())
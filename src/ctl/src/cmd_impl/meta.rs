@@ -17,9 +17,13 @@ mod cluster_info;
 mod connection;
 mod pause_resume;
 mod reschedule;
+mod restore_meta;
+mod telemetry;
 
 pub use backup_meta::*;
 pub use cluster_info::*;
 pub use connection::*;
 pub use pause_resume::*;
 pub use reschedule::*;
+pub use restore_meta::*;
+pub use telemetry::*;
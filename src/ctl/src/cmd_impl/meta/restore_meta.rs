@@ -0,0 +1,25 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_meta::backup_restore::RestoreOpts;
+
+/// Restores a meta store from a meta snapshot taken by `risectl meta backup-meta`.
+///
+/// Unlike other `risectl meta` commands, this doesn't talk to a running meta node: it bootstraps
+/// a brand new meta store directly, the same way the standalone `risingwave_backup_restore`
+/// binary does.
+pub async fn restore_meta(opts: RestoreOpts) -> anyhow::Result<()> {
+    risingwave_meta::backup_restore::restore(opts).await?;
+    Ok(())
+}
@@ -0,0 +1,25 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::CtlContext;
+
+pub async fn telemetry_preview(context: &CtlContext) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+
+    let report_json = meta_client.get_telemetry_report_preview().await?;
+    let report: serde_json::Value = serde_json::from_str(&report_json)?;
+    println!("{}", serde_json::to_string_pretty(&report)?);
+
+    Ok(())
+}
@@ -0,0 +1,174 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use bytes::Bytes;
+use futures::{pin_mut, StreamExt};
+use risingwave_common::row::Row as _;
+use risingwave_common::types::to_text::ToText;
+use risingwave_frontend::TableCatalog;
+use risingwave_hummock_sdk::HummockReadEpoch;
+use risingwave_object_store::object::{parse_remote_object_store, ObjectStoreImpl, ObjectStoreRef};
+use risingwave_storage::hummock::HummockStorage;
+use risingwave_storage::monitor::{MonitoredStateStore, ObjectStoreMetrics};
+use risingwave_storage::store::PrefetchOptions;
+
+use super::scan::{get_table_catalog, get_table_catalog_by_id, make_storage_table};
+use crate::common::HummockServiceOpts;
+use crate::CtlContext;
+
+/// Rows are batched into files of this size before being uploaded, so that a snapshot of a large
+/// MV doesn't have to be buffered into a single object.
+const ROWS_PER_PART: usize = 100_000;
+
+#[derive(serde::Serialize)]
+struct Manifest {
+    table_id: u32,
+    table_name: String,
+    /// The committed epoch the snapshot was taken at. Re-importing the part files reproduces
+    /// exactly the rows visible to a read at this epoch.
+    epoch: u64,
+    columns: Vec<String>,
+    /// Part file names, relative to the manifest, in the order they should be concatenated.
+    parts: Vec<String>,
+    row_count: u64,
+}
+
+pub async fn export(
+    context: &CtlContext,
+    mv_name: String,
+    target: String,
+    data_dir: Option<String>,
+) -> Result<()> {
+    let meta_client = context.meta_client().await?;
+    let hummock = context
+        .hummock_store(HummockServiceOpts::from_env(data_dir)?)
+        .await?;
+    let table = get_table_catalog(meta_client, mv_name).await?;
+    do_export(table, hummock, target).await
+}
+
+pub async fn export_by_id(
+    context: &CtlContext,
+    table_id: u32,
+    target: String,
+    data_dir: Option<String>,
+) -> Result<()> {
+    let meta_client = context.meta_client().await?;
+    let hummock = context
+        .hummock_store(HummockServiceOpts::from_env(data_dir)?)
+        .await?;
+    let table = get_table_catalog_by_id(meta_client, table_id).await?;
+    do_export(table, hummock, target).await
+}
+
+async fn do_export(
+    table: TableCatalog,
+    hummock: MonitoredStateStore<HummockStorage>,
+    target: String,
+) -> Result<()> {
+    let object_store: ObjectStoreRef = Arc::new(
+        parse_remote_object_store(
+            &target,
+            Arc::new(ObjectStoreMetrics::unused()),
+            "risectl-table-export",
+        )
+        .await,
+    );
+    let epoch = hummock.inner().get_pinned_version().max_committed_epoch();
+    let column_names = table
+        .columns()
+        .iter()
+        .map(|c| c.name().to_string())
+        .collect::<Vec<_>>();
+
+    let storage_table = make_storage_table(hummock, &table);
+    let stream = storage_table
+        .batch_iter(
+            HummockReadEpoch::Committed(epoch),
+            true,
+            PrefetchOptions::new_for_exhaust_iter(),
+        )
+        .await?;
+    pin_mut!(stream);
+
+    let mut parts = vec![];
+    let mut row_count = 0u64;
+    let mut part_buf = String::new();
+    let mut part_rows = 0usize;
+    while let Some(item) = stream.next().await {
+        let (_pk, row) = item?;
+        let line = row
+            .iter()
+            .map(|datum| datum.map(|d| d.to_text()).unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join(",");
+        part_buf.push_str(&line);
+        part_buf.push('\n');
+        part_rows += 1;
+        row_count += 1;
+
+        if part_rows >= ROWS_PER_PART {
+            upload_part(&object_store, &target, parts.len(), &part_buf).await?;
+            parts.push(part_file_name(parts.len()));
+            part_buf.clear();
+            part_rows = 0;
+        }
+    }
+    if part_rows > 0 {
+        upload_part(&object_store, &target, parts.len(), &part_buf).await?;
+        parts.push(part_file_name(parts.len()));
+    }
+
+    let manifest = Manifest {
+        table_id: table.id.table_id,
+        table_name: table.name.clone(),
+        epoch,
+        columns: column_names,
+        parts,
+        row_count,
+    };
+    let manifest_path = format!("{}/manifest.json", target);
+    object_store
+        .upload(
+            &manifest_path,
+            Bytes::from(serde_json::to_vec_pretty(&manifest)?),
+        )
+        .await?;
+
+    println!(
+        "exported {} rows of table {} (id {}) at epoch {} to {} ({})",
+        row_count, manifest.table_name, manifest.table_id, epoch, target, manifest_path
+    );
+    Ok(())
+}
+
+fn part_file_name(index: usize) -> String {
+    format!("part-{:05}.csv", index)
+}
+
+async fn upload_part(
+    object_store: &ObjectStoreImpl,
+    target: &str,
+    index: usize,
+    content: &str,
+) -> Result<()> {
+    let path = format!("{}/{}", target, part_file_name(index));
+    object_store
+        .upload(&path, Bytes::from(content.to_string()))
+        .await?;
+    Ok(())
+}
@@ -110,6 +110,24 @@ enum HummockCommands {
 
         #[clap(short, long = "level", default_value_t = 1)]
         level: u32,
+
+        /// Only include these SSTs as input, by sst id. Has no effect if empty.
+        #[clap(long, value_delimiter = ',')]
+        sst_ids: Vec<u64>,
+
+        /// Only include SSTs overlapping this left key (hex-encoded). Requires
+        /// `--key-range-right` to also be set.
+        #[clap(long)]
+        key_range_left: Option<String>,
+
+        /// Only include SSTs overlapping this right key (hex-encoded). Requires
+        /// `--key-range-left` to also be set.
+        #[clap(long)]
+        key_range_right: Option<String>,
+
+        /// Whether `--key-range-right` is inclusive.
+        #[clap(long)]
+        key_range_right_exclusive: bool,
     },
     /// trigger a full GC for SSTs that is not in version and with timestamp <= now -
     /// sst_retention_time_sec.
@@ -123,6 +141,22 @@ enum HummockCommands {
     ListPinnedSnapshots {},
     /// List all compaction groups.
     ListCompactionGroup,
+    /// List all in-progress compact tasks and the compactor each is assigned to.
+    ListCompactTasks,
+    /// List the latest reported progress (bytes read / written) of all in-progress compact
+    /// tasks.
+    ListCompactTaskProgress,
+    /// Show the outstanding compaction debt (pending bytes, suggested compactor scale-out core
+    /// count, raw and EMA-smoothed) that an external autoscaler could use to size the compactor
+    /// deployment.
+    GetScaleCompactor,
+    /// Check the current hummock version for inconsistencies: overlapping key ranges within a
+    /// non-overlapping level, epoch watermarks going backwards, and SSTs referenced by the
+    /// version that are missing or size-mismatched in the object store.
+    CheckVersion {
+        #[clap(short, long = "data-dir")]
+        data_dir: Option<String>,
+    },
     /// Update compaction config for compaction groups.
     UpdateCompactionConfig {
         #[clap(long)]
@@ -145,6 +179,9 @@ enum HummockCommands {
         max_sub_compaction: Option<u32>,
         #[clap(long)]
         level0_stop_write_threshold_sub_level_number: Option<u64>,
+        /// Compression algorithm per level, e.g. `--compression-algorithm None,None,None,Lz4,Lz4,Zstd,Zstd`.
+        #[clap(long, value_delimiter = ',')]
+        compression_algorithm: Option<Vec<String>>,
     },
     /// Split given compaction group into two. Moves the given tables to the new group.
     SplitCompactionGroup {
@@ -173,6 +210,26 @@ enum TableCommands {
     },
     /// list all state tables
     List,
+    /// Export a state table's contents at the latest checkpoint to object storage (or a local
+    /// directory, via `file://`), as a manifest plus a set of CSV part files, for offline
+    /// analysis and re-import.
+    Export {
+        /// name of the materialized view to operate on
+        mv_name: String,
+        /// object store url to export to, e.g. `s3://bucket/path/to/snapshot`
+        target: String,
+        // data directory for hummock state store. None: use default
+        data_dir: Option<String>,
+    },
+    /// Like `Export`, but the table is identified by id rather than name.
+    ExportById {
+        /// id of the state table to operate on
+        table_id: u32,
+        /// object store url to export to, e.g. `s3://bucket/path/to/snapshot`
+        target: String,
+        // data directory for hummock state store. None: use default
+        data_dir: Option<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -212,6 +269,11 @@ enum MetaCommands {
     BackupMeta,
     /// delete meta snapshots
     DeleteMetaSnapshots { snapshot_ids: Vec<u64> },
+    /// restore a meta store from a meta snapshot taken by `backup-meta`
+    RestoreMeta {
+        #[clap(flatten)]
+        opts: risingwave_meta::backup_restore::RestoreOpts,
+    },
 
     /// Create a new connection object
     CreateConnection {
@@ -233,6 +295,9 @@ enum MetaCommands {
         #[clap(long)]
         connection_name: String,
     },
+
+    /// Preview the telemetry report that would be uploaded next, without sending it anywhere
+    TelemetryPreview,
 }
 
 pub async fn start(opts: CliOpts) -> Result<()> {
@@ -273,12 +338,20 @@ pub async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
             compaction_group_id,
             table_id,
             level,
+            sst_ids,
+            key_range_left,
+            key_range_right,
+            key_range_right_exclusive,
         }) => {
             cmd_impl::hummock::trigger_manual_compaction(
                 context,
                 compaction_group_id,
                 table_id,
                 level,
+                sst_ids,
+                key_range_left,
+                key_range_right,
+                key_range_right_exclusive,
             )
             .await?
         }
@@ -294,6 +367,18 @@ pub async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
         Commands::Hummock(HummockCommands::ListCompactionGroup) => {
             cmd_impl::hummock::list_compaction_group(context).await?
         }
+        Commands::Hummock(HummockCommands::ListCompactTasks) => {
+            cmd_impl::hummock::list_compact_tasks(context).await?
+        }
+        Commands::Hummock(HummockCommands::ListCompactTaskProgress) => {
+            cmd_impl::hummock::list_compact_task_progress(context).await?
+        }
+        Commands::Hummock(HummockCommands::GetScaleCompactor) => {
+            cmd_impl::hummock::get_scale_compactor(context).await?
+        }
+        Commands::Hummock(HummockCommands::CheckVersion { data_dir }) => {
+            cmd_impl::hummock::check_version(context, data_dir).await?
+        }
         Commands::Hummock(HummockCommands::UpdateCompactionConfig {
             compaction_group_ids,
             max_bytes_for_level_base,
@@ -305,6 +390,7 @@ pub async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
             compaction_filter_mask,
             max_sub_compaction,
             level0_stop_write_threshold_sub_level_number,
+            compression_algorithm,
         }) => {
             cmd_impl::hummock::update_compaction_config(
                 context,
@@ -319,6 +405,7 @@ pub async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
                     compaction_filter_mask,
                     max_sub_compaction,
                     level0_stop_write_threshold_sub_level_number,
+                    compression_algorithm,
                 ),
             )
             .await?
@@ -337,6 +424,16 @@ pub async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
             cmd_impl::table::scan_id(context, table_id, data_dir).await?
         }
         Commands::Table(TableCommands::List) => cmd_impl::table::list(context).await?,
+        Commands::Table(TableCommands::Export {
+            mv_name,
+            target,
+            data_dir,
+        }) => cmd_impl::table::export(context, mv_name, target, data_dir).await?,
+        Commands::Table(TableCommands::ExportById {
+            table_id,
+            target,
+            data_dir,
+        }) => cmd_impl::table::export_by_id(context, table_id, target, data_dir).await?,
         Commands::Bench(cmd) => cmd_impl::bench::do_bench(context, cmd).await?,
         Commands::Meta(MetaCommands::Pause) => cmd_impl::meta::pause(context).await?,
         Commands::Meta(MetaCommands::Resume) => cmd_impl::meta::resume(context).await?,
@@ -351,6 +448,9 @@ pub async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
         Commands::Meta(MetaCommands::DeleteMetaSnapshots { snapshot_ids }) => {
             cmd_impl::meta::delete_meta_snapshots(context, &snapshot_ids).await?
         }
+        Commands::Meta(MetaCommands::RestoreMeta { opts }) => {
+            cmd_impl::meta::restore_meta(opts).await?
+        }
         Commands::Meta(MetaCommands::CreateConnection {
             connection_name,
             provider,
@@ -372,6 +472,9 @@ pub async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
         Commands::Meta(MetaCommands::DropConnection { connection_name }) => {
             cmd_impl::meta::drop_connection(context, connection_name).await?
         }
+        Commands::Meta(MetaCommands::TelemetryPreview) => {
+            cmd_impl::meta::telemetry_preview(context).await?
+        }
         Commands::Trace => cmd_impl::trace::trace(context).await?,
         Commands::Profile { sleep } => cmd_impl::profile::profile(context, sleep).await?,
     }
@@ -34,10 +34,21 @@ pub enum AggKind {
     ApproxCountDistinct,
     ArrayAgg,
     FirstValue,
+    LastValue,
     VarPop,
     VarSamp,
     StddevPop,
     StddevSamp,
+    /// Ordered-set aggregates, bound from `WITHIN GROUP (ORDER BY ...)`.
+    PercentileCont,
+    PercentileDisc,
+    Mode,
+    /// `every` is an alias for `bool_and`, resolved to this variant by the binder.
+    BoolAnd,
+    BoolOr,
+    BitAnd,
+    BitOr,
+    BitXor,
 }
 
 impl TryFrom<Type> for AggKind {
@@ -55,10 +66,19 @@ impl TryFrom<Type> for AggKind {
             Type::ApproxCountDistinct => Ok(AggKind::ApproxCountDistinct),
             Type::ArrayAgg => Ok(AggKind::ArrayAgg),
             Type::FirstValue => Ok(AggKind::FirstValue),
+            Type::LastValue => Ok(AggKind::LastValue),
             Type::StddevPop => Ok(AggKind::StddevPop),
             Type::StddevSamp => Ok(AggKind::StddevSamp),
             Type::VarPop => Ok(AggKind::VarPop),
             Type::VarSamp => Ok(AggKind::VarSamp),
+            Type::PercentileCont => Ok(AggKind::PercentileCont),
+            Type::PercentileDisc => Ok(AggKind::PercentileDisc),
+            Type::Mode => Ok(AggKind::Mode),
+            Type::BoolAnd => Ok(AggKind::BoolAnd),
+            Type::BoolOr => Ok(AggKind::BoolOr),
+            Type::BitAnd => Ok(AggKind::BitAnd),
+            Type::BitOr => Ok(AggKind::BitOr),
+            Type::BitXor => Ok(AggKind::BitXor),
             Type::Unspecified => bail!("Unrecognized agg."),
         }
     }
@@ -77,10 +97,19 @@ impl AggKind {
             Self::ApproxCountDistinct => Type::ApproxCountDistinct,
             Self::ArrayAgg => Type::ArrayAgg,
             Self::FirstValue => Type::FirstValue,
+            Self::LastValue => Type::LastValue,
             Self::StddevPop => Type::StddevPop,
             Self::StddevSamp => Type::StddevSamp,
             Self::VarPop => Type::VarPop,
             Self::VarSamp => Type::VarSamp,
+            Self::PercentileCont => Type::PercentileCont,
+            Self::PercentileDisc => Type::PercentileDisc,
+            Self::Mode => Type::Mode,
+            Self::BoolAnd => Type::BoolAnd,
+            Self::BoolOr => Type::BoolOr,
+            Self::BitAnd => Type::BitAnd,
+            Self::BitOr => Type::BitOr,
+            Self::BitXor => Type::BitXor,
         }
     }
 }
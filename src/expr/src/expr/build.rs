@@ -20,13 +20,17 @@ use risingwave_pb::expr::expr_node::{PbType, RexNode};
 use risingwave_pb::expr::ExprNode;
 
 use super::expr_array_concat::ArrayConcatExpression;
+use super::expr_array_contains::ArrayContainsExpression;
+use super::expr_array_position::ArrayPositionExpression;
 use super::expr_case::CaseExpression;
 use super::expr_coalesce::CoalesceExpression;
 use super::expr_concat_ws::ConcatWsExpression;
 use super::expr_field::FieldExpression;
 use super::expr_in::InExpression;
 use super::expr_nested_construct::NestedConstructExpression;
-use super::expr_regexp::RegexpMatchExpression;
+use super::expr_regexp::{
+    RegexpMatchExpression, RegexpReplaceExpression, RegexpSplitToArrayExpression,
+};
 use super::expr_some_all::SomeAllExpression;
 use super::expr_udf::UdfExpression;
 use super::expr_vnode::VnodeExpression;
@@ -73,12 +77,22 @@ pub fn build_from_prost(prost: &ExprNode) -> Result<BoxedExpression> {
         E::Array => NestedConstructExpression::try_from(prost).map(Expression::boxed),
         E::Row => NestedConstructExpression::try_from(prost).map(Expression::boxed),
         E::RegexpMatch => RegexpMatchExpression::try_from(prost).map(Expression::boxed),
+        E::RegexpReplace => RegexpReplaceExpression::try_from(prost).map(Expression::boxed),
+        E::RegexpSplitToArray => {
+            RegexpSplitToArrayExpression::try_from(prost).map(Expression::boxed)
+        }
         E::ArrayCat | E::ArrayAppend | E::ArrayPrepend => {
             // Now we implement these three functions as a single expression for the
             // sake of simplicity. If performance matters at some time, we can split
             // the implementation to improve performance.
             ArrayConcatExpression::try_from(prost).map(Expression::boxed)
         }
+        E::ArrayPosition | E::ArrayPositions | E::ArrayRemove => {
+            ArrayPositionExpression::try_from(prost).map(Expression::boxed)
+        }
+        E::ArrayContains | E::ArrayOverlap => {
+            ArrayContainsExpression::try_from(prost).map(Expression::boxed)
+        }
         E::Vnode => VnodeExpression::try_from(prost).map(Expression::boxed),
         E::Udf => UdfExpression::try_from(prost).map(Expression::boxed),
         _ => Err(ExprError::UnsupportedFunction(format!(
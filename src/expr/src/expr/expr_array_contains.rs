@@ -0,0 +1,128 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use risingwave_common::array::{ArrayRef, DataChunk, ListRef};
+use risingwave_common::row::OwnedRow;
+use risingwave_common::types::{DataType, Datum, DatumRef, ScalarRefImpl, ToDatumRef};
+use risingwave_pb::expr::expr_node::{RexNode, Type};
+use risingwave_pb::expr::ExprNode;
+
+use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression, Expression};
+use crate::{bail, ensure, ExprError, Result};
+
+#[derive(Debug, Copy, Clone)]
+enum Operation {
+    Contains,
+    Overlap,
+}
+
+/// `ArrayContainsExpression` backs the `@>` and `&&` operators, which test whether the left
+/// array contains every element of the right array (`@>`), or whether the two arrays have at
+/// least one element in common (`&&`).
+pub struct ArrayContainsExpression {
+    left: BoxedExpression,
+    right: BoxedExpression,
+    op: Operation,
+}
+
+impl std::fmt::Debug for ArrayContainsExpression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArrayContainsExpression")
+            .field("left", &self.left)
+            .field("right", &self.right)
+            .field("op", &self.op)
+            .finish()
+    }
+}
+
+impl ArrayContainsExpression {
+    fn evaluate(&self, left: DatumRef<'_>, right: DatumRef<'_>) -> Datum {
+        let (left, right) = match (left, right) {
+            (Some(ScalarRefImpl::List(left)), Some(ScalarRefImpl::List(right))) => (left, right),
+            _ => return None,
+        };
+        let matches = |needle: ListRef<'_>, haystack: ListRef<'_>, any: bool| {
+            let haystack: Vec<_> = haystack
+                .values_ref()
+                .into_iter()
+                .map(|x| x.map(ScalarRefImpl::into_scalar_impl))
+                .collect();
+            needle
+                .values_ref()
+                .into_iter()
+                .map(|x| x.map(ScalarRefImpl::into_scalar_impl))
+                .fold(!any, |acc, x| {
+                    if any {
+                        acc || haystack.contains(&x)
+                    } else {
+                        acc && haystack.contains(&x)
+                    }
+                })
+        };
+        let result = match self.op {
+            Operation::Contains => matches(right, left, false),
+            Operation::Overlap => matches(left, right, true),
+        };
+        Some(result.into())
+    }
+}
+
+#[async_trait::async_trait]
+impl Expression for ArrayContainsExpression {
+    fn return_type(&self) -> DataType {
+        DataType::Boolean
+    }
+
+    async fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let left = self.left.eval_checked(input).await?;
+        let right = self.right.eval_checked(input).await?;
+        let mut builder = DataType::Boolean.create_array_builder(input.capacity());
+        for i in 0..input.capacity() {
+            if !input.vis().is_set(i) {
+                builder.append_null();
+                continue;
+            }
+            builder.append_datum(&self.evaluate(left.value_at(i), right.value_at(i)));
+        }
+        Ok(Arc::new(builder.finish()))
+    }
+
+    async fn eval_row(&self, input: &OwnedRow) -> Result<Datum> {
+        let left = self.left.eval_row(input).await?;
+        let right = self.right.eval_row(input).await?;
+        Ok(self.evaluate(left.to_datum_ref(), right.to_datum_ref()))
+    }
+}
+
+impl<'a> TryFrom<&'a ExprNode> for ArrayContainsExpression {
+    type Error = ExprError;
+
+    fn try_from(prost: &'a ExprNode) -> Result<Self> {
+        let RexNode::FuncCall(func_call_node) = prost.get_rex_node()? else {
+            bail!("expects a RexNode::FuncCall");
+        };
+        let children = func_call_node.get_children();
+        ensure!(children.len() == 2);
+        let op = match prost.get_expr_type()? {
+            Type::ArrayContains => Operation::Contains,
+            Type::ArrayOverlap => Operation::Overlap,
+            _ => bail!("expects `ArrayContains`|`ArrayOverlap`"),
+        };
+        let left = expr_build_from_prost(&children[0])?;
+        let right = expr_build_from_prost(&children[1])?;
+        Ok(Self { left, right, op })
+    }
+}
@@ -0,0 +1,264 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use risingwave_common::array::{ArrayRef, DataChunk, ListRef, ListValue};
+use risingwave_common::row::OwnedRow;
+use risingwave_common::types::{DataType, Datum, DatumRef, ScalarRefImpl, ToDatumRef};
+use risingwave_pb::expr::expr_node::{RexNode, Type};
+use risingwave_pb::expr::ExprNode;
+
+use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression, Expression};
+use crate::{bail, ensure, ExprError, Result};
+
+#[derive(Debug, Copy, Clone)]
+enum Operation {
+    Position,
+    Positions,
+    Remove,
+}
+
+pub struct ArrayPositionExpression {
+    return_type: DataType,
+    array: BoxedExpression,
+    element: BoxedExpression,
+    start: Option<BoxedExpression>,
+    op: Operation,
+}
+
+impl std::fmt::Debug for ArrayPositionExpression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArrayPositionExpression")
+            .field("return_type", &self.return_type)
+            .field("array", &self.array)
+            .field("element", &self.element)
+            .field("op", &self.op)
+            .finish()
+    }
+}
+
+impl ArrayPositionExpression {
+    /// Returns the subscript of the first occurrence of the given value in the array, or `NULL`
+    /// if not found. If `start` is given, the search starts at that 1-based subscript.
+    ///
+    /// ```sql
+    /// array_position ( array anyarray, element anyelement [, start int ] ) → int
+    /// ```
+    ///
+    /// Examples:
+    ///
+    /// ```slt
+    /// query T
+    /// select array_position(array[1,2,3,2], 2);
+    /// ----
+    /// 2
+    ///
+    /// query T
+    /// select array_position(array[1,2,3,2], 2, 3);
+    /// ----
+    /// 4
+    ///
+    /// query T
+    /// select array_position(array[1,2,3], 4);
+    /// ----
+    /// NULL
+    ///
+    /// query T
+    /// select array_position(null::int[], 1);
+    /// ----
+    /// NULL
+    /// ```
+    fn position(array: Option<ListRef<'_>>, element: DatumRef<'_>, start: Option<i32>) -> Datum {
+        let array = array?;
+        let start = start.unwrap_or(1).max(1) as usize;
+        let element = element.map(ScalarRefImpl::into_scalar_impl);
+        let pos = array
+            .values_ref()
+            .into_iter()
+            .map(|x| x.map(ScalarRefImpl::into_scalar_impl))
+            .enumerate()
+            .skip(start - 1)
+            .find(|(_, x)| *x == element)
+            .map(|(i, _)| i as i32 + 1);
+        pos.map(Into::into)
+    }
+
+    /// Returns an array of the subscripts of all occurrences of the given value in the array,
+    /// or an empty array if not found.
+    ///
+    /// ```sql
+    /// array_positions ( array anyarray, element anyelement ) → int[]
+    /// ```
+    ///
+    /// Examples:
+    ///
+    /// ```slt
+    /// query T
+    /// select array_positions(array[1,2,1,3,1], 1);
+    /// ----
+    /// {1,3,5}
+    ///
+    /// query T
+    /// select array_positions(array[1,2,3], 4);
+    /// ----
+    /// {}
+    ///
+    /// query T
+    /// select array_positions(null::int[], 1);
+    /// ----
+    /// NULL
+    /// ```
+    fn positions(array: Option<ListRef<'_>>, element: DatumRef<'_>) -> Datum {
+        let array = array?;
+        let element = element.map(ScalarRefImpl::into_scalar_impl);
+        let positions = array
+            .values_ref()
+            .into_iter()
+            .map(|x| x.map(ScalarRefImpl::into_scalar_impl))
+            .enumerate()
+            .filter(|(_, x)| *x == element)
+            .map(|(i, _)| Some((i as i32 + 1).into()))
+            .collect();
+        Some(ListValue::new(positions).into())
+    }
+
+    /// Removes all elements equal to the given value from the array.
+    ///
+    /// ```sql
+    /// array_remove ( array anyarray, element anyelement ) → array
+    /// ```
+    ///
+    /// Examples:
+    ///
+    /// ```slt
+    /// query T
+    /// select array_remove(array[1,2,1,3,1], 1);
+    /// ----
+    /// {2,3}
+    ///
+    /// query T
+    /// select array_remove(array[1,2,3], NULL);
+    /// ----
+    /// {1,2,3}
+    ///
+    /// query T
+    /// select array_remove(null::int[], 1);
+    /// ----
+    /// NULL
+    /// ```
+    fn remove(array: Option<ListRef<'_>>, element: DatumRef<'_>) -> Datum {
+        let array = array?;
+        let element = element.map(ScalarRefImpl::into_scalar_impl);
+        let remaining = array
+            .values_ref()
+            .into_iter()
+            .map(|x| x.map(ScalarRefImpl::into_scalar_impl))
+            .filter(|x| *x != element)
+            .collect();
+        Some(ListValue::new(remaining).into())
+    }
+
+    fn evaluate(&self, array: DatumRef<'_>, element: DatumRef<'_>, start: DatumRef<'_>) -> Datum {
+        let array = match array {
+            Some(ScalarRefImpl::List(list)) => Some(list),
+            Some(_) => unreachable!("the first argument of {:?} must be a list", self.op),
+            None => None,
+        };
+        match self.op {
+            Operation::Position => {
+                let start = match start {
+                    Some(ScalarRefImpl::Int32(s)) => Some(s),
+                    _ => None,
+                };
+                Self::position(array, element, start)
+            }
+            Operation::Positions => Self::positions(array, element),
+            Operation::Remove => Self::remove(array, element),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Expression for ArrayPositionExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    async fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let array = self.array.eval_checked(input).await?;
+        let element = self.element.eval_checked(input).await?;
+        let start = match &self.start {
+            Some(start) => Some(start.eval_checked(input).await?),
+            None => None,
+        };
+        let mut builder = self.return_type.create_array_builder(array.len());
+        for i in 0..input.capacity() {
+            if !input.vis().is_set(i) {
+                builder.append_null();
+                continue;
+            }
+            let start_datum = start.as_ref().map(|s| s.value_at(i)).unwrap_or(None);
+            let datum = self.evaluate(array.value_at(i), element.value_at(i), start_datum);
+            builder.append_datum(&datum);
+        }
+        Ok(Arc::new(builder.finish()))
+    }
+
+    async fn eval_row(&self, input: &OwnedRow) -> Result<Datum> {
+        let array = self.array.eval_row(input).await?;
+        let element = self.element.eval_row(input).await?;
+        let start = match &self.start {
+            Some(start) => start.eval_row(input).await?,
+            None => None,
+        };
+        Ok(self.evaluate(
+            array.to_datum_ref(),
+            element.to_datum_ref(),
+            start.to_datum_ref(),
+        ))
+    }
+}
+
+impl<'a> TryFrom<&'a ExprNode> for ArrayPositionExpression {
+    type Error = ExprError;
+
+    fn try_from(prost: &'a ExprNode) -> Result<Self> {
+        let RexNode::FuncCall(func_call_node) = prost.get_rex_node()? else {
+            bail!("expects a RexNode::FuncCall");
+        };
+        let children = func_call_node.get_children();
+        let op = match prost.get_expr_type()? {
+            Type::ArrayPosition => Operation::Position,
+            Type::ArrayPositions => Operation::Positions,
+            Type::ArrayRemove => Operation::Remove,
+            _ => bail!("expects `ArrayPosition`|`ArrayPositions`|`ArrayRemove`"),
+        };
+        match op {
+            Operation::Position => ensure!(children.len() == 2 || children.len() == 3),
+            Operation::Positions | Operation::Remove => ensure!(children.len() == 2),
+        }
+        let array = expr_build_from_prost(&children[0])?;
+        let element = expr_build_from_prost(&children[1])?;
+        let start = children.get(2).map(expr_build_from_prost).transpose()?;
+        let ret_type = DataType::from(prost.get_return_type()?);
+        Ok(Self {
+            return_type: ret_type,
+            array,
+            element,
+            start,
+            op,
+        })
+    }
+}
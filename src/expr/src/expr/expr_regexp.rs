@@ -19,7 +19,7 @@ use itertools::Itertools;
 use regex::{Regex, RegexBuilder};
 use risingwave_common::array::{
     Array, ArrayBuilder, ArrayMeta, ArrayRef, DataChunk, ListArrayBuilder, ListRef, ListValue,
-    Utf8Array,
+    Utf8Array, Utf8ArrayBuilder,
 };
 use risingwave_common::row::OwnedRow;
 use risingwave_common::types::{DataType, Datum, ScalarImpl};
@@ -49,6 +49,8 @@ impl RegexpContext {
 struct RegexpOptions {
     /// `c` and `i`
     case_insensitive: bool,
+    /// `g`, only meaningful for `regexp_replace`
+    global: bool,
 }
 
 #[expect(clippy::derivable_impls)]
@@ -56,6 +58,7 @@ impl Default for RegexpOptions {
     fn default() -> Self {
         Self {
             case_insensitive: false,
+            global: false,
         }
     }
 }
@@ -69,7 +72,7 @@ impl FromStr for RegexpOptions {
             match c {
                 'c' => opts.case_insensitive = false,
                 'i' => opts.case_insensitive = true,
-                'g' => {}
+                'g' => opts.global = true,
                 _ => {
                     bail!("invalid regular expression option: \"{c}\"");
                 }
@@ -230,3 +233,334 @@ impl Expression for RegexpMatchExpression {
         })
     }
 }
+
+#[derive(Debug)]
+pub struct RegexpReplaceExpression {
+    pub text: Box<dyn Expression>,
+    pub replacement: Box<dyn Expression>,
+    pub ctx: RegexpContext,
+    /// Whether the `g` flag was given, i.e. replace every match instead of just the first one.
+    pub global: bool,
+    /// Whether the `pattern` or `flags` argument was a literal `NULL`, in which case (following
+    /// PostgreSQL's strict-function semantics) the result is always `NULL`.
+    pub always_null: bool,
+}
+
+impl<'a> TryFrom<&'a ExprNode> for RegexpReplaceExpression {
+    type Error = ExprError;
+
+    fn try_from(prost: &'a ExprNode) -> Result<Self> {
+        ensure!(prost.get_expr_type().unwrap() == Type::RegexpReplace);
+        let RexNode::FuncCall(func_call_node) = prost.get_rex_node().unwrap() else {
+            bail!("Expected RexNode::FuncCall");
+        };
+        let mut children = func_call_node.children.iter();
+        let Some(text_node) = children.next() else {
+            bail!("Expected argument text");
+        };
+        let text_expr = expr_build_from_prost(text_node)?;
+        let Some(pattern_node) = children.next() else {
+            bail!("Expected argument pattern");
+        };
+        let mut always_null = false;
+        let pattern = match &pattern_node.get_rex_node()? {
+            RexNode::Constant(pattern_value) => {
+                let pattern_datum = deserialize_datum(
+                    pattern_value.get_body().as_slice(),
+                    &DataType::from(pattern_node.get_return_type().unwrap()),
+                )
+                .map_err(|e| ExprError::Internal(e.into()))?;
+
+                match pattern_datum {
+                    Some(ScalarImpl::Utf8(pattern)) => pattern.to_string(),
+                    // NULL pattern
+                    None => {
+                        always_null = true;
+                        NULL_PATTERN.to_string()
+                    }
+                    _ => bail!("Expected pattern to be an String"),
+                }
+            }
+            _ => {
+                return Err(ExprError::UnsupportedFunction(
+                    "non-constant pattern in regexp_replace".to_string(),
+                ))
+            }
+        };
+        let Some(replacement_node) = children.next() else {
+            bail!("Expected argument replacement");
+        };
+        let replacement_expr = expr_build_from_prost(replacement_node)?;
+
+        let flags = if let Some(flags_node) = children.next() {
+            match &flags_node.get_rex_node()? {
+                RexNode::Constant(flags_value) => {
+                    let flags_datum = deserialize_datum(
+                        flags_value.get_body().as_slice(),
+                        &DataType::from(flags_node.get_return_type().unwrap()),
+                    )
+                    .map_err(|e| ExprError::Internal(e.into()))?;
+
+                    match flags_datum {
+                        Some(ScalarImpl::Utf8(flags)) => flags.to_string(),
+                        // NULL flag
+                        None => {
+                            always_null = true;
+                            "".to_string()
+                        }
+                        _ => bail!("Expected flags to be an String"),
+                    }
+                }
+                _ => {
+                    return Err(ExprError::UnsupportedFunction(
+                        "non-constant flags in regexp_replace".to_string(),
+                    ))
+                }
+            }
+        } else {
+            "".to_string()
+        };
+
+        let global = RegexpOptions::from_str(&flags)?.global;
+        let ctx = RegexpContext::new(&pattern, &flags)?;
+        Ok(Self {
+            text: text_expr,
+            replacement: replacement_expr,
+            ctx,
+            global,
+            always_null,
+        })
+    }
+}
+
+impl RegexpReplaceExpression {
+    /// Translates PostgreSQL's `regexp_replace` backreference syntax (`\1`..`\9`, `\\`) into the
+    /// syntax expected by the `regex` crate's replacement templates (`$1`..`$9`, `$$`).
+    fn translate_replacement(replacement: &str) -> String {
+        let mut out = String::with_capacity(replacement.len());
+        let mut chars = replacement.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '$' => out.push_str("$$"),
+                '\\' => match chars.next() {
+                    Some(d) if d.is_ascii_digit() => {
+                        out.push('$');
+                        out.push(d);
+                    }
+                    Some(other) => out.push(other),
+                    None => out.push('\\'),
+                },
+                _ => out.push(c),
+            }
+        }
+        out
+    }
+
+    fn replace_one(&self, text: &str, replacement: &str) -> String {
+        let replacement = Self::translate_replacement(replacement);
+        if self.global {
+            self.ctx.0.replace_all(text, replacement.as_str()).into()
+        } else {
+            self.ctx.0.replace(text, replacement.as_str()).into()
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Expression for RegexpReplaceExpression {
+    fn return_type(&self) -> DataType {
+        DataType::Varchar
+    }
+
+    async fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let mut output = Utf8ArrayBuilder::new(input.capacity());
+        if self.always_null {
+            for _ in 0..input.capacity() {
+                output.append_null();
+            }
+            return Ok(Arc::new(output.finish().into()));
+        }
+
+        let text_arr = self.text.eval_checked(input).await?;
+        let text_arr: &Utf8Array = text_arr.as_ref().into();
+        let replacement_arr = self.replacement.eval_checked(input).await?;
+        let replacement_arr: &Utf8Array = replacement_arr.as_ref().into();
+
+        for ((text, replacement), vis) in text_arr
+            .iter()
+            .zip_eq_fast(replacement_arr.iter())
+            .zip_eq_fast(input.vis().iter())
+        {
+            if !vis {
+                output.append_null();
+            } else if let (Some(text), Some(replacement)) = (text, replacement) {
+                output.append(Some(&self.replace_one(text, replacement)));
+            } else {
+                output.append_null();
+            }
+        }
+
+        Ok(Arc::new(output.finish().into()))
+    }
+
+    async fn eval_row(&self, input: &OwnedRow) -> Result<Datum> {
+        if self.always_null {
+            return Ok(None);
+        }
+        let text = self.text.eval_row(input).await?;
+        let replacement = self.replacement.eval_row(input).await?;
+        Ok(
+            match (text, replacement) {
+                (Some(ScalarImpl::Utf8(text)), Some(ScalarImpl::Utf8(replacement))) => {
+                    Some(self.replace_one(&text, &replacement).into())
+                }
+                _ => None,
+            },
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct RegexpSplitToArrayExpression {
+    pub child: Box<dyn Expression>,
+    pub ctx: RegexpContext,
+    /// Whether the `pattern` or `flags` argument was a literal `NULL`, in which case (following
+    /// PostgreSQL's strict-function semantics) the result is always `NULL`.
+    pub always_null: bool,
+}
+
+impl<'a> TryFrom<&'a ExprNode> for RegexpSplitToArrayExpression {
+    type Error = ExprError;
+
+    fn try_from(prost: &'a ExprNode) -> Result<Self> {
+        ensure!(prost.get_expr_type().unwrap() == Type::RegexpSplitToArray);
+        let RexNode::FuncCall(func_call_node) = prost.get_rex_node().unwrap() else {
+            bail!("Expected RexNode::FuncCall");
+        };
+        let mut children = func_call_node.children.iter();
+        let Some(text_node) = children.next() else {
+            bail!("Expected argument text");
+        };
+        let text_expr = expr_build_from_prost(text_node)?;
+        let Some(pattern_node) = children.next() else {
+            bail!("Expected argument pattern");
+        };
+        let mut always_null = false;
+        let pattern = match &pattern_node.get_rex_node()? {
+            RexNode::Constant(pattern_value) => {
+                let pattern_datum = deserialize_datum(
+                    pattern_value.get_body().as_slice(),
+                    &DataType::from(pattern_node.get_return_type().unwrap()),
+                )
+                .map_err(|e| ExprError::Internal(e.into()))?;
+
+                match pattern_datum {
+                    Some(ScalarImpl::Utf8(pattern)) => pattern.to_string(),
+                    // NULL pattern
+                    None => {
+                        always_null = true;
+                        NULL_PATTERN.to_string()
+                    }
+                    _ => bail!("Expected pattern to be an String"),
+                }
+            }
+            _ => {
+                return Err(ExprError::UnsupportedFunction(
+                    "non-constant pattern in regexp_split_to_array".to_string(),
+                ))
+            }
+        };
+
+        let flags = if let Some(flags_node) = children.next() {
+            match &flags_node.get_rex_node()? {
+                RexNode::Constant(flags_value) => {
+                    let flags_datum = deserialize_datum(
+                        flags_value.get_body().as_slice(),
+                        &DataType::from(flags_node.get_return_type().unwrap()),
+                    )
+                    .map_err(|e| ExprError::Internal(e.into()))?;
+
+                    match flags_datum {
+                        Some(ScalarImpl::Utf8(flags)) => flags.to_string(),
+                        // NULL flag
+                        None => {
+                            always_null = true;
+                            "".to_string()
+                        }
+                        _ => bail!("Expected flags to be an String"),
+                    }
+                }
+                _ => {
+                    return Err(ExprError::UnsupportedFunction(
+                        "non-constant flags in regexp_split_to_array".to_string(),
+                    ))
+                }
+            }
+        } else {
+            "".to_string()
+        };
+
+        let ctx = RegexpContext::new(&pattern, &flags)?;
+        Ok(Self {
+            child: text_expr,
+            ctx,
+            always_null,
+        })
+    }
+}
+
+impl RegexpSplitToArrayExpression {
+    fn split_one(&self, text: &str) -> ListValue {
+        ListValue::new(self.ctx.0.split(text).map(|s| Some(s.into())).collect_vec())
+    }
+}
+
+#[async_trait::async_trait]
+impl Expression for RegexpSplitToArrayExpression {
+    fn return_type(&self) -> DataType {
+        DataType::List {
+            datatype: Box::new(DataType::Varchar),
+        }
+    }
+
+    async fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let mut output = ListArrayBuilder::with_meta(
+            input.capacity(),
+            ArrayMeta::List {
+                datatype: Box::new(DataType::Varchar),
+            },
+        );
+        if self.always_null {
+            for _ in 0..input.capacity() {
+                output.append_null();
+            }
+            return Ok(Arc::new(output.finish().into()));
+        }
+
+        let text_arr = self.child.eval_checked(input).await?;
+        let text_arr: &Utf8Array = text_arr.as_ref().into();
+
+        for (text, vis) in text_arr.iter().zip_eq_fast(input.vis().iter()) {
+            if !vis || text.is_none() {
+                output.append_null();
+            } else {
+                let list = self.split_one(text.unwrap());
+                output.append(Some(ListRef::ValueRef { val: &list }));
+            }
+        }
+
+        Ok(Arc::new(output.finish().into()))
+    }
+
+    async fn eval_row(&self, input: &OwnedRow) -> Result<Datum> {
+        if self.always_null {
+            return Ok(None);
+        }
+        let text = self.child.eval_row(input).await?;
+        Ok(if let Some(ScalarImpl::Utf8(text)) = text {
+            Some(self.split_one(&text).into())
+        } else {
+            None
+        })
+    }
+}
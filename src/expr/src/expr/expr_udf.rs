@@ -91,6 +91,13 @@ impl<'a> TryFrom<&'a ExprNode> for UdfExpression {
         let RexNode::Udf(udf) = prost.get_rex_node().unwrap() else {
             bail!("expect UDF");
         };
+        if udf.language == "wasm" {
+            // The compiled module is stored in the catalog, but there is no embedded wasm
+            // runtime in this build to execute it in-process yet.
+            return Err(ExprError::UnsupportedFunction(
+                "embedded wasm UDFs are not executable yet".to_string(),
+            ));
+        }
         // connect to UDF service
         let arg_schema = Arc::new(Schema::new(
             udf.arg_types
@@ -33,8 +33,10 @@
 
 // These modules define concrete expression structures.
 mod expr_array_concat;
+mod expr_array_contains;
 mod expr_array_distinct;
 mod expr_array_length;
+mod expr_array_position;
 mod expr_array_to_string;
 mod expr_binary_nonnull;
 mod expr_binary_nullable;
@@ -53,6 +55,7 @@ mod expr_now;
 pub mod expr_regexp;
 mod expr_some_all;
 mod expr_to_char_const_tmpl;
+mod expr_to_date_const_tmpl;
 mod expr_to_timestamp_const_tmpl;
 mod expr_udf;
 mod expr_unary;
@@ -90,6 +90,11 @@ static AGG_FUNC_SIG_MAP: LazyLock<AggFuncSigMap> = LazyLock::new(|| {
         A::Count,
         A::Avg,
         A::ApproxCountDistinct,
+        A::Mode,
+        A::BoolAnd,
+        A::BoolOr,
+        A::FirstValue,
+        A::LastValue,
     ] {
         for input in all_types {
             if let Some(v) = infer_return_type(&agg, &[DataType::from(input)]) {
@@ -97,12 +102,29 @@ static AGG_FUNC_SIG_MAP: LazyLock<AggFuncSigMap> = LazyLock::new(|| {
             }
         }
     }
+    // Bitwise aggregates only accept integer inputs.
+    for agg in [A::BitAnd, A::BitOr, A::BitXor] {
+        for input in all_types {
+            if let Some(v) = infer_return_type(&agg, &[DataType::from(input)]) {
+                map.insert(agg, vec![input], DataTypeName::from(v));
+            }
+        }
+    }
     // Handle special case for `string_agg`, for it accepts two input arguments.
     map.insert(
         AggKind::StringAgg,
         vec![DataTypeName::Varchar, DataTypeName::Varchar],
         DataTypeName::Varchar,
     );
+    // `percentile_cont`/`percentile_disc` take the sorted column and a float fraction.
+    for agg in [A::PercentileCont, A::PercentileDisc] {
+        for input in all_types {
+            if let Some(v) = infer_return_type(&agg, &[DataType::from(input), DataType::Float64])
+            {
+                map.insert(agg, vec![input, DataTypeName::Float64], DataTypeName::from(v));
+            }
+        }
+    }
     map
 });
 
@@ -117,9 +139,13 @@ pub fn infer_return_type(agg_kind: &AggKind, inputs: &[DataType]) -> Option<Data
     // The function signatures are aligned with postgres, see
     // https://www.postgresql.org/docs/current/functions-aggregate.html.
     let return_type = match (&agg_kind, inputs) {
-        // Min, Max, FirstValue
-        (AggKind::Min | AggKind::Max | AggKind::FirstValue, [input]) => input.clone(),
-        (AggKind::Min | AggKind::Max | AggKind::FirstValue, _) => return None,
+        // Min, Max, FirstValue, LastValue
+        (AggKind::Min | AggKind::Max | AggKind::FirstValue | AggKind::LastValue, [input]) => {
+            input.clone()
+        }
+        (AggKind::Min | AggKind::Max | AggKind::FirstValue | AggKind::LastValue, _) => {
+            return None
+        }
 
         // Avg
         (AggKind::Avg, [input]) => match input {
@@ -182,6 +208,33 @@ pub fn infer_return_type(agg_kind: &AggKind, inputs: &[DataType]) -> Option<Data
             datatype: Box::new(input.clone()),
         },
         (AggKind::ArrayAgg, _) => return None,
+
+        // Ordered-set aggregates: `mode`/`percentile_disc` return a value of the sorted column's
+        // type, while `percentile_cont` interpolates between two of them and thus always returns
+        // a float.
+        (AggKind::Mode, [input]) => input.clone(),
+        (AggKind::Mode, _) => return None,
+
+        (AggKind::PercentileDisc, [input, fraction]) if fraction.is_numeric() => input.clone(),
+        (AggKind::PercentileDisc, _) => return None,
+
+        (AggKind::PercentileCont, [input, fraction])
+            if input.is_numeric() && fraction.is_numeric() =>
+        {
+            DataType::Float64
+        }
+        (AggKind::PercentileCont, _) => return None,
+
+        // BoolAnd/BoolOr (bool_and/every and bool_or)
+        (AggKind::BoolAnd | AggKind::BoolOr, [DataType::Boolean]) => DataType::Boolean,
+        (AggKind::BoolAnd | AggKind::BoolOr, _) => return None,
+
+        // BitAnd/BitOr/BitXor, over integer inputs only.
+        (
+            AggKind::BitAnd | AggKind::BitOr | AggKind::BitXor,
+            [input @ (DataType::Int16 | DataType::Int32 | DataType::Int64)],
+        ) => input.clone(),
+        (AggKind::BitAnd | AggKind::BitOr | AggKind::BitXor, _) => return None,
     };
 
     Some(return_type)
@@ -0,0 +1,105 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
+use risingwave_common::types::{Interval, Timestamp, USECS_PER_SEC};
+use risingwave_expr_macro::function;
+
+use crate::Result;
+
+/// Computes the calendar-aware difference between two timestamps, broken down into years,
+/// months, days and a time-of-day component, unlike `timestamp - timestamp` which only produces
+/// days and microseconds. Mirrors PostgreSQL's `age(timestamp, timestamp)`.
+#[function("age(timestamp, timestamp) -> interval")]
+pub fn timestamp_timestamp_age(l: Timestamp, r: Timestamp) -> Result<Interval> {
+    Ok(age(l.0, r.0))
+}
+
+fn age(l: NaiveDateTime, r: NaiveDateTime) -> Interval {
+    let mut year = l.year() - r.year();
+    let mut month = l.month() as i32 - r.month() as i32;
+    let mut day = l.day() as i32 - r.day() as i32;
+    let mut hour = l.hour() as i64 - r.hour() as i64;
+    let mut minute = l.minute() as i64 - r.minute() as i64;
+    let mut usec = (l.second() as i64 * USECS_PER_SEC + l.nanosecond() as i64 / 1000)
+        - (r.second() as i64 * USECS_PER_SEC + r.nanosecond() as i64 / 1000);
+
+    if usec < 0 {
+        usec += 60 * USECS_PER_SEC;
+        minute -= 1;
+    }
+    if minute < 0 {
+        minute += 60;
+        hour -= 1;
+    }
+    if hour < 0 {
+        hour += 24;
+        day -= 1;
+    }
+    if day < 0 {
+        // Borrow the number of days in the month right before `l`'s month, since we are
+        // "ahead of" that month.
+        let (prev_year, prev_month) = if l.month() == 1 {
+            (l.year() - 1, 12)
+        } else {
+            (l.year(), l.month() - 1)
+        };
+        day += days_in_month(prev_year, prev_month) as i32;
+        month -= 1;
+    }
+    if month < 0 {
+        month += 12;
+        year -= 1;
+    }
+
+    let usecs = hour * 3600 * USECS_PER_SEC + minute * 60 * USECS_PER_SEC + usec;
+    Interval::from_month_day_usec(year * 12 + month, day, usecs)
+}
+
+fn days_in_month(year: i32, month: u32) -> i64 {
+    let this_month_first = NaiveDate::from_ymd_opt(year, month, 1).unwrap();
+    let next_month_first = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)
+    }
+    .unwrap();
+    (next_month_first - this_month_first).num_days()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector_op::cast::str_to_timestamp;
+
+    #[test]
+    fn test_age() {
+        let cases = [
+            ("2001-04-10", "1957-06-13", "43 years 9 mons 28 days"),
+            ("1957-06-13", "2001-04-10", "-43 years -10 mons 3 days"),
+            ("2001-01-01", "2000-12-31", "1 day"),
+            (
+                "2001-12-31 15:00:00",
+                "2000-12-31 16:00:00",
+                "11 mons 29 days 23:00:00",
+            ),
+        ];
+        for (l, r, expected) in cases {
+            let l = str_to_timestamp(l).unwrap();
+            let r = str_to_timestamp(r).unwrap();
+            let expected: Interval = expected.parse().unwrap();
+            assert_eq!(timestamp_timestamp_age(l, r).unwrap(), expected);
+        }
+    }
+}
@@ -238,6 +238,17 @@ pub fn create_agg_state_unary(
         (Max, max_struct, struct_type, struct_type, None),
         (Max, max_str, varchar, varchar, None),
         (Max, max_list, list, list, None),
+        (BoolAnd, bool_and, boolean, boolean, None),
+        (BoolOr, bool_or, boolean, boolean, None),
+        (BitAnd, bit_and, int16, int16, None),
+        (BitAnd, bit_and, int32, int32, None),
+        (BitAnd, bit_and, int64, int64, None),
+        (BitOr, bit_or, int16, int16, None),
+        (BitOr, bit_or, int32, int32, None),
+        (BitOr, bit_or, int64, int64, None),
+        (BitXor, bit_xor, int16, int16, None),
+        (BitXor, bit_xor, int32, int32, None),
+        (BitXor, bit_xor, int64, int64, None),
         (FirstValue, first, int16, int16, None),
         (FirstValue, first, int32, int32, None),
         (FirstValue, first, int64, int64, None),
@@ -252,6 +263,20 @@ pub fn create_agg_state_unary(
         (FirstValue, first_struct, struct_type, struct_type, None),
         (FirstValue, first_str, varchar, varchar, None),
         (FirstValue, first_list, list, list, None),
+        (LastValue, last, int16, int16, None),
+        (LastValue, last, int32, int32, None),
+        (LastValue, last, int64, int64, None),
+        (LastValue, last, float32, float32, None),
+        (LastValue, last, float64, float64, None),
+        (LastValue, last, decimal, decimal, None),
+        (LastValue, last, boolean, boolean, None),
+        (LastValue, last, interval, interval, None),
+        (LastValue, last, date, date, None),
+        (LastValue, last, timestamp, timestamp, None),
+        (LastValue, last, time, time, None),
+        (LastValue, last_struct, struct_type, struct_type, None),
+        (LastValue, last_str, varchar, varchar, None),
+        (LastValue, last_list, list, list, None),
         // Global Agg
         (Sum, sum, int64, int64, None),
     ];
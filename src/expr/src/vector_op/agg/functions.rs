@@ -123,6 +123,54 @@ pub fn max_struct<'a>(
     max(r, i)
 }
 
+pub fn bit_and<'a, T>(result: Option<T>, input: Option<T>) -> Result<Option<T>>
+where
+    T: ScalarRef<'a> + std::ops::BitAnd<Output = T>,
+{
+    let res = match (result, input) {
+        (None, _) => input,
+        (_, None) => result,
+        (Some(r), Some(i)) => Some(r & i),
+    };
+    Ok(res)
+}
+
+pub fn bit_or<'a, T>(result: Option<T>, input: Option<T>) -> Result<Option<T>>
+where
+    T: ScalarRef<'a> + std::ops::BitOr<Output = T>,
+{
+    let res = match (result, input) {
+        (None, _) => input,
+        (_, None) => result,
+        (Some(r), Some(i)) => Some(r | i),
+    };
+    Ok(res)
+}
+
+pub fn bit_xor<'a, T>(result: Option<T>, input: Option<T>) -> Result<Option<T>>
+where
+    T: ScalarRef<'a> + std::ops::BitXor<Output = T>,
+{
+    let res = match (result, input) {
+        (None, _) => input,
+        (_, None) => result,
+        (Some(r), Some(i)) => Some(r ^ i),
+    };
+    Ok(res)
+}
+
+/// `bool_and` (aliased as `every`) is true iff every non-null input is true, which is equivalent
+/// to taking the minimum under `false < true`.
+pub fn bool_and(result: Option<bool>, input: Option<bool>) -> Result<Option<bool>> {
+    min(result, input)
+}
+
+/// `bool_or` is true iff any non-null input is true, which is equivalent to taking the maximum
+/// under `false < true`.
+pub fn bool_or(result: Option<bool>, input: Option<bool>) -> Result<Option<bool>> {
+    max(result, input)
+}
+
 pub fn max_list<'a>(r: Option<ListRef<'a>>, i: Option<ListRef<'a>>) -> Result<Option<ListRef<'a>>> {
     max(r, i)
 }
@@ -149,6 +197,28 @@ pub fn first_list<'a>(
     first(r, i)
 }
 
+pub fn last<T>(_result: Option<T>, input: Option<T>) -> Result<Option<T>> {
+    Ok(input)
+}
+
+pub fn last_str<'a>(r: Option<&'a str>, i: Option<&'a str>) -> Result<Option<&'a str>> {
+    last(r, i)
+}
+
+pub fn last_struct<'a>(
+    r: Option<StructRef<'a>>,
+    i: Option<StructRef<'a>>,
+) -> Result<Option<StructRef<'a>>> {
+    last(r, i)
+}
+
+pub fn last_list<'a>(
+    r: Option<ListRef<'a>>,
+    i: Option<ListRef<'a>>,
+) -> Result<Option<ListRef<'a>>> {
+    last(r, i)
+}
+
 /// Note the following corner cases:
 ///
 /// ```slt
@@ -38,11 +38,6 @@ pub fn date_trunc_timestamp(field: &str, ts: Timestamp) -> Result<Timestamp> {
     })
 }
 
-// #[function("date_trunc(varchar, timestamptz) -> timestamptz")]
-pub fn date_trunc_timestamptz(_field: &str, _ts: i64) -> Result<i64> {
-    todo!("date_trunc_timestamptz")
-}
-
 #[function("date_trunc(varchar, timestamptz, varchar) -> timestamptz")]
 pub fn date_trunc_timestamptz_at_timezone(field: &str, ts: i64, timezone: &str) -> Result<i64> {
     let timestamp = timestamptz_at_time_zone(ts, timezone)?;
@@ -16,6 +16,7 @@ use chrono::{Datelike, NaiveTime, Timelike};
 use risingwave_common::types::{Date, Decimal, Interval, Time, Timestamp, F64};
 use risingwave_expr_macro::function;
 
+use super::timestamptz::timestamptz_at_time_zone;
 use crate::{ExprError, Result};
 
 fn extract_date(date: impl Datelike, unit: &str) -> Option<Decimal> {
@@ -105,11 +106,24 @@ pub fn extract_from_timestamp(unit: &str, timestamp: Timestamp) -> Result<Decima
 pub fn extract_from_timestamptz(unit: &str, usecs: i64) -> Result<Decimal> {
     match unit {
         "EPOCH" => Ok(Decimal::from_i128_with_scale(usecs as i128, 6)),
-        // TODO(#5826): all other units depend on implicit session TimeZone
+        // All other units depend on the session time zone; see `extract_from_timestamptz_at_timezone`.
         _ => Err(invalid_unit("timestamp with time zone units", unit)),
     }
 }
 
+#[function("extract(varchar, timestamptz, varchar) -> decimal")]
+pub fn extract_from_timestamptz_at_timezone(
+    unit: &str,
+    usecs: i64,
+    timezone: &str,
+) -> Result<Decimal> {
+    if unit.eq_ignore_ascii_case("epoch") {
+        return Ok(Decimal::from_i128_with_scale(usecs as i128, 6));
+    }
+    let local = timestamptz_at_time_zone(usecs, timezone)?;
+    extract_from_timestamp(unit, local)
+}
+
 #[function("extract(varchar, interval) -> decimal")]
 pub fn extract_from_interval(unit: &str, interval: Interval) -> Result<Decimal> {
     Ok(if unit.eq_ignore_ascii_case("millennium") {
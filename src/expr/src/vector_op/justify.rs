@@ -0,0 +1,65 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::Interval;
+use risingwave_expr_macro::function;
+
+use crate::{ExprError, Result};
+
+#[function("justify_hours(interval) -> interval")]
+pub fn justify_hours(interval: Interval) -> Result<Interval> {
+    interval.justify_hours().ok_or(ExprError::NumericOutOfRange)
+}
+
+#[function("justify_days(interval) -> interval")]
+pub fn justify_days(interval: Interval) -> Result<Interval> {
+    interval.justify_days().ok_or(ExprError::NumericOutOfRange)
+}
+
+#[function("justify_interval(interval) -> interval")]
+pub fn justify_interval(interval: Interval) -> Result<Interval> {
+    interval
+        .justify_interval()
+        .ok_or(ExprError::NumericOutOfRange)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_justify_hours() {
+        let interval: Interval = "1 mon 1 day 36:00:00".parse().unwrap();
+        let expected: Interval = "1 mon 2 days 12:00:00".parse().unwrap();
+        assert_eq!(justify_hours(interval).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_justify_days() {
+        let interval: Interval = "35 days".parse().unwrap();
+        let expected: Interval = "1 mon 5 days".parse().unwrap();
+        assert_eq!(justify_days(interval).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_justify_interval() {
+        let interval: Interval = "1 mon -1 day".parse().unwrap();
+        let expected: Interval = "29 days".parse().unwrap();
+        assert_eq!(justify_interval(interval).unwrap(), expected);
+
+        let interval: Interval = "-1 mon 1 day".parse().unwrap();
+        let expected: Interval = "-29 days".parse().unwrap();
+        assert_eq!(justify_interval(interval).unwrap(), expected);
+    }
+}
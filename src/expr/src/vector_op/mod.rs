@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod age;
 pub mod agg;
 pub mod arithmetic_op;
 pub mod array_access;
@@ -26,6 +27,7 @@ pub mod exp;
 pub mod extract;
 pub mod format_type;
 pub mod jsonb_info;
+pub mod justify;
 pub mod length;
 pub mod like;
 pub mod lower;
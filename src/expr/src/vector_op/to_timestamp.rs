@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use chrono::format::Parsed;
-use risingwave_common::types::Timestamp;
+use risingwave_common::types::{Date, Timestamp};
 
 // use risingwave_expr_macro::function;
 use super::to_char::{compile_pattern_to_chrono, ChronoPattern};
@@ -69,3 +69,15 @@ pub fn to_timestamp(s: &str, tmpl: &str) -> Result<Timestamp> {
     let pattern = compile_pattern_to_chrono(tmpl);
     to_timestamp_const_tmpl(s, &pattern)
 }
+
+#[inline(always)]
+pub fn to_date_const_tmpl(s: &str, tmpl: &ChronoPattern) -> Result<Date> {
+    let ts = to_timestamp_const_tmpl(s, tmpl)?;
+    Ok(Date(ts.0.date()))
+}
+
+// #[function("to_date(varchar, varchar) -> date")]
+pub fn to_date(s: &str, tmpl: &str) -> Result<Date> {
+    let pattern = compile_pattern_to_chrono(tmpl);
+    to_date_const_tmpl(s, &pattern)
+}
@@ -382,6 +382,7 @@ impl TestCase {
                     source_schema,
                     source_watermarks,
                     append_only,
+                    on_conflict,
                     ..
                 } => {
                     create_table::handle_create_table(
@@ -393,6 +394,7 @@ impl TestCase {
                         source_schema,
                         source_watermarks,
                         append_only,
+                        on_conflict,
                     )
                     .await?;
                 }
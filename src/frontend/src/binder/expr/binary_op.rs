@@ -112,6 +112,8 @@ impl Binder {
                 func_types.push(ExprType::IsNull);
                 ExprType::RegexpMatch
             }
+            BinaryOperator::PGContains => ExprType::ArrayContains,
+            BinaryOperator::PGOverlap => ExprType::ArrayOverlap,
             _ => {
                 return Err(
                     ErrorCode::NotImplemented(format!("binary op: {:?}", op), 112.into()).into(),
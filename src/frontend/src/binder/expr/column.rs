@@ -19,7 +19,34 @@ use crate::binder::Binder;
 use crate::expr::{CorrelatedInputRef, ExprImpl, ExprType, FunctionCall, InputRef};
 
 impl Binder {
+    /// Binds a (possibly dotted) column reference, e.g. `a`, `t.a`, `s.t.a`, or, when `a` is a
+    /// struct column, the dot-style field access `t.a.field` (and deeper, `t.a.field.subfield`).
+    ///
+    /// Since a bare identifier can denote either a schema/table qualifier or a struct field, we
+    /// try the longest `schema.table.column` prefix first and fall back to shorter ones,
+    /// resolving any leftover idents as struct field accesses on the bound column.
     pub fn bind_column(&mut self, idents: &[Ident]) -> Result<ExprImpl> {
+        let mut err = None;
+        for prefix_len in (1..=idents.len().min(3)).rev() {
+            match self.bind_column_prefix(&idents[..prefix_len]) {
+                Ok(expr) => {
+                    let fields = &idents[prefix_len..];
+                    if fields.is_empty() {
+                        return Ok(expr);
+                    }
+                    return Ok(Self::bind_field(String::new(), expr, fields, false)?[0]
+                        .0
+                        .clone());
+                }
+                Err(e) => err.get_or_insert(e),
+            };
+        }
+        Err(err.unwrap())
+    }
+
+    /// Binds an exact `column`, `table.column`, or `schema.table.column` reference, without
+    /// considering any struct field access.
+    fn bind_column_prefix(&mut self, idents: &[Ident]) -> Result<ExprImpl> {
         // TODO: check quote style of `ident`.
         let (_schema_name, table_name, column_name) = match idents {
             [column] => (None, None, column.real_value()),
@@ -15,24 +15,29 @@
 use std::collections::HashMap;
 use std::iter::once;
 use std::str::FromStr;
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock};
 
 use bk_tree::{metrics, BKTree};
 use itertools::Itertools;
 use risingwave_common::array::ListValue;
-use risingwave_common::catalog::PG_CATALOG_SCHEMA_NAME;
+use risingwave_common::catalog::{Field, PG_CATALOG_SCHEMA_NAME};
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::session_config::USER_NAME_WILD_CARD;
 use risingwave_common::types::{DataType, ScalarImpl};
+use risingwave_common::util::iter_util::ZipEqFast;
 use risingwave_common::{GIT_SHA, RW_VERSION};
 use risingwave_expr::expr::AggKind;
-use risingwave_sqlparser::ast::{Function, FunctionArg, FunctionArgExpr, WindowSpec};
+use risingwave_sqlparser::ast::{Function, FunctionArg, FunctionArgExpr, OrderByExpr, WindowSpec};
+use risingwave_sqlparser::parser::Parser;
+use risingwave_sqlparser::tokenizer::Tokenizer;
 
 use crate::binder::bind_context::Clause;
 use crate::binder::{Binder, BoundQuery, BoundSetExpr};
+use crate::catalog::function_catalog::FunctionCatalog;
 use crate::expr::{
-    AggCall, Expr, ExprImpl, ExprType, FunctionCall, Literal, OrderBy, Subquery, SubqueryKind,
-    TableFunction, TableFunctionType, UserDefinedFunction, WindowFunction, WindowFunctionType,
+    AggCall, Expr, ExprImpl, ExprRewriter, ExprType, FunctionCall, InputRef, Literal, OrderBy,
+    Subquery, SubqueryKind, TableFunction, TableFunctionType, UserDefinedFunction, WindowFunction,
+    WindowFunctionType,
 };
 use crate::utils::Condition;
 
@@ -62,7 +67,13 @@ impl Binder {
         };
 
         // agg calls
-        if let Ok(kind) = function_name.parse() {
+        // `every` is a standard SQL alias for `bool_and`.
+        let agg_kind_name = if function_name == "every" {
+            "bool_and"
+        } else {
+            function_name.as_str()
+        };
+        if let Ok(kind) = agg_kind_name.parse() {
             if f.over.is_some() {
                 return Err(ErrorCode::NotImplemented(
                     format!("aggregate function as over window function: {}", kind),
@@ -116,20 +127,115 @@ impl Binder {
         {
             use crate::catalog::function_catalog::FunctionKind::*;
             match &func.kind {
+                Scalar { .. } if func.language == "sql" => {
+                    return self.bind_sql_udf(func.clone(), inputs);
+                }
                 Scalar { .. } => return Ok(UserDefinedFunction::new(func.clone(), inputs).into()),
                 Table { .. } => {
                     self.ensure_table_function_allowed()?;
                     return Ok(TableFunction::new_user_defined(func.clone(), inputs).into());
                 }
-                Aggregate => todo!("support UDAF"),
+                Aggregate => {
+                    // `CREATE AGGREGATE` fully catalogs the aggregate, but there is no batch or
+                    // streaming executor yet that can drive its create/accumulate/retract/finish
+                    // state machine, so calling it is rejected here rather than panicking deeper
+                    // in the planner.
+                    return Err(ErrorCode::NotImplemented(
+                        format!("calling user-defined aggregate \"{}\"", func.name),
+                        None.into(),
+                    )
+                    .into());
+                }
             }
         }
 
         self.bind_builtin_scalar_function(function_name.as_str(), inputs)
     }
 
+    /// Inlines the body of a `LANGUAGE sql` UDF at the call site: the stored body expression is
+    /// parsed, bound in an isolated context where the function's declared arguments are exposed
+    /// as columns, and the resulting references to those arguments are substituted with the
+    /// already-bound `inputs`.
+    fn bind_sql_udf(
+        &mut self,
+        func: Arc<FunctionCatalog>,
+        inputs: Vec<ExprImpl>,
+    ) -> Result<ExprImpl> {
+        if !self.udf_context.insert(func.name.clone()) {
+            return Err(ErrorCode::BindError(format!(
+                "function \"{}\" has a recursive definition",
+                func.name
+            ))
+            .into());
+        }
+
+        // Arguments declared without a name (e.g. `CREATE FUNCTION f(int)`) can still be
+        // referenced positionally in the body via `$1`, `$2`, etc., like in Postgres. Give every
+        // argument a name to bind against: the declared one if present, otherwise a synthetic
+        // one matching its position.
+        let arg_names = (0..func.arg_types.len())
+            .map(|i| match func.arg_names.get(i) {
+                Some(name) if !name.is_empty() => name.clone(),
+                _ => format!("col{}", i + 1),
+            })
+            .collect_vec();
+
+        let result = (|| {
+            let body = func.body.as_ref().ok_or_else(|| {
+                ErrorCode::BindError(format!("sql udf \"{}\" has no body", func.name))
+            })?;
+            let body = substitute_positional_params(body, &arg_names);
+            let tokens = Tokenizer::new(&body)
+                .tokenize_with_location()
+                .map_err(|e| ErrorCode::BindError(e.to_string()))?;
+            let ast = Parser::new(tokens)
+                .parse_expr()
+                .map_err(|e| ErrorCode::BindError(format!("failed to parse sql udf body: {e}")))?;
+
+            self.push_context();
+            let columns = arg_names
+                .iter()
+                .zip_eq_fast(func.arg_types.iter())
+                .map(|(name, ty)| (false, Field::with_name(ty.clone(), name.clone())));
+            let bind_result = self
+                .bind_table_to_context(columns, func.name.clone(), None)
+                .and_then(|()| self.bind_expr(ast));
+            self.pop_context()?;
+            let bound = bind_result?;
+
+            // Substitute references to the function's own arguments with the expressions bound
+            // at the call site.
+            struct SubstituteArgs<'a> {
+                inputs: &'a [ExprImpl],
+            }
+            impl ExprRewriter for SubstituteArgs<'_> {
+                fn rewrite_input_ref(&mut self, input_ref: InputRef) -> ExprImpl {
+                    self.inputs[input_ref.index].clone()
+                }
+            }
+            Ok(SubstituteArgs { inputs: &inputs }.rewrite_expr(bound))
+        })();
+
+        self.udf_context.remove(&func.name);
+        result
+    }
+
     pub(super) fn bind_agg(&mut self, mut f: Function, kind: AggKind) -> Result<ExprImpl> {
         self.ensure_aggregate_allowed()?;
+        let within_group = f.within_group.take();
+        if matches!(
+            kind,
+            AggKind::Mode | AggKind::PercentileCont | AggKind::PercentileDisc
+        ) {
+            return self.bind_ordered_set_agg(f, kind, within_group);
+        }
+        if within_group.is_some() {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "WITHIN GROUP is not allowed in `{}` aggregation",
+                kind
+            ))
+            .into());
+        }
         let inputs: Vec<ExprImpl> = f
             .args
             .into_iter()
@@ -198,25 +304,101 @@ impl Binder {
             None => Condition::true_cond(),
         };
 
-        if f.distinct && !f.order_by.is_empty() {
-            // <https://www.postgresql.org/docs/current/sql-expressions.html#SYNTAX-AGGREGATES:~:text=the%20DISTINCT%20list.-,Note,-The%20ability%20to>
-            return Err(ErrorCode::InvalidInputSyntax(
-                "DISTINCT and ORDER BY are not supported to appear at the same time now"
-                    .to_string(),
-            )
-            .into());
-        }
         let order_by = OrderBy::new(
             f.order_by
                 .into_iter()
                 .map(|e| self.bind_order_by_expr(e))
                 .try_collect()?,
         );
+        if f.distinct
+            && !order_by
+                .sort_exprs
+                .iter()
+                .all(|o| inputs.contains(&o.expr))
+        {
+            // Following Postgres, ORDER BY is allowed to appear together with DISTINCT only if
+            // its sort expressions are also direct arguments of the aggregate: sorting the
+            // already-deduplicated rows by one of their own columns is well-defined, but sorting
+            // by anything else is not, since DISTINCT may reorder/drop rows arbitrarily.
+            // <https://www.postgresql.org/docs/current/sql-expressions.html#SYNTAX-AGGREGATES:~:text=the%20DISTINCT%20list.-,Note,-The%20ability%20to>
+            return Err(ErrorCode::InvalidInputSyntax(
+                "ORDER BY expressions must appear in the argument list when DISTINCT is specified"
+                    .to_string(),
+            )
+            .into());
+        }
         Ok(ExprImpl::AggCall(Box::new(AggCall::new(
             kind, inputs, f.distinct, order_by, filter,
         )?)))
     }
 
+    /// Bind an ordered-set aggregate, e.g. `percentile_cont(0.5) WITHIN GROUP (ORDER BY x)` or
+    /// `mode() WITHIN GROUP (ORDER BY x)`.
+    ///
+    /// The `WITHIN GROUP` value expression is bound and prepended to the aggregate's `inputs` as
+    /// the value column, and becomes the (only) `ORDER BY` key of the aggregate.
+    fn bind_ordered_set_agg(
+        &mut self,
+        f: Function,
+        kind: AggKind,
+        within_group: Option<Box<OrderByExpr>>,
+    ) -> Result<ExprImpl> {
+        if f.distinct {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "DISTINCT is not allowed in `{}` aggregation",
+                kind
+            ))
+            .into());
+        }
+        let Some(within_group) = within_group else {
+            return Err(ErrorCode::BindError(format!(
+                "`{}` aggregation requires WITHIN GROUP (ORDER BY ...)",
+                kind
+            ))
+            .into());
+        };
+
+        let fraction_args: Vec<ExprImpl> = f
+            .args
+            .into_iter()
+            .map(|arg| self.bind_function_arg(arg))
+            .flatten_ok()
+            .try_collect()?;
+
+        let value_order_by = self.bind_order_by_expr(*within_group)?;
+        let mut inputs = vec![value_order_by.expr.clone()];
+
+        match kind {
+            AggKind::PercentileCont | AggKind::PercentileDisc => {
+                let [fraction]: [ExprImpl; 1] = fraction_args.try_into().map_err(|_| {
+                    ErrorCode::BindError(format!(
+                        "`{}` aggregation takes exactly one fraction argument",
+                        kind
+                    ))
+                })?;
+                inputs.push(fraction.cast_implicit(DataType::Float64)?);
+            }
+            AggKind::Mode => {
+                if !fraction_args.is_empty() {
+                    return Err(ErrorCode::BindError(
+                        "`mode` aggregation takes no arguments".to_string(),
+                    )
+                    .into());
+                }
+            }
+            _ => unreachable!(),
+        }
+
+        let order_by = OrderBy::new(vec![value_order_by]);
+        Ok(ExprImpl::AggCall(Box::new(AggCall::new(
+            kind,
+            inputs,
+            false,
+            order_by,
+            Condition::true_cond(),
+        )?)))
+    }
+
     pub(super) fn bind_window_function(
         &mut self,
         WindowSpec {
@@ -363,8 +545,27 @@ impl Binder {
                         (2, raw_call(ExprType::ToTimestamp1)),
                     ]),
                 ),
-                ("date_trunc", raw_call(ExprType::DateTrunc)),
+                ("to_date", raw_call(ExprType::ToDate)),
+                ("date_trunc", raw(|_binder, inputs| {
+                    // `date_trunc(field, timestamptz)` implicitly relies on the session
+                    // timezone. We bind it as an unchecked 2-argument call here and let
+                    // `SessionTimezone` (run once per query, after binding) expand it into the
+                    // 3-argument `date_trunc(field, timestamptz, timezone)` form.
+                    if inputs.len() == 2 && inputs[1].return_type() == DataType::Timestamptz {
+                        return Ok(FunctionCall::new_unchecked(
+                            ExprType::DateTrunc,
+                            inputs,
+                            DataType::Timestamptz,
+                        )
+                        .into());
+                    }
+                    Ok(FunctionCall::new(ExprType::DateTrunc, inputs)?.into())
+                })),
                 ("date_part", raw_call(ExprType::DatePart)),
+                ("justify_hours", raw_call(ExprType::JustifyHours)),
+                ("justify_days", raw_call(ExprType::JustifyDays)),
+                ("justify_interval", raw_call(ExprType::JustifyInterval)),
+                ("age", raw_call(ExprType::Age)),
                 // string
                 ("substr", raw_call(ExprType::Substr)),
                 ("length", raw_call(ExprType::Length)),
@@ -392,6 +593,11 @@ impl Binder {
                 ("octet_length", raw_call(ExprType::OctetLength)),
                 ("bit_length", raw_call(ExprType::BitLength)),
                 ("regexp_match", raw_call(ExprType::RegexpMatch)),
+                ("regexp_replace", raw_call(ExprType::RegexpReplace)),
+                (
+                    "regexp_split_to_array",
+                    raw_call(ExprType::RegexpSplitToArray),
+                ),
                 ("chr", raw_call(ExprType::Chr)),
                 ("starts_with", raw_call(ExprType::StartsWith)),
                 ("initcap", raw_call(ExprType::Initcap)),
@@ -411,6 +617,15 @@ impl Binder {
                 ("array_distinct", raw_call(ExprType::ArrayDistinct)),
                 ("array_length", raw_call(ExprType::ArrayLength)),
                 ("cardinality", raw_call(ExprType::Cardinality)),
+                (
+                    "array_position",
+                    dispatch_by_len(vec![
+                        (2, raw_call(ExprType::ArrayPosition)),
+                        (3, raw_call(ExprType::ArrayPosition)),
+                    ]),
+                ),
+                ("array_positions", raw_call(ExprType::ArrayPositions)),
+                ("array_remove", raw_call(ExprType::ArrayRemove)),
                 // jsonb
                 ("jsonb_object_field", raw_call(ExprType::JsonbAccessInner)),
                 ("jsonb_array_element", raw_call(ExprType::JsonbAccessInner)),
@@ -733,3 +948,49 @@ impl Binder {
         }
     }
 }
+
+/// Rewrites positional parameter references (`$1`, `$2`, ...) in a `LANGUAGE sql` UDF body into
+/// double-quoted identifiers naming the corresponding argument in `arg_names`, so that the normal
+/// identifier-resolution path in [`Binder::bind_expr`] can bind them like any other column
+/// reference. Occurrences inside single-quoted string literals are left untouched.
+fn substitute_positional_params(body: &str, arg_names: &[String]) -> String {
+    let mut out = String::with_capacity(body.len());
+    let mut chars = body.chars().peekable();
+    let mut in_string = false;
+    while let Some(c) = chars.next() {
+        if c == '\'' {
+            in_string = !in_string;
+            out.push(c);
+            continue;
+        }
+        if in_string || c != '$' {
+            out.push(c);
+            continue;
+        }
+        let mut digits = String::new();
+        while let Some(d) = chars.peek() {
+            if d.is_ascii_digit() {
+                digits.push(*d);
+                chars.next();
+            } else {
+                break;
+            }
+        }
+        if digits.is_empty() {
+            out.push('$');
+            continue;
+        }
+        match digits.parse::<usize>().ok().and_then(|i| i.checked_sub(1)) {
+            Some(i) if i < arg_names.len() => {
+                out.push('"');
+                out.push_str(&arg_names[i]);
+                out.push('"');
+            }
+            _ => {
+                out.push('$');
+                out.push_str(&digits);
+            }
+        }
+    }
+    out
+}
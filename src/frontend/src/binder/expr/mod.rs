@@ -140,20 +140,27 @@ impl Binder {
     pub(super) fn bind_extract(&mut self, field: String, expr: Expr) -> Result<ExprImpl> {
         let arg = self.bind_expr(expr)?;
         let arg_type = arg.return_type();
-        Ok(FunctionCall::new(
-            ExprType::Extract,
-            vec![self.bind_string(field.clone())?.into(), arg],
-        )
-        .map_err(|_| {
-            ErrorCode::NotImplemented(
-                format!(
-                    "function extract({} from {:?}) doesn't exist",
-                    field, arg_type
-                ),
-                112.into(),
-            )
-        })?
-        .into())
+        let inputs = vec![self.bind_string(field.clone())?.into(), arg];
+        // `extract(field from timestamptz)` implicitly relies on the session timezone (except
+        // for the timezone-independent `epoch` field). Bind it as an unchecked 2-argument call
+        // and let `SessionTimezone` expand it into the 3-argument
+        // `extract(field, timestamptz, timezone)` form after binding.
+        if arg_type == DataType::Timestamptz {
+            return Ok(
+                FunctionCall::new_unchecked(ExprType::Extract, inputs, DataType::Decimal).into(),
+            );
+        }
+        Ok(FunctionCall::new(ExprType::Extract, inputs)
+            .map_err(|_| {
+                ErrorCode::NotImplemented(
+                    format!(
+                        "function extract({} from {:?}) doesn't exist",
+                        field, arg_type
+                    ),
+                    112.into(),
+                )
+            })?
+            .into())
     }
 
     pub(super) fn bind_at_time_zone(&mut self, input: Expr, time_zone: String) -> Result<ExprImpl> {
@@ -467,6 +474,7 @@ pub fn bind_struct_field(column_def: &StructField) -> Result<ColumnDesc> {
                     field_descs: vec![],
                     type_name: "".to_string(),
                     generated_column: None,
+                    default_column: None,
                 })
             })
             .collect::<Result<Vec<_>>>()?
@@ -480,6 +488,7 @@ pub fn bind_struct_field(column_def: &StructField) -> Result<ColumnDesc> {
         field_descs,
         type_name: "".to_string(),
         generated_column: None,
+        default_column: None,
     })
 }
 
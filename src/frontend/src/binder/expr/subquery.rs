@@ -24,11 +24,16 @@ impl Binder {
         query: Query,
         kind: SubqueryKind,
     ) -> Result<ExprImpl> {
-        let query = self.bind_query(query)?;
+        let mut query = self.bind_query(query)?;
         if !matches!(kind, SubqueryKind::Existential) && query.data_types().len() != 1 {
-            return Err(
-                ErrorCode::BindError("Subquery must return only one column".to_string()).into(),
-            );
+            // A scalar subquery returning multiple columns is implicitly a row value, as long as
+            // it is a plain `SELECT` (not a `UNION`/`VALUES`/etc, which we don't collapse).
+            if !matches!(kind, SubqueryKind::Scalar) || !query.body.collapse_into_struct() {
+                return Err(ErrorCode::BindError(
+                    "Subquery must return only one column".to_string(),
+                )
+                .into());
+            }
         }
         Ok(Subquery::new(query, kind).into())
     }
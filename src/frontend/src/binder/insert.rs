@@ -15,11 +15,11 @@
 use std::collections::{HashMap, HashSet};
 
 use itertools::Itertools;
-use risingwave_common::catalog::{ColumnCatalog, Schema, TableVersionId};
+use risingwave_common::catalog::{ColumnCatalog, ConflictBehavior, Schema, TableVersionId};
 use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_common::types::DataType;
 use risingwave_common::util::iter_util::ZipEqFast;
-use risingwave_sqlparser::ast::{Ident, ObjectName, Query, SelectItem};
+use risingwave_sqlparser::ast::{Ident, ObjectName, OnConflict, Query, SelectItem};
 
 use super::statement::RewriteExprsRecursive;
 use super::BoundQuery;
@@ -91,6 +91,7 @@ impl Binder {
         name: ObjectName,
         cols_to_insert_by_user: Vec<Ident>,
         source: Query,
+        on_conflict: Option<OnConflict>,
         returning_items: Vec<SelectItem>,
     ) -> Result<BoundInsert> {
         let (schema_name, table_name) = Self::resolve_schema_qualified_name(&self.db_name, name)?;
@@ -99,6 +100,19 @@ impl Binder {
         let table_catalog = self.resolve_dml_table(schema_name.as_deref(), &table_name, true)?;
         let table_id = table_catalog.id;
         let owner = table_catalog.owner;
+
+        // The table's primary-key conflict handling is fixed at `CREATE TABLE` time, so an
+        // `INSERT`'s `ON CONFLICT` clause can only be the no-op that matches it, not override it.
+        match (&on_conflict, table_catalog.conflict_behavior()) {
+            (None, _)
+            | (Some(OnConflict::DoNothing), ConflictBehavior::IgnoreConflict)
+            | (Some(OnConflict::DoUpdate), ConflictBehavior::Overwrite) => {}
+            (Some(_), _) => {
+                return Err(RwError::from(ErrorCode::InvalidInputSyntax(format!(
+                    "ON CONFLICT clause does not match the ON CONFLICT behavior of table \"{table_name}\""
+                ))));
+            }
+        }
         let table_version_id = table_catalog.version_id().expect("table must be versioned");
         let cols_to_insert_in_table = table_catalog.columns_to_insert().cloned().collect_vec();
 
@@ -142,6 +156,21 @@ impl Binder {
             .iter()
             .map(|idx| cols_to_insert_in_table[*idx].data_type().clone())
             .collect();
+        // For columns the user did not list explicitly, fall back to the column's `DEFAULT`
+        // expression (if any) instead of `NULL`. Only consulted for the tail of `expected_types`
+        // that `bind_values` pads in -- see its doc comment.
+        let default_exprs: Vec<Option<ExprImpl>> = col_indices_to_insert
+            .iter()
+            .map(|idx| {
+                cols_to_insert_in_table[*idx]
+                    .column_desc
+                    .default_column
+                    .as_ref()
+                    .and_then(|d| d.expr.as_ref())
+                    .map(|expr| ExprImpl::from_expr_proto(expr))
+                    .transpose()
+            })
+            .try_collect()?;
 
         // When the column types of `source` query do not match `expected_types`,
         // casting is needed.
@@ -222,7 +251,8 @@ impl Binder {
                     return Err(RwError::from(ErrorCode::BindError(msg.to_string())));
                 }
 
-                let values = self.bind_values(values.clone(), Some(expected_types))?;
+                let values =
+                    self.bind_values(values.clone(), Some(expected_types), &default_exprs)?;
                 bound_query = BoundQuery::with_values(values);
                 cast_exprs = vec![];
             }
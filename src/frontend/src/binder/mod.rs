@@ -19,6 +19,7 @@ use itertools::Itertools;
 use risingwave_common::error::Result;
 use risingwave_common::session_config::SearchPath;
 use risingwave_common::types::DataType;
+use risingwave_common::util::epoch::Epoch;
 use risingwave_common::util::iter_util::ZipEqDebug;
 use risingwave_sqlparser::ast::Statement;
 
@@ -55,6 +56,7 @@ pub use update::BoundUpdate;
 pub use values::BoundValues;
 
 use crate::catalog::catalog_service::CatalogReadGuard;
+use crate::catalog::view_catalog::ViewCatalog;
 use crate::catalog::{TableId, ViewId};
 use crate::session::{AuthContext, SessionImpl};
 
@@ -99,6 +101,19 @@ pub struct Binder {
     included_relations: HashSet<TableId>,
 
     param_types: ParameterTypes,
+
+    /// Names of the SQL-language UDFs currently being inlined, used to detect (possibly
+    /// mutual) recursion among UDF bodies while binding.
+    udf_context: HashSet<String>,
+
+    /// Session-local views created by `CREATE TEMPORARY VIEW`, consulted before the shared
+    /// catalog so they shadow any persisted relation of the same name.
+    temporary_views: HashMap<String, Arc<ViewCatalog>>,
+
+    /// The historical epoch requested via a `FOR SYSTEM_TIME AS OF '<timestamp>'` clause, if
+    /// any. The whole query is pinned to this epoch, regardless of which relation the clause
+    /// was attached to syntactically.
+    as_of: Option<Epoch>,
 }
 
 /// `ParameterTypes` is used to record the types of the parameters during binding. It works
@@ -207,6 +222,9 @@ impl Binder {
             shared_views: HashMap::new(),
             included_relations: HashSet::new(),
             param_types: ParameterTypes::new(param_types),
+            udf_context: HashSet::new(),
+            temporary_views: session.temporary_views(),
+            as_of: None,
         }
     }
 
@@ -243,6 +261,37 @@ impl Binder {
         self.included_relations.clone()
     }
 
+    /// Returns the epoch requested by a `FOR SYSTEM_TIME AS OF '<timestamp>'` clause
+    /// encountered while binding the query, if any.
+    pub fn as_of(&self) -> Option<Epoch> {
+        self.as_of
+    }
+
+    /// Records the epoch requested by a `FOR SYSTEM_TIME AS OF '<timestamp>'` clause. Only one
+    /// such epoch is supported per query, since the whole query is pinned to a single snapshot.
+    fn bind_as_of_timestamp(&mut self, timestamp: &str) -> Result<()> {
+        if self.in_streaming {
+            return Err(ErrorCode::NotSupported(
+                "FOR SYSTEM_TIME AS OF '<timestamp>' is not supported in streaming queries"
+                    .to_string(),
+                "remove the clause, or use FOR SYSTEM_TIME AS OF NOW() for a temporal join"
+                    .to_string(),
+            )
+            .into());
+        }
+        let epoch = Epoch::from_unix_millis(parse_as_of_timestamp_millis(timestamp)?);
+        if let Some(existing) = self.as_of && existing != epoch {
+            return Err(ErrorCode::NotImplemented(
+                "only one distinct FOR SYSTEM_TIME AS OF timestamp is supported per query"
+                    .to_string(),
+                None.into(),
+            )
+            .into());
+        }
+        self.as_of = Some(epoch);
+        Ok(())
+    }
+
     fn push_context(&mut self) {
         let new_context = std::mem::take(&mut self.context);
         self.context.cte_to_relation = new_context.cte_to_relation.clone();
@@ -281,20 +330,6 @@ impl Binder {
         Ok(())
     }
 
-    fn try_mark_lateral_as_visible(&mut self) {
-        if let Some(mut ctx) = self.lateral_contexts.pop() {
-            ctx.is_visible = true;
-            self.lateral_contexts.push(ctx);
-        }
-    }
-
-    fn try_mark_lateral_as_invisible(&mut self) {
-        if let Some(mut ctx) = self.lateral_contexts.pop() {
-            ctx.is_visible = false;
-            self.lateral_contexts.push(ctx);
-        }
-    }
-
     fn next_subquery_id(&mut self) -> usize {
         let id = self.next_subquery_id;
         self.next_subquery_id += 1;
@@ -332,6 +367,16 @@ pub mod test_utils {
     }
 }
 
+/// Parses a `FOR SYSTEM_TIME AS OF '<timestamp>'` literal into milliseconds since the Unix
+/// epoch, for conversion into a RisingWave [`Epoch`].
+fn parse_as_of_timestamp_millis(timestamp: &str) -> Result<u64> {
+    use risingwave_expr::vector_op::cast::str_to_timestamp;
+
+    let ts = str_to_timestamp(timestamp)
+        .map_err(|_| ErrorCode::InvalidInputSyntax(format!("invalid timestamp: {}", timestamp)))?;
+    Ok(ts.0.timestamp_millis().max(0) as u64)
+}
+
 /// The column name stored in [`BindContext`] for a column without an alias.
 pub const UNNAMED_COLUMN: &str = "?column?";
 /// The table name stored in [`BindContext`] for a subquery without an alias.
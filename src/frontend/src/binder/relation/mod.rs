@@ -24,7 +24,8 @@ use risingwave_common::catalog::{
 use risingwave_common::error::{internal_error, ErrorCode, Result, RwError};
 use risingwave_common::types::DataType;
 use risingwave_sqlparser::ast::{
-    Expr as ParserExpr, FunctionArg, FunctionArgExpr, Ident, ObjectName, TableAlias, TableFactor,
+    AsOf, Expr as ParserExpr, FunctionArg, FunctionArgExpr, Ident, ObjectName, TableAlias,
+    TableFactor,
 };
 
 use self::watermark::is_watermark_func;
@@ -109,7 +110,11 @@ impl Relation {
         match self {
             Relation::Subquery(subquery) => subquery
                 .query
-                .collect_correlated_indices_by_depth_and_assign_id(depth + 1, correlated_id),
+                .collect_correlated_indices_by_depth_and_assign_id(depth, correlated_id)
+                .into_iter()
+                .sorted()
+                .dedup()
+                .collect(),
             Relation::Join(join) => {
                 let mut correlated_indices = vec![];
                 correlated_indices.extend(
@@ -289,7 +294,7 @@ impl Binder {
         &mut self,
         name: ObjectName,
         alias: Option<TableAlias>,
-        for_system_time_as_of_now: bool,
+        as_of: Option<AsOf>,
     ) -> Result<Relation> {
         let (schema_name, table_name) = Self::resolve_schema_qualified_name(&self.db_name, name)?;
         if schema_name.is_none() && let Some(item) = self.context.cte_to_relation.get(&table_name) {
@@ -325,7 +330,7 @@ impl Binder {
             Ok(share_relation)
         } else {
 
-            self.bind_relation_by_name_inner(schema_name.as_deref(), &table_name, alias, for_system_time_as_of_now)
+            self.bind_relation_by_name_inner(schema_name.as_deref(), &table_name, alias, as_of)
         }
     }
 
@@ -345,7 +350,7 @@ impl Binder {
         }?;
 
         Ok((
-            self.bind_relation_by_name(table_name.clone(), None, false)?,
+            self.bind_relation_by_name(table_name.clone(), None, None)?,
             table_name,
         ))
     }
@@ -390,16 +395,14 @@ impl Binder {
             .map_or(DEFAULT_SCHEMA_NAME.to_string(), |arg| arg.to_string());
 
         let table_name = self.catalog.get_table_name_by_id(table_id)?;
-        self.bind_relation_by_name_inner(Some(&schema), &table_name, alias, false)
+        self.bind_relation_by_name_inner(Some(&schema), &table_name, alias, None)
     }
 
     pub(super) fn bind_table_factor(&mut self, table_factor: TableFactor) -> Result<Relation> {
         match table_factor {
-            TableFactor::Table {
-                name,
-                alias,
-                for_system_time_as_of_now,
-            } => self.bind_relation_by_name(name, alias, for_system_time_as_of_now),
+            TableFactor::Table { name, alias, as_of } => {
+                self.bind_relation_by_name(name, alias, as_of)
+            }
             TableFactor::TableFunction { name, alias, args } => {
                 let func_name = &name.0[0].real_value();
                 if func_name.eq_ignore_ascii_case(RW_INTERNAL_TABLE_FUNCTION_NAME) {
@@ -415,7 +418,7 @@ impl Binder {
                         Some(PG_CATALOG_SCHEMA_NAME),
                         PG_KEYWORDS_TABLE_NAME,
                         alias,
-                        false,
+                        None,
                     );
                 }
                 if let Ok(kind) = WindowTableFunctionKind::from_str(func_name) {
@@ -473,18 +476,13 @@ impl Binder {
                 alias,
             } => {
                 if lateral {
-                    // If we detect a lateral, we mark the lateral context as visible.
-                    self.try_mark_lateral_as_visible();
-
-                    // Bind lateral subquery here.
-
-                    // Mark the lateral context as invisible once again.
-                    self.try_mark_lateral_as_invisible();
-                    Err(ErrorCode::NotImplemented(
-                        "lateral subqueries are not yet supported".into(),
-                        Some(3815).into(),
-                    )
-                    .into())
+                    // Unlike a non-lateral subquery, a `LATERAL` subquery may refer to columns
+                    // of the preceding item in the same join clause. We bind it directly
+                    // against the current context (instead of hiding the join-tree built so
+                    // far, as the non-lateral case below does), so that those columns are
+                    // visible as correlated references inside the subquery.
+                    let bound_subquery = self.bind_subquery_relation(*subquery, alias)?;
+                    Ok(Relation::Subquery(Box::new(bound_subquery)))
                 } else {
                     // Non-lateral subqueries to not have access to the join-tree context.
                     self.push_lateral_context();
@@ -19,7 +19,7 @@ use itertools::Itertools;
 use risingwave_common::catalog::{Field, SYSTEM_SCHEMAS};
 use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_common::session_config::USER_NAME_WILD_CARD;
-use risingwave_sqlparser::ast::{Statement, TableAlias};
+use risingwave_sqlparser::ast::{AsOf, Statement, TableAlias};
 use risingwave_sqlparser::parser::Parser;
 
 use super::BoundShare;
@@ -64,8 +64,13 @@ impl Binder {
         schema_name: Option<&str>,
         table_name: &str,
         alias: Option<TableAlias>,
-        for_system_time_as_of_now: bool,
+        as_of: Option<AsOf>,
     ) -> Result<Relation> {
+        if let Some(AsOf::TimestampString(ref timestamp)) = as_of {
+            self.bind_as_of_timestamp(timestamp)?;
+        }
+        let for_system_time_as_of_now = matches!(as_of, Some(AsOf::ProcessTime));
+
         fn is_system_schema(schema_name: &str) -> bool {
             SYSTEM_SCHEMAS.iter().any(|s| *s == schema_name)
         }
@@ -86,6 +91,15 @@ impl Binder {
             )
         };
 
+        // A temporary view shadows any persisted relation of the same (unqualified) name.
+        if schema_name.is_none()
+            && let Some(view_catalog) = self.temporary_views.get(table_name).cloned()
+        {
+            let (ret, columns) = self.resolve_view_relation(&view_catalog)?;
+            self.bind_table_to_context(columns, table_name.to_string(), alias)?;
+            return Ok(ret);
+        }
+
         // start to bind
         let (ret, columns) = {
             match schema_name {
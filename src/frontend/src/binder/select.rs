@@ -105,6 +105,30 @@ impl BoundSelect {
             }
     }
 
+    /// Collapses a multi-column select into a single struct-typed column, so that it can be used
+    /// where only one column is allowed, e.g. as a scalar subquery: `select (select a, b from t)`.
+    ///
+    /// No-op if this select already returns a single column.
+    pub fn collapse_into_struct(&mut self) {
+        if self.select_items.len() <= 1 {
+            return;
+        }
+        let field_names = self
+            .schema
+            .fields
+            .iter()
+            .map(|f| f.name.clone())
+            .collect_vec();
+        let item_types = self.select_items.iter().map(|e| e.return_type()).collect_vec();
+        let data_type = DataType::new_struct(item_types, field_names);
+        let items = std::mem::take(&mut self.select_items);
+        let struct_expr: ExprImpl =
+            FunctionCall::new_unchecked(ExprType::Row, items, data_type.clone()).into();
+        self.select_items = vec![struct_expr];
+        self.aliases = vec![None];
+        self.schema = Schema::new(vec![Field::unnamed(data_type)]);
+    }
+
     pub fn collect_correlated_indices_by_depth_and_assign_id(
         &mut self,
         depth: Depth,
@@ -70,6 +70,18 @@ impl BoundSetExpr {
         }
     }
 
+    /// Collapses a multi-column `SELECT` body into a single struct-typed column. Only a plain
+    /// `SELECT` (no set operation, union/except/intersect) can be collapsed this way.
+    pub fn collapse_into_struct(&mut self) -> bool {
+        match self {
+            BoundSetExpr::Select(s) => {
+                s.collapse_into_struct();
+                true
+            }
+            _ => false,
+        }
+    }
+
     pub fn is_correlated(&self, depth: Depth) -> bool {
         match self {
             BoundSetExpr::Select(s) => s.is_correlated(depth),
@@ -114,7 +126,7 @@ impl Binder {
     pub(super) fn bind_set_expr(&mut self, set_expr: SetExpr) -> Result<BoundSetExpr> {
         match set_expr {
             SetExpr::Select(s) => Ok(BoundSetExpr::Select(Box::new(self.bind_select(*s)?))),
-            SetExpr::Values(v) => Ok(BoundSetExpr::Values(Box::new(self.bind_values(v, None)?))),
+            SetExpr::Values(v) => Ok(BoundSetExpr::Values(Box::new(self.bind_values(v, None, &[])?))),
             SetExpr::Query(q) => Ok(BoundSetExpr::Query(Box::new(self.bind_query(*q)?))),
             SetExpr::SetOperation {
                 op,
@@ -122,58 +134,57 @@ impl Binder {
                 left,
                 right,
             } => {
-                match op {
-                    SetOperator::Union => {
-                        let left = Box::new(self.bind_set_expr(*left)?);
-                        // Reset context for right side, but keep `cte_to_relation`.
-                        let new_context = std::mem::take(&mut self.context);
-                        self.context.cte_to_relation = new_context.cte_to_relation.clone();
-                        let right = Box::new(self.bind_set_expr(*right)?);
+                let bound_op = match &op {
+                    SetOperator::Union => BoundSetOperation::Union,
+                    SetOperator::Except => BoundSetOperation::Except,
+                    SetOperator::Intersect => BoundSetOperation::Intersect,
+                };
 
-                        if left.schema().fields.len() != right.schema().fields.len() {
-                            return Err(ErrorCode::InvalidInputSyntax(
-                                "each UNION query must have the same number of columns".to_string(),
-                            )
-                            .into());
-                        }
+                let left = Box::new(self.bind_set_expr(*left)?);
+                // Reset context for right side, but keep `cte_to_relation`.
+                let new_context = std::mem::take(&mut self.context);
+                self.context.cte_to_relation = new_context.cte_to_relation.clone();
+                let right = Box::new(self.bind_set_expr(*right)?);
 
-                        for (a, b) in left
-                            .schema()
-                            .fields
-                            .iter()
-                            .zip_eq_fast(right.schema().fields.iter())
-                        {
-                            if a.data_type != b.data_type {
-                                return Err(ErrorCode::InvalidInputSyntax(format!(
-                                    "UNION types {} of column {} is different from types {} of column {}",
-                                    a.data_type.prost_type_name().as_str_name(),
-                                    a.name,
-                                    b.data_type.prost_type_name().as_str_name(),
-                                    b.name,
-                                ))
-                                    .into());
-                            }
-                        }
+                if left.schema().fields.len() != right.schema().fields.len() {
+                    return Err(ErrorCode::InvalidInputSyntax(format!(
+                        "each {} query must have the same number of columns",
+                        op
+                    ))
+                    .into());
+                }
 
-                        // Reset context for the set operation.
-                        // Consider this case:
-                        // select a from t2 union all select b from t2 order by a+1; should throw an
-                        // error.
-                        self.context = BindContext::default();
-                        self.context.cte_to_relation = new_context.cte_to_relation;
-                        Ok(BoundSetExpr::SetOperation {
-                            op: BoundSetOperation::Union,
-                            all,
-                            left,
-                            right,
-                        })
+                for (a, b) in left
+                    .schema()
+                    .fields
+                    .iter()
+                    .zip_eq_fast(right.schema().fields.iter())
+                {
+                    if a.data_type != b.data_type {
+                        return Err(ErrorCode::InvalidInputSyntax(format!(
+                            "{} types {} of column {} is different from types {} of column {}",
+                            op,
+                            a.data_type.prost_type_name().as_str_name(),
+                            a.name,
+                            b.data_type.prost_type_name().as_str_name(),
+                            b.name,
+                        ))
+                            .into());
                     }
-                    SetOperator::Intersect | SetOperator::Except => Err(ErrorCode::NotImplemented(
-                        format!("set expr: {:?}", op),
-                        None.into(),
-                    )
-                    .into()),
                 }
+
+                // Reset context for the set operation.
+                // Consider this case:
+                // select a from t2 union all select b from t2 order by a+1; should throw an
+                // error.
+                self.context = BindContext::default();
+                self.context.cte_to_relation = new_context.cte_to_relation;
+                Ok(BoundSetExpr::SetOperation {
+                    op: bound_op,
+                    all,
+                    left,
+                    right,
+                })
             }
         }
     }
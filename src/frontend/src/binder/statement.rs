@@ -56,9 +56,10 @@ impl Binder {
                 table_name,
                 columns,
                 source,
+                on_conflict,
                 returning,
             } => Ok(BoundStatement::Insert(
-                self.bind_insert(table_name, columns, *source, returning)?
+                self.bind_insert(table_name, columns, *source, on_conflict, returning)?
                     .into(),
             )),
 
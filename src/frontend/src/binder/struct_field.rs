@@ -61,7 +61,7 @@ impl Binder {
 
     /// Bind field in recursive way. It could return a couple Field expressions
     /// if it ends with a wildcard.
-    fn bind_field(
+    pub(crate) fn bind_field(
         field_name: String,
         expr: ExprImpl,
         idents: &[Ident],
@@ -154,8 +154,15 @@ impl Binder {
                     AssignmentValue::Expr(expr) => {
                         self.bind_expr(expr)?.cast_assign(id_expr.return_type())?
                     }
-                    // TODO: specify default expression after we support non-`NULL` default values.
-                    AssignmentValue::Default => ExprImpl::literal_null(id_expr.return_type()),
+                    AssignmentValue::Default => table_catalog
+                        .columns()
+                        .iter()
+                        .find(|c| c.name() == id.real_value())
+                        .and_then(|c| c.column_desc.default_column.as_ref())
+                        .and_then(|d| d.expr.as_ref())
+                        .map(ExprImpl::from_expr_proto)
+                        .transpose()?
+                        .unwrap_or_else(|| ExprImpl::literal_null(id_expr.return_type())),
                 };
 
                 match assignment_exprs.entry(id_expr) {
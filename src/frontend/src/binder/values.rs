@@ -84,11 +84,14 @@ fn values_column_name(values_id: usize, col_id: usize) -> String {
 impl Binder {
     /// Bind [`Values`] with given `expected_types`. If no types are expected, a compatible type for
     /// all rows will be used.
-    /// If values are shorter than expected, `NULL`s will be filled.
+    /// If values are shorter than expected, the column's default expression is used if one is
+    /// configured (via `missing_col_defaults`, aligned with the tail of `expected_types`),
+    /// falling back to `NULL` otherwise.
     pub(super) fn bind_values(
         &mut self,
         values: Values,
         expected_types: Option<Vec<DataType>>,
+        missing_col_defaults: &[Option<ExprImpl>],
     ) -> Result<BoundValues> {
         assert!(!values.0.is_empty());
 
@@ -109,11 +112,15 @@ impl Binder {
             );
         }
         if let Some(expected_types) = &expected_types && expected_types.len() > num_columns {
-            let nulls_to_insert = expected_types.len() - num_columns;
+            let missing_cols = expected_types.len() - num_columns;
             for row in &mut bound {
-                for i in 0..nulls_to_insert {
-                    let t = expected_types[num_columns + i].clone();
-                    row.push(ExprImpl::literal_null(t));
+                for i in 0..missing_cols {
+                    let col_index = num_columns + i;
+                    let t = expected_types[col_index].clone();
+                    let default_expr = missing_col_defaults
+                        .get(col_index)
+                        .and_then(|d| d.clone());
+                    row.push(default_expr.unwrap_or_else(|| ExprImpl::literal_null(t)));
                 }
             }
             num_columns = expected_types.len();
@@ -183,7 +190,7 @@ mod tests {
         let expr1 = Expr::Value(Value::Number("1".to_string()));
         let expr2 = Expr::Value(Value::Number("1.1".to_string()));
         let values = Values(vec![vec![expr1], vec![expr2]]);
-        let res = binder.bind_values(values, None).unwrap();
+        let res = binder.bind_values(values, None, &[]).unwrap();
 
         let types = vec![DataType::Decimal];
         let n_cols = types.len();
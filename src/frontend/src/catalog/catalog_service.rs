@@ -21,7 +21,7 @@ use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::{Result, RwError};
 use risingwave_common::util::column_index_mapping::ColIndexMapping;
 use risingwave_pb::catalog::{
-    PbDatabase, PbFunction, PbIndex, PbSchema, PbSink, PbSource, PbTable, PbView,
+    PbComment, PbDatabase, PbFunction, PbIndex, PbSchema, PbSink, PbSource, PbTable, PbView,
 };
 use risingwave_pb::ddl_service::alter_relation_name_request::Relation;
 use risingwave_pb::ddl_service::create_connection_request;
@@ -30,12 +30,16 @@ use risingwave_rpc_client::MetaClient;
 use tokio::sync::watch::Receiver;
 
 use super::root_catalog::Catalog;
+use super::table_stats::TableStats;
 use super::DatabaseId;
 use crate::user::UserId;
 
 pub type CatalogReadGuard = ArcRwLockReadGuard<RawRwLock, Catalog>;
 
 /// [`CatalogReader`] can read catalog from local catalog and force the holder can not modify it.
+///
+/// The one exception is [`Self::update_table_stats`]: `ANALYZE` statistics are a local cache that
+/// never goes through meta, so they're updated in place here rather than via [`CatalogWriter`].
 #[derive(Clone)]
 pub struct CatalogReader(Arc<RwLock<Catalog>>);
 impl CatalogReader {
@@ -47,6 +51,12 @@ impl CatalogReader {
         // Make this recursive so that one can get this guard in the same thread without fear.
         self.0.read_arc_recursive()
     }
+
+    /// Record statistics collected by `ANALYZE` for `table_id`. See [`TableStats`] for why this
+    /// bypasses the usual meta round-trip.
+    pub fn update_table_stats(&self, table_id: TableId, stats: TableStats) {
+        self.0.write_arc().update_table_stats(table_id, stats);
+    }
 }
 
 /// [`CatalogWriter`] initiate DDL operations (create table/schema/database/function/connection).
@@ -134,6 +144,8 @@ pub trait CatalogWriter: Send + Sync {
     async fn alter_sink_name(&self, sink_id: u32, sink_name: &str) -> Result<()>;
 
     async fn alter_source_name(&self, source_id: u32, source_name: &str) -> Result<()>;
+
+    async fn comment_on(&self, comment: PbComment) -> Result<()>;
 }
 
 #[derive(Clone)]
@@ -341,6 +353,11 @@ impl CatalogWriter for CatalogWriterImpl {
             .await?;
         self.wait_version(version).await
     }
+
+    async fn comment_on(&self, comment: PbComment) -> Result<()> {
+        let version = self.meta_client.comment_on(comment).await?;
+        self.wait_version(version).await
+    }
 }
 
 impl CatalogWriterImpl {
@@ -28,6 +28,11 @@ pub struct FunctionCatalog {
     pub language: String,
     pub identifier: String,
     pub link: String,
+    /// Names of `arg_types`, in declaration order. Only meaningful for `language` `sql`.
+    pub arg_names: Vec<String>,
+    /// The function body. Only present for `language` `sql`, where it is inlined at call sites
+    /// instead of being executed by an external service.
+    pub body: Option<String>,
 }
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
@@ -60,6 +65,8 @@ impl From<&PbFunction> for FunctionCatalog {
             language: prost.language.clone(),
             identifier: prost.identifier.clone(),
             link: prost.link.clone(),
+            arg_names: prost.arg_names.clone(),
+            body: prost.body.clone(),
         }
     }
 }
@@ -33,10 +33,12 @@ pub(crate) mod schema_catalog;
 pub(crate) mod source_catalog;
 pub(crate) mod system_catalog;
 pub(crate) mod table_catalog;
+pub(crate) mod table_stats;
 pub(crate) mod view_catalog;
 
 pub use index_catalog::IndexCatalog;
 pub use table_catalog::TableCatalog;
+pub use table_stats::TableStats;
 
 use crate::user::UserId;
 
@@ -27,6 +27,7 @@ use risingwave_pb::catalog::{
 use super::function_catalog::FunctionCatalog;
 use super::source_catalog::SourceCatalog;
 use super::system_catalog::get_sys_catalogs_in_schema;
+use super::table_stats::TableStats;
 use super::view_catalog::ViewCatalog;
 use super::{CatalogError, CatalogResult, SinkId, SourceId, ViewId};
 use crate::catalog::connection_catalog::ConnectionCatalog;
@@ -97,6 +98,9 @@ pub struct Catalog {
     table_by_id: HashMap<TableId, Arc<TableCatalog>>,
     connection_by_id: HashMap<ConnectionId, ConnectionCatalog>,
     connection_id_by_name: HashMap<String, ConnectionId>,
+    /// Statistics collected by `ANALYZE`, keyed by table id. See [`TableStats`] for why this
+    /// lives outside the regular catalog replication path.
+    table_stats_by_id: HashMap<TableId, Arc<TableStats>>,
 }
 
 #[expect(clippy::derivable_impls)]
@@ -109,6 +113,7 @@ impl Default for Catalog {
             table_by_id: HashMap::new(),
             connection_by_id: HashMap::new(), // TODO: move to schema_catalog
             connection_id_by_name: HashMap::new(), // TODO: move to schema_catalog
+            table_stats_by_id: HashMap::new(),
         }
     }
 }
@@ -123,6 +128,17 @@ impl Catalog {
         self.database_by_name.clear();
         self.db_name_by_id.clear();
         self.table_by_id.clear();
+        self.table_stats_by_id.clear();
+    }
+
+    /// Record statistics collected by `ANALYZE` for `table_id`, overwriting any previous value.
+    pub fn update_table_stats(&mut self, table_id: TableId, stats: TableStats) {
+        self.table_stats_by_id.insert(table_id, Arc::new(stats));
+    }
+
+    /// The statistics collected by the last `ANALYZE` of `table_id`, if any.
+    pub fn get_table_stats(&self, table_id: TableId) -> Option<Arc<TableStats>> {
+        self.table_stats_by_id.get(&table_id).cloned()
     }
 
     pub fn create_database(&mut self, db: &PbDatabase) {
@@ -228,6 +244,7 @@ impl Catalog {
 
     pub fn drop_table(&mut self, db_id: DatabaseId, schema_id: SchemaId, tb_id: TableId) {
         self.table_by_id.remove(&tb_id);
+        self.table_stats_by_id.remove(&tb_id);
         self.get_database_mut(db_id)
             .unwrap()
             .get_schema_mut(schema_id)
@@ -38,6 +38,8 @@ pub struct SourceCatalog {
     pub properties: BTreeMap<String, String>,
     pub watermark_descs: Vec<WatermarkDesc>,
     pub associated_table_id: Option<TableId>,
+    /// The full `CREATE SOURCE` definition of the source.
+    pub definition: String,
 }
 
 impl From<&PbSource> for SourceCatalog {
@@ -78,10 +80,18 @@ impl From<&PbSource> for SourceCatalog {
             properties: with_options.into_inner(),
             watermark_descs,
             associated_table_id: associated_table_id.map(|x| x.into()),
+            definition: prost.definition.clone(),
         }
     }
 }
 
+impl SourceCatalog {
+    /// Returns the SQL statement that can be used to create this source.
+    pub fn create_sql(&self) -> String {
+        self.definition.clone()
+    }
+}
+
 impl RelationCatalog for SourceCatalog {
     fn owner(&self) -> UserId {
         self.owner
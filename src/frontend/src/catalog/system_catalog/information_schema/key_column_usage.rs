@@ -0,0 +1,32 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::DataType;
+
+use crate::catalog::system_catalog::SystemCatalogColumnsDef;
+
+/// The view `key_column_usage` identifies columns that are constrained by a primary key or
+/// unique constraint. RisingWave only surfaces primary key columns today.
+/// Ref: [`https://www.postgresql.org/docs/current/infoschema-key-column-usage.html`]
+pub const KEY_COLUMN_USAGE_TABLE_NAME: &str = "key_column_usage";
+pub const KEY_COLUMN_USAGE_COLUMNS: &[SystemCatalogColumnsDef<'_>] = &[
+    (DataType::Varchar, "constraint_catalog"),
+    (DataType::Varchar, "constraint_schema"),
+    (DataType::Varchar, "constraint_name"),
+    (DataType::Varchar, "table_catalog"),
+    (DataType::Varchar, "table_schema"),
+    (DataType::Varchar, "table_name"),
+    (DataType::Varchar, "column_name"),
+    (DataType::Int32, "ordinal_position"),
+];
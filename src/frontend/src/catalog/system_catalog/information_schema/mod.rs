@@ -13,14 +13,18 @@
 // limitations under the License.
 
 pub mod columns;
+pub mod key_column_usage;
 pub mod tables;
+pub mod views;
 
 pub use columns::*;
 use itertools::Itertools;
+pub use key_column_usage::*;
 use risingwave_common::error::Result;
 use risingwave_common::row::OwnedRow;
 use risingwave_common::types::ScalarImpl;
 pub use tables::*;
+pub use views::*;
 
 use super::SysCatalogReaderImpl;
 
@@ -132,4 +136,51 @@ impl SysCatalogReaderImpl {
             })
             .collect_vec())
     }
+
+    pub(super) fn read_information_schema_views_info(&self) -> Result<Vec<OwnedRow>> {
+        let reader = self.catalog_reader.read_guard();
+        let schemas = reader.iter_schemas(&self.auth_context.database)?;
+
+        Ok(schemas
+            .flat_map(|schema| {
+                schema.iter_view().map(|view| {
+                    OwnedRow::new(vec![
+                        Some(ScalarImpl::Utf8(self.auth_context.database.clone().into())),
+                        Some(ScalarImpl::Utf8(schema.name().into())),
+                        Some(ScalarImpl::Utf8(view.name().into())),
+                        Some(ScalarImpl::Utf8(view.sql.clone().into())),
+                    ])
+                })
+            })
+            .collect_vec())
+    }
+
+    pub(super) fn read_key_column_usage_info(&self) -> Result<Vec<OwnedRow>> {
+        let reader = self.catalog_reader.read_guard();
+        let schemas = reader.iter_schemas(&self.auth_context.database)?;
+
+        Ok(schemas
+            .flat_map(|schema| {
+                schema
+                    .iter_valid_table()
+                    .filter(|table| !table.pk.is_empty())
+                    .flat_map(|table| {
+                        table.pk.iter().map(|order| {
+                            let column = &table.columns()[order.column_index];
+                            OwnedRow::new(vec![
+                                Some(ScalarImpl::Utf8(self.auth_context.database.clone().into())),
+                                Some(ScalarImpl::Utf8(schema.name().into())),
+                                Some(ScalarImpl::Utf8(format!("{}_pkey", table.name).into())),
+                                Some(ScalarImpl::Utf8(self.auth_context.database.clone().into())),
+                                Some(ScalarImpl::Utf8(schema.name().into())),
+                                Some(ScalarImpl::Utf8(table.name.clone().into())),
+                                Some(ScalarImpl::Utf8(column.name().into())),
+                                Some(ScalarImpl::Int32(order.column_index as i32 + 1)),
+                            ])
+                        })
+                    })
+                    .collect_vec()
+            })
+            .collect_vec())
+    }
 }
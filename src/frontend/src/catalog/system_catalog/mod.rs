@@ -132,6 +132,7 @@ macro_rules! def_sys_catalog {
                         field_descs: vec![],
                         type_name: "".to_string(),
                         generated_column: None,
+                        default_column: None,
                     },
                     is_hidden: false,
                 })
@@ -187,6 +188,7 @@ prepare_sys_catalog! {
     { PG_CATALOG, PG_USER, vec![0], read_user_info },
     { PG_CATALOG, PG_CLASS, vec![0], read_class_info },
     { PG_CATALOG, PG_INDEX, vec![0], read_index_info },
+    { PG_CATALOG, PG_CONSTRAINT, vec![0], read_constraint_info },
     { PG_CATALOG, PG_OPCLASS, vec![0], read_opclass_info },
     { PG_CATALOG, PG_COLLATION, vec![0], read_collation_info },
     { PG_CATALOG, PG_AM, vec![0], read_am_info },
@@ -206,6 +208,8 @@ prepare_sys_catalog! {
     { PG_CATALOG, PG_CONVERSION, vec![0], read_conversion_info },
     { INFORMATION_SCHEMA, COLUMNS, vec![], read_columns_info },
     { INFORMATION_SCHEMA, TABLES, vec![], read_tables_info },
+    { INFORMATION_SCHEMA, VIEWS, vec![], read_information_schema_views_info },
+    { INFORMATION_SCHEMA, KEY_COLUMN_USAGE, vec![], read_key_column_usage_info },
     { RW_CATALOG, RW_META_SNAPSHOT, vec![], read_meta_snapshot await },
     { RW_CATALOG, RW_DDL_PROGRESS, vec![], read_ddl_progress await },
 }
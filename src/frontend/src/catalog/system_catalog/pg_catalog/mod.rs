@@ -18,6 +18,7 @@ pub mod pg_attribute;
 pub mod pg_cast;
 pub mod pg_class;
 pub mod pg_collation;
+pub mod pg_constraint;
 pub mod pg_conversion;
 pub mod pg_database;
 pub mod pg_description;
@@ -46,6 +47,7 @@ pub use pg_attribute::*;
 pub use pg_cast::*;
 pub use pg_class::*;
 pub use pg_collation::*;
+pub use pg_constraint::*;
 pub use pg_conversion::*;
 pub use pg_database::*;
 pub use pg_description::*;
@@ -75,6 +77,7 @@ use risingwave_pb::user::UserInfo;
 use serde_json::json;
 
 use super::SysCatalogReaderImpl;
+use crate::catalog::table_catalog::TableCatalog;
 use crate::user::user_privilege::available_prost_privilege;
 use crate::user::UserId;
 
@@ -450,6 +453,41 @@ impl SysCatalogReaderImpl {
             .collect_vec())
     }
 
+    pub(super) fn read_constraint_info(&self) -> Result<Vec<OwnedRow>> {
+        let reader = self.catalog_reader.read_guard();
+        let schemas = reader.iter_schemas(&self.auth_context.database)?;
+        let schema_infos = reader.get_all_schema_info(&self.auth_context.database)?;
+
+        Ok(schemas
+            .zip_eq_debug(schema_infos.iter())
+            .flat_map(|(schema, schema_info)| {
+                schema
+                    .iter_valid_table()
+                    .filter(|table| !table.pk.is_empty())
+                    .map(|table| {
+                        OwnedRow::new(vec![
+                            Some(ScalarImpl::Int32(table.id.table_id() as i32)),
+                            Some(ScalarImpl::Utf8(format!("{}_pkey", table.name).into())),
+                            Some(ScalarImpl::Int32(schema_info.id as i32)),
+                            Some(ScalarImpl::Utf8("p".into())),
+                            Some(ScalarImpl::Int32(table.id.table_id() as i32)),
+                            None,
+                            Some(ScalarImpl::List(ListValue::new(
+                                table
+                                    .pk
+                                    .iter()
+                                    .map(|order| {
+                                        Some(ScalarImpl::Int16(order.column_index as i16 + 1))
+                                    })
+                                    .collect_vec(),
+                            ))),
+                        ])
+                    })
+                    .collect_vec()
+            })
+            .collect_vec())
+    }
+
     pub(super) async fn read_mviews_info(&self) -> Result<Vec<OwnedRow>> {
         let mut table_ids = Vec::new();
         {
@@ -582,16 +620,42 @@ impl SysCatalogReaderImpl {
         let reader = self.catalog_reader.read_guard();
         let schemas = reader.iter_schemas(&self.auth_context.database)?;
 
+        // Emits the table/mv's own description (`objsubid` 0), plus one row per column that has
+        // a `COMMENT ON COLUMN`, using the column's `attnum` (1-based position, matching
+        // `pg_attribute`) as `objsubid`.
+        fn table_and_column_rows(table: &TableCatalog) -> Vec<OwnedRow> {
+            let mut rows = vec![new_pg_description_row_inner(
+                table.id.table_id,
+                0,
+                table.description.clone(),
+            )];
+            rows.extend(table.columns().iter().enumerate().filter_map(
+                |(index, column)| {
+                    table
+                        .column_comments
+                        .get(&column.column_id())
+                        .map(|description| {
+                            new_pg_description_row_inner(
+                                table.id.table_id,
+                                index as i32 + 1,
+                                Some(description.clone()),
+                            )
+                        })
+                },
+            ));
+            rows
+        }
+
         Ok(schemas
             .flat_map(|schema| {
                 let rows = schema
                     .iter_table()
-                    .map(|table| new_pg_description_row(table.id().table_id))
+                    .flat_map(|table| table_and_column_rows(table))
                     .collect_vec();
 
                 let mvs = schema
                     .iter_mv()
-                    .map(|mv| new_pg_description_row(mv.id().table_id))
+                    .flat_map(|mv| table_and_column_rows(mv))
                     .collect_vec();
 
                 let indexes = schema
@@ -0,0 +1,41 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::LazyLock;
+
+use risingwave_common::types::DataType;
+
+use crate::catalog::system_catalog::SystemCatalogColumnsDef;
+
+/// The catalog `pg_constraint` records primary key, unique, check, and foreign key constraints
+/// on tables. RisingWave only surfaces primary keys today, derived from the row's pk.
+/// Ref: [`https://www.postgresql.org/docs/current/catalog-pg-constraint.html`]
+pub const PG_CONSTRAINT_TABLE_NAME: &str = "pg_constraint";
+pub static PG_CONSTRAINT_COLUMNS: LazyLock<Vec<SystemCatalogColumnsDef<'_>>> = LazyLock::new(|| {
+    vec![
+        (DataType::Int32, "oid"),
+        (DataType::Varchar, "conname"),
+        (DataType::Int32, "connamespace"),
+        (DataType::Varchar, "contype"),
+        (DataType::Int32, "conrelid"),
+        // None. RisingWave doesn't support foreign keys yet.
+        (DataType::Int32, "confrelid"),
+        (
+            DataType::List {
+                datatype: Box::new(DataType::Int16),
+            },
+            "conkey",
+        ),
+    ]
+});
@@ -31,10 +31,20 @@ pub const PG_DESCRIPTION_COLUMNS: &[SystemCatalogColumnsDef<'_>] = &[
 ];
 
 pub fn new_pg_description_row(id: u32) -> OwnedRow {
+    new_pg_description_row_inner(id, 0, None)
+}
+
+/// Builds a `pg_description` row for a single object, or a single column of it when `objsubid` is
+/// non-zero (following Postgres' convention of using the column's `attnum` as `objsubid`).
+pub fn new_pg_description_row_inner(
+    id: u32,
+    objsubid: i32,
+    description: Option<String>,
+) -> OwnedRow {
     OwnedRow::new(vec![
         Some(ScalarImpl::Int32(id as i32)),
         None,
-        Some(ScalarImpl::Int32(0)),
-        None,
+        Some(ScalarImpl::Int32(objsubid)),
+        description.map(|d| ScalarImpl::Utf8(d.into())),
     ])
 }
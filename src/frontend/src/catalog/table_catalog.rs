@@ -131,6 +131,14 @@ pub struct TableCatalog {
     /// Optional field specifies the distribution key indices in pk.
     /// See https://github.com/risingwavelabs/risingwave/issues/8377 for more information.
     pub dist_key_in_pk: Vec<usize>,
+
+    /// Set by `COMMENT ON TABLE`/`COMMENT ON MATERIALIZED VIEW`. Exposed to users via
+    /// `pg_description`.
+    pub description: Option<String>,
+
+    /// Set by `COMMENT ON COLUMN`, keyed by the column's [`ColumnId`]. Exposed to users via
+    /// `pg_description`.
+    pub column_comments: HashMap<ColumnId, String>,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -373,6 +381,12 @@ impl TableCatalog {
             watermark_indices: self.watermark_columns.ones().map(|x| x as _).collect_vec(),
             dist_key_in_pk: self.dist_key_in_pk.iter().map(|x| *x as _).collect(),
             handle_pk_conflict_behavior: self.conflict_behavior.to_protobuf().into(),
+            description: self.description.clone(),
+            column_comments: self
+                .column_comments
+                .iter()
+                .map(|(column_id, description)| (column_id.get_id(), description.clone()))
+                .collect(),
         }
     }
 
@@ -451,6 +465,12 @@ impl From<PbTable> for TableCatalog {
             version: tb.version.map(TableVersion::from_prost),
             watermark_columns,
             dist_key_in_pk: tb.dist_key_in_pk.iter().map(|x| *x as _).collect(),
+            description: tb.description,
+            column_comments: tb
+                .column_comments
+                .into_iter()
+                .map(|(column_id, description)| (ColumnId::new(column_id), description))
+                .collect(),
         }
     }
 }
@@ -562,6 +582,7 @@ mod tests {
                             ],
                             type_name: ".test.Country".to_string(),
                             generated_column: None,
+                            default_column: None,
                         },
                         is_hidden: false
                     }
@@ -585,6 +606,8 @@ mod tests {
                 version: Some(TableVersion::new_initial_for_test(ColumnId::new(1))),
                 watermark_columns: FixedBitSet::with_capacity(2),
                 dist_key_in_pk: vec![],
+                description: None,
+                column_comments: HashMap::new(),
             }
         );
         assert_eq!(table, TableCatalog::from(table.to_prost(0, 0)));
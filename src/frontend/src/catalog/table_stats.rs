@@ -0,0 +1,24 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Approximate statistics about a table, collected by `ANALYZE` and consulted by the optimizer
+/// instead of its previous fixed guesses.
+///
+/// Unlike the rest of the catalog, these stats are a local cache kept by [`super::Catalog`]: they
+/// are never sent to meta and are not replicated across frontend nodes, so a table's stats need
+/// to be recomputed (by re-running `ANALYZE`) on every frontend that should benefit from them.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TableStats {
+    pub row_count: u64,
+}
@@ -104,6 +104,16 @@ impl SessionTimezone {
                     _ => None,
                 }
             }
+            // `date_trunc`/`extract` on a `timestamptz` without an explicit timezone argument
+            // are bound (see `Binder::bind_function`/`bind_extract`) as an unchecked 2-argument
+            // call; fill in the session timezone as the missing 3rd argument here.
+            ExprType::DateTrunc | ExprType::Extract
+                if inputs.len() == 2 && inputs[1].return_type() == DataType::Timestamptz =>
+            {
+                let mut inputs = inputs.clone();
+                inputs.push(ExprImpl::literal_varchar(self.timezone.clone()));
+                Some(FunctionCall::new_unchecked(func_type, inputs, return_type).into())
+            }
             // is cmp
             ExprType::Equal
             | ExprType::NotEqual
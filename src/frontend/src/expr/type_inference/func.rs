@@ -477,6 +477,16 @@ fn infer_type_for_special(
                 datatype: Box::new(DataType::Varchar),
             }))
         }
+        ExprType::RegexpReplace => {
+            ensure_arity!("regexp_replace", 3 <= | inputs | <= 4);
+            Ok(Some(DataType::Varchar))
+        }
+        ExprType::RegexpSplitToArray => {
+            ensure_arity!("regexp_split_to_array", 2 <= | inputs | <= 3);
+            Ok(Some(DataType::List {
+                datatype: Box::new(DataType::Varchar),
+            }))
+        }
         ExprType::ArrayCat => {
             ensure_arity!("array_cat", | inputs | == 2);
             let left_type = inputs[0].return_type();
@@ -595,6 +605,63 @@ fn infer_type_for_special(
                 _ => Ok(None),
             }
         }
+        ExprType::ArrayPosition => {
+            ensure_arity!("array_position", 2 <= | inputs | <= 3);
+            match align_array_and_element(0, 1, inputs) {
+                Ok(_) => Ok(Some(DataType::Int32)),
+                Err(_) => Err(ErrorCode::BindError(format!(
+                    "Cannot find {} in {}",
+                    inputs[1].return_type(),
+                    inputs[0].return_type()
+                ))
+                .into()),
+            }
+        }
+        ExprType::ArrayPositions => {
+            ensure_arity!("array_positions", | inputs | == 2);
+            match align_array_and_element(0, 1, inputs) {
+                Ok(_) => Ok(Some(DataType::List {
+                    datatype: Box::new(DataType::Int32),
+                })),
+                Err(_) => Err(ErrorCode::BindError(format!(
+                    "Cannot find {} in {}",
+                    inputs[1].return_type(),
+                    inputs[0].return_type()
+                ))
+                .into()),
+            }
+        }
+        ExprType::ArrayRemove => {
+            ensure_arity!("array_remove", | inputs | == 2);
+            match align_array_and_element(0, 1, inputs) {
+                Ok(casted) => Ok(Some(casted)),
+                Err(_) => Err(ErrorCode::BindError(format!(
+                    "Cannot remove {} from {}",
+                    inputs[1].return_type(),
+                    inputs[0].return_type()
+                ))
+                .into()),
+            }
+        }
+        ExprType::ArrayContains | ExprType::ArrayOverlap => {
+            let func_name = if func_type == ExprType::ArrayContains {
+                "array_contains"
+            } else {
+                "array_overlap"
+            };
+            ensure_arity!(func_name, | inputs | == 2);
+            match (inputs[0].return_type(), inputs[1].return_type()) {
+                (DataType::List { .. }, DataType::List { .. }) => {
+                    align_types(inputs.iter_mut())?;
+                    Ok(Some(DataType::Boolean))
+                }
+                (left, right) => Err(ErrorCode::BindError(format!(
+                    "Cannot compare {} and {}",
+                    left, right
+                ))
+                .into()),
+            }
+        }
         ExprType::Vnode => {
             ensure_arity!("vnode", 1 <= | inputs |);
             Ok(Some(DataType::Int16))
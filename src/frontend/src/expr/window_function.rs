@@ -42,6 +42,8 @@ pub enum WindowFunctionType {
     RowNumber,
     Rank,
     DenseRank,
+    Lag,
+    Lead,
 }
 
 impl WindowFunctionType {
@@ -53,6 +55,12 @@ impl WindowFunctionType {
                 | WindowFunctionType::DenseRank
         )
     }
+
+    /// `lag`/`lead` take the value to offset as their 1st argument, and an optional constant
+    /// offset (default `1`) as their 2nd.
+    pub fn is_offset_function(&self) -> bool {
+        matches!(self, WindowFunctionType::Lag | WindowFunctionType::Lead)
+    }
 }
 
 impl FromStr for WindowFunctionType {
@@ -63,6 +71,8 @@ impl FromStr for WindowFunctionType {
             "row_number" => Ok(WindowFunctionType::RowNumber),
             "rank" => Ok(WindowFunctionType::Rank),
             "dense_rank" => Ok(WindowFunctionType::DenseRank),
+            "lag" => Ok(WindowFunctionType::Lag),
+            "lead" => Ok(WindowFunctionType::Lead),
             _ => Err(ErrorCode::NotImplemented(
                 format!("unknown table function kind: {s}"),
                 None.into(),
@@ -80,16 +90,34 @@ impl WindowFunction {
         order_by: OrderBy,
         args: Vec<ExprImpl>,
     ) -> RwResult<Self> {
-        if !args.is_empty() {
-            return Err(ErrorCode::BindError(format!(
-                "the length of args of {function_type} function should be 0"
-            ))
-            .into());
-        }
+        let return_type = if function_type.is_offset_function() {
+            if !matches!(args.len(), 1 | 2) {
+                return Err(ErrorCode::BindError(format!(
+                    "the length of args of {function_type} function should be 1 or 2, but got {}",
+                    args.len()
+                ))
+                .into());
+            }
+            if let Some(offset) = args.get(1) && !matches!(offset, ExprImpl::Literal(_)) {
+                return Err(ErrorCode::BindError(format!(
+                    "the offset of {function_type} function should be a constant"
+                ))
+                .into());
+            }
+            args[0].return_type()
+        } else {
+            if !args.is_empty() {
+                return Err(ErrorCode::BindError(format!(
+                    "the length of args of {function_type} function should be 0"
+                ))
+                .into());
+            }
+            DataType::Int64
+        };
 
         Ok(Self {
             args,
-            return_type: DataType::Int64,
+            return_type,
             function_type,
             partition_by,
             order_by,
@@ -226,4 +226,39 @@ mod tests {
             .to_string();
         assert_eq!(altered_table_name, "t1");
     }
+
+    #[tokio::test]
+    async fn test_alter_table_name_rewrites_dependent_definition() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        let session = frontend.session_ref();
+        let schema_path = SchemaPath::Name(DEFAULT_SCHEMA_NAME);
+
+        frontend
+            .run_sql("create table t (i int, r real);")
+            .await
+            .unwrap();
+        frontend
+            .run_sql("create materialized view mv as select i from t;")
+            .await
+            .unwrap();
+
+        let mv_id = {
+            let catalog_reader = session.env().catalog_reader().read_guard();
+            catalog_reader
+                .get_table_by_name(DEFAULT_DATABASE_NAME, schema_path, "mv")
+                .unwrap()
+                .0
+                .id
+        };
+
+        frontend
+            .run_sql("alter table t rename to t1;")
+            .await
+            .unwrap();
+
+        let catalog_reader = session.env().catalog_reader().read_guard();
+        let mv_definition = catalog_reader.get_table_by_id(&mv_id).unwrap().create_sql();
+        assert!(mv_definition.contains("FROM t1"));
+        assert!(!mv_definition.contains("FROM t "));
+    }
 }
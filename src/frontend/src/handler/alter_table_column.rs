@@ -112,6 +112,22 @@ pub async fn handle_alter_table_column(
                 ))?
             }
 
+            // TODO: support backfilling a `DEFAULT` value into existing rows. Until then, reject
+            // it explicitly instead of silently dropping the option: existing rows would read
+            // back as `NULL` for the new column rather than the specified default.
+            if new_column
+                .options
+                .iter()
+                .any(|x| matches!(x.option, ColumnOption::Default(_)))
+            {
+                Err(ErrorCode::NotImplemented(
+                    "alter table add column with a default value is not supported yet; \
+                     existing rows cannot be backfilled"
+                        .to_string(),
+                    None.into(),
+                ))?
+            }
+
             // Add the new column to the table definition.
             columns.push(new_column);
         }
@@ -157,7 +173,7 @@ pub async fn handle_alter_table_column(
     // Create handler args as if we're creating a new table with the altered definition.
     let handler_args = HandlerArgs::new(session.clone(), &definition, "")?;
     let col_id_gen = ColumnIdGenerator::new_alter(&original_catalog);
-    let Statement::CreateTable { columns, constraints, source_watermarks, append_only, .. } = definition else {
+    let Statement::CreateTable { columns, constraints, source_watermarks, append_only, on_conflict, .. } = definition else {
         panic!("unexpected statement type: {:?}", definition);
     };
 
@@ -171,6 +187,7 @@ pub async fn handle_alter_table_column(
             col_id_gen,
             source_watermarks,
             append_only,
+            on_conflict,
         )?;
 
         // We should already have rejected the case where the table has a connector.
@@ -294,4 +311,18 @@ mod tests {
             altered_table.version.as_ref().unwrap().next_column_id
         );
     }
+
+    #[tokio::test]
+    async fn test_add_column_with_default_rejected() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+
+        let sql = "create table t (i int);";
+        frontend.run_sql(sql).await.unwrap();
+
+        // Backfilling existing rows with a default value is not supported yet, so the statement
+        // should be rejected rather than silently filling new rows with `NULL`.
+        let sql = "alter table t add column s int default 1;";
+        let result = frontend.run_sql(sql).await;
+        assert!(result.is_err());
+    }
 }
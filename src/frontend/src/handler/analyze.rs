@@ -0,0 +1,87 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Index;
+
+use futures::StreamExt;
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_sqlparser::ast::ObjectName;
+use risingwave_sqlparser::parser::Parser;
+
+use super::query::handle_query;
+use super::{HandlerArgs, RwPgResponse};
+use crate::binder::{Binder, Relation};
+use crate::catalog::table_stats::TableStats;
+
+/// Handles `ANALYZE table_name`.
+///
+/// We only collect a row count today: it's the single statistic the optimizer can act on right
+/// now (see [`crate::optimizer::rule::index_selection_rule`]). NDV sketches and per-column
+/// min/max are natural follow-ups once the optimizer has a use for them.
+///
+/// The collected statistics live in this frontend node's local catalog cache only; they are
+/// never sent to meta and are not replicated to other frontend nodes, so re-run `ANALYZE` after
+/// failing over to a different frontend.
+pub async fn handle_analyze(
+    handler_args: HandlerArgs,
+    table_name: ObjectName,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session.clone();
+
+    let table_id = {
+        let mut binder = Binder::new(&session, vec![]);
+        match binder.bind_relation_by_name(table_name.clone(), None, None)? {
+            Relation::BaseTable(t) => t.table_id,
+            _ => {
+                return Err(ErrorCode::NotImplemented(
+                    "ANALYZE is only supported on tables".to_string(),
+                    None.into(),
+                )
+                .into());
+            }
+        }
+    };
+
+    let count_stmt = Parser::parse_sql(&format!("SELECT COUNT(*) FROM {}", table_name))
+        .map_err(|e| ErrorCode::InternalError(format!("failed to build ANALYZE query: {e}")))?
+        .into_iter()
+        .next()
+        .unwrap();
+
+    let mut row_stream = handle_query(handler_args, count_stmt, vec![])
+        .await?
+        .into_values_stream();
+
+    let mut row_count = 0u64;
+    if let Some(row_set) = row_stream.next().await {
+        if let Some(row) = row_set
+            .map_err(|e| ErrorCode::InternalError(e.to_string()))?
+            .into_iter()
+            .next()
+        {
+            row_count = std::str::from_utf8(row.index(0).as_ref().unwrap())
+                .unwrap()
+                .parse()
+                .unwrap();
+        }
+    }
+
+    session
+        .env()
+        .catalog_reader()
+        .update_table_stats(table_id, TableStats { row_count });
+
+    Ok(PgResponse::empty_result(StatementType::ANALYZE))
+}
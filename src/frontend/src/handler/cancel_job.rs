@@ -0,0 +1,25 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::error::Result;
+
+use super::RwPgResponse;
+use crate::handler::HandlerArgs;
+
+pub async fn handle_cancel(handler_args: HandlerArgs, job_ids: Vec<u32>) -> Result<RwPgResponse> {
+    let client = handler_args.session.env().meta_client();
+    client.cancel_creating_jobs_by_ids(job_ids).await?;
+    Ok(PgResponse::empty_result(StatementType::CANCEL_JOBS))
+}
@@ -0,0 +1,115 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Handle `COMMENT ON TABLE`/`COMMENT ON MATERIALIZED VIEW`/`COMMENT ON COLUMN`, persisting the
+//! description on the target table so it can later be read back from `pg_description`.
+
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_pb::catalog::PbComment;
+use risingwave_sqlparser::ast::{CommentObject, ObjectName};
+
+use super::{HandlerArgs, RwPgResponse};
+use crate::catalog::root_catalog::SchemaPath;
+use crate::catalog::table_catalog::TableType;
+use crate::Binder;
+
+pub async fn handle_comment(
+    handler_args: HandlerArgs,
+    object_type: CommentObject,
+    object_name: ObjectName,
+    comment: Option<String>,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+    let db_name = session.database();
+    let search_path = session.config().get_search_path();
+    let user_name = &session.auth_context().user_name;
+
+    // For `COMMENT ON COLUMN`, the object name is `table_name.column_name`; split it apart so the
+    // table can be resolved the same way as for `TABLE`/`MATERIALIZED VIEW`.
+    let (table_name, column_name) = match object_type {
+        CommentObject::Column => {
+            let mut name = object_name.0;
+            let column_name = name
+                .pop()
+                .ok_or_else(|| ErrorCode::InvalidInputSyntax("empty column name".to_string()))?
+                .real_value();
+            (ObjectName(name), Some(column_name))
+        }
+        CommentObject::Table | CommentObject::MaterializedView => (object_name, None),
+    };
+
+    let (schema_name, real_table_name) =
+        Binder::resolve_schema_qualified_name(db_name, table_name)?;
+    let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+    let comment = {
+        let reader = session.env().catalog_reader().read_guard();
+        let (table, schema_name) =
+            reader.get_table_by_name(db_name, schema_path, &real_table_name)?;
+
+        let expected_table_type = match object_type {
+            CommentObject::Table => Some(TableType::Table),
+            CommentObject::MaterializedView => Some(TableType::MaterializedView),
+            CommentObject::Column => None,
+        };
+        if let Some(expected_table_type) = expected_table_type
+            && table.table_type != expected_table_type
+        {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "\"{real_table_name}\" is not a {}",
+                table_type_name(object_type)
+            ))
+            .into());
+        }
+
+        session.check_privilege_for_drop_alter(schema_name, &**table)?;
+
+        let column_id = match &column_name {
+            Some(column_name) => Some(
+                table
+                    .columns
+                    .iter()
+                    .find(|c| c.name() == column_name.as_str())
+                    .ok_or_else(|| {
+                        ErrorCode::ItemNotFound(format!(
+                            "column \"{column_name}\" of table \"{real_table_name}\" does not exist"
+                        ))
+                    })?
+                    .column_id()
+                    .get_id(),
+            ),
+            None => None,
+        };
+
+        PbComment {
+            table_id: table.id.table_id,
+            column_id,
+            description: comment,
+        }
+    };
+
+    let catalog_writer = session.env().catalog_writer();
+    catalog_writer.comment_on(comment).await?;
+
+    Ok(PgResponse::empty_result(StatementType::COMMENT))
+}
+
+fn table_type_name(object_type: CommentObject) -> &'static str {
+    match object_type {
+        CommentObject::Table => "table",
+        CommentObject::MaterializedView => "materialized view",
+        CommentObject::Column => unreachable!("columns don't have a table type"),
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_sqlparser::ast::{Expr, Ident, ObjectName, Query, SetExpr, Statement, Value, Values};
+
+use super::query::handle_query;
+use super::{HandlerArgs, RwPgResponse};
+use crate::binder::Binder;
+use crate::catalog::root_catalog::SchemaPath;
+
+/// Handles `COPY ... FROM STDIN` where the payload is embedded right in the SQL text (the
+/// only form our parser accepts today -- there is no support yet for the interactive
+/// `CopyInResponse`/`CopyData` wire protocol, nor for `COPY ... TO STDOUT`).
+///
+/// We simply turn the tab-separated payload into an equivalent `INSERT ... VALUES` statement
+/// and run it through the regular query handling path, so it gets the same binding, casting,
+/// and privilege checks as a normal insert.
+pub async fn handle_copy(
+    handler_args: HandlerArgs,
+    table_name: ObjectName,
+    columns: Vec<Ident>,
+    values: Vec<Option<String>>,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session.clone();
+
+    let row_width = if columns.is_empty() {
+        let (schema_name, real_table_name) =
+            Binder::resolve_schema_qualified_name(session.database(), table_name.clone())?;
+        let search_path = session.config().get_search_path();
+        let user_name = session.user_name();
+        let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+        let catalog_reader = session.env().catalog_reader().read_guard();
+        let (table, _) =
+            catalog_reader.get_table_by_name(session.database(), schema_path, &real_table_name)?;
+        table.columns_to_insert().count()
+    } else {
+        columns.len()
+    };
+
+    if row_width == 0 || values.len() % row_width != 0 {
+        return Err(ErrorCode::BindError(
+            "COPY data does not match the number of columns in the table".to_string(),
+        )
+        .into());
+    }
+
+    let rows = values
+        .chunks(row_width)
+        .map(|row| {
+            row.iter()
+                .map(|value| match value {
+                    Some(s) => Expr::Value(Value::SingleQuotedString(s.clone())),
+                    None => Expr::Value(Value::Null),
+                })
+                .collect_vec()
+        })
+        .collect_vec();
+
+    let insert_stmt = Statement::Insert {
+        table_name,
+        columns,
+        source: Box::new(Query {
+            with: None,
+            body: SetExpr::Values(Values(rows)),
+            order_by: vec![],
+            limit: None,
+            offset: None,
+            fetch: None,
+        }),
+        on_conflict: None,
+        returning: vec![],
+    };
+
+    handle_query(handler_args, insert_stmt, vec![]).await
+}
@@ -0,0 +1,144 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::anyhow;
+use itertools::Itertools;
+use pgwire::pg_response::StatementType;
+use risingwave_common::catalog::FunctionId;
+use risingwave_common::types::DataType;
+use risingwave_pb::catalog::function::{AggregateFunction, Kind};
+use risingwave_pb::catalog::Function;
+use risingwave_sqlparser::ast::{
+    CreateFunctionBody, CreateFunctionUsing, FunctionDefinition, ObjectName, OperateFunctionArg,
+};
+use risingwave_udf::ArrowFlightUdfClient;
+
+use super::*;
+use crate::catalog::CatalogError;
+use crate::{bind_data_type, Binder};
+
+/// Handles `CREATE AGGREGATE`, which registers a user-defined aggregate backed by an external
+/// service exposing `create`/`accumulate`/`retract`/`finish` calls for the given `identifier`, or
+/// by an embedded wasm module.
+///
+/// The aggregate is fully cataloged by this statement, but there is no streaming or batch executor
+/// that can run its create/accumulate/retract/finish state machine yet: see the `Aggregate` arm of
+/// [`crate::binder::Binder::bind_function`] for where calling it currently fails.
+pub async fn handle_create_aggregate(
+    handler_args: HandlerArgs,
+    or_replace: bool,
+    name: ObjectName,
+    args: Option<Vec<OperateFunctionArg>>,
+    returns: DataType,
+    params: CreateFunctionBody,
+) -> Result<RwPgResponse> {
+    if or_replace {
+        return Err(ErrorCode::NotImplemented(
+            "CREATE OR REPLACE AGGREGATE".to_string(),
+            None.into(),
+        )
+        .into());
+    }
+    let language = match params.language {
+        Some(lang) => lang.real_value().to_lowercase(),
+        None => {
+            return Err(
+                ErrorCode::InvalidParameterValue("LANGUAGE must be specified".to_string()).into(),
+            )
+        }
+    };
+    if !matches!(language.as_str(), "python" | "wasm") {
+        return Err(ErrorCode::InvalidParameterValue(
+            "LANGUAGE should be one of: python, wasm".to_string(),
+        )
+        .into());
+    }
+    let return_type = bind_data_type(&returns)?;
+
+    let mut arg_types = vec![];
+    let mut arg_names = vec![];
+    for arg in args.unwrap_or_default() {
+        arg_types.push(bind_data_type(&arg.data_type)?);
+        arg_names.push(arg.name.map(|n| n.real_value()).unwrap_or_default());
+    }
+
+    // resolve database and schema id
+    let session = &handler_args.session;
+    let db_name = session.database();
+    let (schema_name, function_name) = Binder::resolve_schema_qualified_name(db_name, name)?;
+    let (database_id, schema_id) = session.get_database_and_schema_id_for_create(schema_name)?;
+
+    // check if function exists
+    if (session.env().catalog_reader().read_guard())
+        .get_schema_by_id(&database_id, &schema_id)?
+        .get_function_by_name_args(&function_name, &arg_types)
+        .is_some()
+    {
+        let name = format!(
+            "{function_name}({})",
+            arg_types.iter().map(|t| t.to_string()).join(",")
+        );
+        return Err(CatalogError::Duplicated("function", name).into());
+    }
+
+    let Some(FunctionDefinition::SingleQuotedDef(identifier)) = params.as_ else {
+        return Err(ErrorCode::InvalidParameterValue("AS must be specified".to_string()).into());
+    };
+    let Some(CreateFunctionUsing::Link(link)) = params.using else {
+        return Err(ErrorCode::InvalidParameterValue("USING must be specified".to_string()).into());
+    };
+
+    let mut compiled_wasm_module = None;
+    if language == "wasm" {
+        let path = link.strip_prefix("file://").ok_or_else(|| {
+            anyhow!("wasm aggregates must be created with USING LINK 'file://<path>'")
+        })?;
+        let module =
+            std::fs::read(path).map_err(|e| anyhow!("failed to read wasm module: {e}"))?;
+        if module.get(0..4) != Some(b"\0asm") {
+            return Err(anyhow!("file at {path} is not a valid wasm module").into());
+        }
+        compiled_wasm_module = Some(module);
+    } else {
+        // The external service is expected to expose `{identifier}_create`,
+        // `{identifier}_accumulate`, `{identifier}_retract` and `{identifier}_finish`; only
+        // reachability of the service is checked here, since there is no common schema to
+        // validate all four calls against at once.
+        ArrowFlightUdfClient::connect(&link)
+            .await
+            .map_err(|e| anyhow!(e))?;
+    }
+
+    let function = Function {
+        id: FunctionId::placeholder().0,
+        schema_id,
+        database_id,
+        name: function_name,
+        kind: Some(Kind::Aggregate(AggregateFunction {})),
+        arg_types: arg_types.into_iter().map(|t| t.into()).collect(),
+        return_type: Some(return_type.into()),
+        language,
+        identifier,
+        link,
+        compiled_wasm_module,
+        arg_names,
+        body: None,
+        owner: session.user_id(),
+    };
+
+    let catalog_writer = session.env().catalog_writer();
+    catalog_writer.create_function(function).await?;
+
+    Ok(PgResponse::empty_result(StatementType::CREATE_FUNCTION))
+}
@@ -59,24 +59,12 @@ pub async fn handle_create_function(
             )
         }
     };
-    if language != "python" {
+    if !matches!(language.as_str(), "python" | "wasm" | "sql") {
         return Err(ErrorCode::InvalidParameterValue(
-            "LANGUAGE should be one of: python".to_string(),
+            "LANGUAGE should be one of: python, wasm, sql".to_string(),
         )
         .into());
     }
-    let Some(FunctionDefinition::SingleQuotedDef(identifier)) = params.as_ else {
-        return Err(ErrorCode::InvalidParameterValue(
-            "AS must be specified".to_string(),
-        )
-        .into());
-    };
-    let Some(CreateFunctionUsing::Link(link)) = params.using else {
-        return Err(ErrorCode::InvalidParameterValue(
-            "USING must be specified".to_string(),
-        )
-        .into());
-    };
     let return_type;
     let kind = match returns {
         Some(CreateFunctionReturns::Value(data_type)) => {
@@ -110,8 +98,10 @@ pub async fn handle_create_function(
     };
 
     let mut arg_types = vec![];
+    let mut arg_names = vec![];
     for arg in args.unwrap_or_default() {
         arg_types.push(bind_data_type(&arg.data_type)?);
+        arg_names.push(arg.name.map(|n| n.real_value()).unwrap_or_default());
     }
 
     // resolve database and schema id
@@ -133,38 +123,111 @@ pub async fn handle_create_function(
         return Err(CatalogError::Duplicated("function", name).into());
     }
 
-    // check the service
-    let client = ArrowFlightUdfClient::connect(&link)
-        .await
-        .map_err(|e| anyhow!(e))?;
-    let args = arrow_schema::Schema::new(
-        arg_types
-            .iter()
-            .map(|t| arrow_schema::Field::new("", t.into(), true))
-            .collect(),
-    );
-    let returns = match kind {
-        Kind::Scalar(_) => arrow_schema::Schema::new(vec![arrow_schema::Field::new(
-            "",
-            return_type.clone().into(),
-            true,
-        )]),
-        Kind::Table(_) => arrow_schema::Schema::new(match &return_type {
-            DataType::Struct(s) => (s.fields.iter())
-                .map(|t| arrow_schema::Field::new("", t.clone().into(), true))
-                .collect(),
-            _ => vec![arrow_schema::Field::new(
-                "",
-                return_type.clone().into(),
-                true,
-            )],
-        }),
-        _ => unreachable!(),
-    };
-    client
-        .check(&identifier, &args, &returns)
-        .await
-        .map_err(|e| anyhow!(e))?;
+    let mut identifier = String::new();
+    let mut link = String::new();
+    let mut compiled_wasm_module = None;
+    let mut body = None;
+
+    if language == "sql" {
+        if matches!(kind, Kind::Table(_)) {
+            return Err(ErrorCode::NotImplemented(
+                "table-valued LANGUAGE sql functions".to_string(),
+                None.into(),
+            )
+            .into());
+        }
+        // A SQL UDF has no external implementation to connect to: its body is an expression
+        // that gets parsed once here and re-bound (with arguments substituted in) at every call
+        // site, so `USING` does not apply.
+        if params.using.is_some() {
+            return Err(ErrorCode::InvalidParameterValue(
+                "USING is not allowed for LANGUAGE sql".to_string(),
+            )
+            .into());
+        }
+        let expr_sql = match (params.as_, params.return_) {
+            (Some(_), Some(_)) => {
+                return Err(ErrorCode::InvalidParameterValue(
+                    "only one of AS or RETURN can be specified for LANGUAGE sql".to_string(),
+                )
+                .into())
+            }
+            (Some(FunctionDefinition::SingleQuotedDef(s)), None)
+            | (Some(FunctionDefinition::DoubleDollarDef(s)), None) => s,
+            (None, Some(expr)) => expr.to_string(),
+            (None, None) => {
+                return Err(ErrorCode::InvalidParameterValue(
+                    "AS or RETURN must be specified for LANGUAGE sql".to_string(),
+                )
+                .into())
+            }
+        };
+        body = Some(expr_sql);
+    } else {
+        let Some(FunctionDefinition::SingleQuotedDef(id)) = params.as_ else {
+            return Err(ErrorCode::InvalidParameterValue(
+                "AS must be specified".to_string(),
+            )
+            .into());
+        };
+        identifier = id;
+        let Some(CreateFunctionUsing::Link(l)) = params.using else {
+            return Err(ErrorCode::InvalidParameterValue(
+                "USING must be specified".to_string(),
+            )
+            .into());
+        };
+        link = l;
+
+        if language == "wasm" {
+            // For wasm functions, `USING LINK` points at a local compiled module instead of an
+            // external service. The module is read once at creation time and the bytes are
+            // persisted in the catalog, so that running functions no longer depend on the file
+            // being present.
+            let path = link.strip_prefix("file://").ok_or_else(|| {
+                anyhow!("wasm functions must be created with USING LINK 'file://<path>'")
+            })?;
+            let module =
+                std::fs::read(path).map_err(|e| anyhow!("failed to read wasm module: {e}"))?;
+            if module.get(0..4) != Some(b"\0asm") {
+                return Err(anyhow!("file at {path} is not a valid wasm module").into());
+            }
+            compiled_wasm_module = Some(module);
+        } else {
+            // check the service
+            let client = ArrowFlightUdfClient::connect(&link)
+                .await
+                .map_err(|e| anyhow!(e))?;
+            let args = arrow_schema::Schema::new(
+                arg_types
+                    .iter()
+                    .map(|t| arrow_schema::Field::new("", t.into(), true))
+                    .collect(),
+            );
+            let returns = match kind {
+                Kind::Scalar(_) => arrow_schema::Schema::new(vec![arrow_schema::Field::new(
+                    "",
+                    return_type.clone().into(),
+                    true,
+                )]),
+                Kind::Table(_) => arrow_schema::Schema::new(match &return_type {
+                    DataType::Struct(s) => (s.fields.iter())
+                        .map(|t| arrow_schema::Field::new("", t.clone().into(), true))
+                        .collect(),
+                    _ => vec![arrow_schema::Field::new(
+                        "",
+                        return_type.clone().into(),
+                        true,
+                    )],
+                }),
+                _ => unreachable!(),
+            };
+            client
+                .check(&identifier, &args, &returns)
+                .await
+                .map_err(|e| anyhow!(e))?;
+        }
+    }
 
     let function = Function {
         id: FunctionId::placeholder().0,
@@ -177,6 +240,9 @@ pub async fn handle_create_function(
         language,
         identifier,
         link,
+        compiled_wasm_module,
+        arg_names,
+        body,
         owner: session.user_id(),
     };
 
@@ -39,7 +39,7 @@ pub fn gen_sink_query_from_name(from_name: ObjectName) -> Result<Query> {
     let table_factor = TableFactor::Table {
         name: from_name,
         alias: None,
-        for_system_time_as_of_now: false,
+        as_of: None,
     };
     let from = vec![TableWithJoins {
         relation: table_factor,
@@ -464,6 +464,7 @@ fn check_and_add_timestamp_column(
             field_descs: vec![],
             type_name: "".to_string(),
             generated_column: None,
+            default_column: None,
         };
         column_descs.push(kafka_timestamp_column);
     }
@@ -718,6 +719,7 @@ pub async fn handle_create_source(
         owner: session.user_id(),
         watermark_descs,
         optional_associated_table_id: None,
+        definition: handler_args.normalized_sql,
     };
 
     let catalog_writer = session.env().catalog_writer();
@@ -19,17 +19,17 @@ use fixedbitset::FixedBitSet;
 use itertools::Itertools;
 use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::catalog::{
-    ColumnCatalog, ColumnDesc, TableId, TableVersionId, INITIAL_TABLE_VERSION_ID,
-    USER_COLUMN_ID_OFFSET,
+    ColumnCatalog, ColumnDesc, ConflictBehavior, TableId, TableVersionId,
+    INITIAL_TABLE_VERSION_ID, USER_COLUMN_ID_OFFSET,
 };
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_pb::catalog::source::OptionalAssociatedTableId;
 use risingwave_pb::catalog::{PbSource, PbTable, StreamSourceInfo, WatermarkDesc};
-use risingwave_pb::plan_common::GeneratedColumnDesc;
+use risingwave_pb::plan_common::{DefaultColumnDesc, GeneratedColumnDesc};
 use risingwave_pb::stream_plan::stream_fragment_graph::Parallelism;
 use risingwave_sqlparser::ast::{
-    ColumnDef, ColumnOption, DataType as AstDataType, ObjectName, SourceSchema, SourceWatermark,
-    TableConstraint,
+    ColumnDef, ColumnOption, DataType as AstDataType, ObjectName, OnConflict, SourceSchema,
+    SourceWatermark, TableConstraint,
 };
 
 use super::create_source::resolve_source_schema;
@@ -164,6 +164,7 @@ pub fn bind_sql_columns(
             field_descs,
             type_name: "".to_string(),
             generated_column: None,
+            default_column: None,
         });
     }
 
@@ -233,6 +234,17 @@ pub fn bind_sql_column_constraints(
                         expr: Some(expr_impl.to_expr_proto()),
                     });
                 }
+                ColumnOption::Default(expr) => {
+                    let idx = binder
+                        .get_column_binding_index(table_name.clone(), &column.name.real_value())?;
+                    let expr_impl = binder
+                        .bind_expr(expr)?
+                        .cast_assign(column_catalogs[idx].data_type().clone())?;
+
+                    column_catalogs[idx].column_desc.default_column = Some(DefaultColumnDesc {
+                        expr: Some(expr_impl.to_expr_proto()),
+                    });
+                }
                 ColumnOption::Unique { is_primary: true } => {
                     // Bind primary key in `bind_sql_table_column_constraints`
                 }
@@ -281,6 +293,9 @@ pub fn bind_sql_table_column_constraints(
                 ColumnOption::GeneratedColumns(_) => {
                     // Bind generated columns in `bind_sql_column_constraints`
                 }
+                ColumnOption::Default(_) => {
+                    // Bind default values in `bind_sql_column_constraints`
+                }
                 _ => {
                     return Err(ErrorCode::NotImplemented(
                         format!("column constraints \"{}\"", option_def),
@@ -369,6 +384,7 @@ pub(crate) async fn gen_create_table_plan_with_source(
     source_watermarks: Vec<SourceWatermark>,
     mut col_id_gen: ColumnIdGenerator,
     append_only: bool,
+    on_conflict: Option<OnConflict>,
 ) -> Result<(PlanRef, Option<PbSource>, PbTable)> {
     let session = context.session_ctx();
     let column_descs = bind_sql_columns(column_defs.clone(), &mut col_id_gen)?;
@@ -419,12 +435,14 @@ pub(crate) async fn gen_create_table_plan_with_source(
         definition,
         watermark_descs,
         append_only,
+        on_conflict,
         Some(col_id_gen.into_version()),
     )
 }
 
 /// `gen_create_table_plan` generates the plan for creating a table without an external stream
 /// source.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn gen_create_table_plan(
     context: OptimizerContext,
     table_name: ObjectName,
@@ -433,6 +451,7 @@ pub(crate) fn gen_create_table_plan(
     mut col_id_gen: ColumnIdGenerator,
     source_watermarks: Vec<SourceWatermark>,
     append_only: bool,
+    on_conflict: Option<OnConflict>,
 ) -> Result<(PlanRef, Option<PbSource>, PbTable)> {
     let definition = context.normalized_sql().to_owned();
     let column_descs = bind_sql_columns(columns.clone(), &mut col_id_gen)?;
@@ -448,6 +467,7 @@ pub(crate) fn gen_create_table_plan(
         definition,
         source_watermarks,
         append_only,
+        on_conflict,
         Some(col_id_gen.into_version()),
     )
 }
@@ -463,6 +483,7 @@ pub(crate) fn gen_create_table_plan_without_bind(
     definition: String,
     source_watermarks: Vec<SourceWatermark>,
     append_only: bool,
+    on_conflict: Option<OnConflict>,
     version: Option<TableVersion>,
 ) -> Result<(PlanRef, Option<PbSource>, PbTable)> {
     let (mut columns, pk_column_ids, row_id_index) =
@@ -493,6 +514,7 @@ pub(crate) fn gen_create_table_plan_without_bind(
         definition,
         watermark_descs,
         append_only,
+        on_conflict,
         version,
     )
 }
@@ -509,6 +531,7 @@ fn gen_table_plan_inner(
     definition: String,
     watermark_descs: Vec<WatermarkDesc>,
     append_only: bool,
+    on_conflict: Option<OnConflict>,
     version: Option<TableVersion>, /* TODO: this should always be `Some` if we support `ALTER
                                     * TABLE` for `CREATE TABLE AS`. */
 ) -> Result<(PlanRef, Option<PbSource>, PbTable)> {
@@ -535,6 +558,7 @@ fn gen_table_plan_inner(
         optional_associated_table_id: Some(OptionalAssociatedTableId::AssociatedTableId(
             TableId::placeholder().table_id,
         )),
+        definition: definition.clone(),
     });
 
     let source_catalog = source.as_ref().map(|source| Rc::new((source).into()));
@@ -572,6 +596,22 @@ fn gen_table_plan_inner(
         .into());
     }
 
+    if append_only && on_conflict.is_some() {
+        return Err(ErrorCode::InvalidInputSyntax(
+            "ON CONFLICT is not supported for an append-only table".to_owned(),
+        )
+        .into());
+    }
+
+    let conflict_behavior = if append_only {
+        ConflictBehavior::NoCheck
+    } else {
+        match on_conflict {
+            Some(OnConflict::DoNothing) => ConflictBehavior::IgnoreConflict,
+            Some(OnConflict::DoUpdate) | None => ConflictBehavior::Overwrite,
+        }
+    };
+
     let materialize = plan_root.gen_table_plan(
         name,
         columns,
@@ -579,6 +619,7 @@ fn gen_table_plan_inner(
         pk_column_ids,
         row_id_index,
         append_only,
+        conflict_behavior,
         watermark_descs,
         version,
     )?;
@@ -599,6 +640,7 @@ pub async fn handle_create_table(
     source_schema: Option<SourceSchema>,
     source_watermarks: Vec<SourceWatermark>,
     append_only: bool,
+    on_conflict: Option<OnConflict>,
 ) -> Result<RwPgResponse> {
     let session = handler_args.session.clone();
 
@@ -629,6 +671,7 @@ pub async fn handle_create_table(
                     source_watermarks,
                     col_id_gen,
                     append_only,
+                    on_conflict,
                 )
                 .await?
             }
@@ -640,6 +683,7 @@ pub async fn handle_create_table(
                 col_id_gen,
                 source_watermarks,
                 append_only,
+                on_conflict,
             )?,
         };
         let mut graph = build_graph(plan);
@@ -759,6 +803,65 @@ mod tests {
         assert_eq!(columns, expected_columns);
     }
 
+    #[tokio::test]
+    async fn test_bind_default_column() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        let session = frontend.session_ref();
+
+        for (sql, expected) in [
+            ("create table t (v1 int, v2 int default 100)", Ok(100)),
+            (
+                "create table t (v1 int, v2 int default 'not a number')",
+                Err("cannot cast"),
+            ),
+        ] {
+            let mut ast = risingwave_sqlparser::parser::Parser::parse_sql(sql).unwrap();
+            let risingwave_sqlparser::ast::Statement::CreateTable { columns, .. } =
+                ast.remove(0)
+            else {
+                panic!("test case should be create table")
+            };
+            let actual: Result<_> = (|| {
+                let column_descs =
+                    bind_sql_columns(columns.clone(), &mut ColumnIdGenerator::new_initial())?;
+                let mut column_catalogs = column_descs
+                    .into_iter()
+                    .map(|column_desc| ColumnCatalog {
+                        column_desc,
+                        is_hidden: false,
+                    })
+                    .collect_vec();
+                bind_sql_column_constraints(
+                    &session,
+                    "t".to_string(),
+                    &mut column_catalogs,
+                    columns,
+                )?;
+                let default_expr = column_catalogs[1]
+                    .column_desc
+                    .default_column
+                    .as_ref()
+                    .unwrap()
+                    .expr
+                    .as_ref()
+                    .unwrap();
+                let datum = ExprImpl::from_expr_proto(default_expr)?.eval_row_const()?;
+                Ok(datum.map(|scalar| scalar.into_int32()))
+            })();
+            match (expected, actual) {
+                (Ok(expected), Ok(actual)) => {
+                    assert_eq!(Some(expected), actual, "sql: {sql}")
+                }
+                (Ok(_), Err(actual)) => panic!("sql: {sql}\nunexpected error: {actual:?}"),
+                (Err(_), Ok(actual)) => panic!("sql: {sql}\nexpects error but got: {actual:?}"),
+                (Err(expected), Err(actual)) => assert!(
+                    actual.to_string().contains(expected),
+                    "sql: {sql}\nexpected: {expected:?}\nactual: {actual:?}"
+                ),
+            }
+        }
+    }
+
     #[test]
     fn test_bind_primary_key() {
         // Note: Column ID 0 is reserved for row ID column.
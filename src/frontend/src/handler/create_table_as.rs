@@ -127,6 +127,7 @@ pub async fn handle_create_as(
         table_name,
         columns: vec![],
         source: query,
+        on_conflict: None,
         returning: vec![],
     };
 
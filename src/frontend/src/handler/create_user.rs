@@ -13,10 +13,11 @@
 // limitations under the License.
 
 use pgwire::pg_response::{PgResponse, StatementType};
-use risingwave_common::error::ErrorCode::PermissionDenied;
+use risingwave_common::error::ErrorCode::{NotImplemented, PermissionDenied};
 use risingwave_common::error::Result;
+use risingwave_pb::user::auth_info::EncryptionType;
 use risingwave_pb::user::grant_privilege::{Action, ActionWithGrantOption, Object};
-use risingwave_pb::user::{GrantPrivilege, UserInfo};
+use risingwave_pb::user::{AuthInfo, GrantPrivilege, UserInfo};
 use risingwave_sqlparser::ast::{CreateUserStatement, UserOption, UserOptions};
 
 use super::RwPgResponse;
@@ -25,6 +26,24 @@ use crate::catalog::{CatalogError, DatabaseId};
 use crate::handler::HandlerArgs;
 use crate::user::user_authentication::encrypted_password;
 
+/// pgwire doesn't speak the SASL/SCRAM-SHA-256 handshake needed to verify a SHA-256-encrypted
+/// password, so a user created with one could never log in. Reject it at creation time instead
+/// of silently storing a credential that can't be used.
+fn reject_unverifiable_auth(auth_info: Option<AuthInfo>) -> Result<Option<AuthInfo>> {
+    if let Some(auth_info) = &auth_info
+        && auth_info.encryption_type == EncryptionType::Sha256 as i32
+    {
+        return Err(NotImplemented(
+            "SCRAM-SHA-256 authentication is not yet implemented; use an MD5 or plaintext \
+             password instead"
+                .to_string(),
+            None.into(),
+        )
+        .into());
+    }
+    Ok(auth_info)
+}
+
 fn make_prost_user_info(
     user_name: String,
     options: &UserOptions,
@@ -79,13 +98,15 @@ fn make_prost_user_info(
             UserOption::EncryptedPassword(password) => {
                 // TODO: Behaviour of PostgreSQL: Notice when password is empty string.
                 if !password.0.is_empty() {
-                    user_info.auth_info = encrypted_password(&user_info.name, &password.0);
+                    user_info.auth_info =
+                        reject_unverifiable_auth(encrypted_password(&user_info.name, &password.0))?;
                 }
             }
             UserOption::Password(opt) => {
                 // TODO: Behaviour of PostgreSQL: Notice when password is empty string.
                 if let Some(password) = opt && !password.0.is_empty() {
-                    user_info.auth_info = encrypted_password(&user_info.name, &password.0);
+                    user_info.auth_info =
+                        reject_unverifiable_auth(encrypted_password(&user_info.name, &password.0))?;
                 }
             }
         }
@@ -190,4 +211,27 @@ mod tests {
             .await
             .is_err());
     }
+
+    #[tokio::test]
+    async fn test_create_user_with_sha256_password_rejected() {
+        // pgwire doesn't speak the SASL/SCRAM-SHA-256 handshake needed to verify a SHA-256
+        // password, so a user with one could never log in; CREATE USER should reject it
+        // up front rather than store an unusable credential.
+        let frontend = LocalFrontend::new(Default::default()).await;
+        let session = frontend.session_ref();
+        let user_info_reader = session.env().user_info_reader();
+
+        assert!(frontend
+            .run_sql(
+                "CREATE USER sha_user WITH PASSWORD 'SHA-256:\
+                 88ecde925da3c6f8ec3d140683da9d2a422f26c1ae1d9212da1e5a53416dcc88'",
+            )
+            .await
+            .is_err());
+
+        assert!(user_info_reader
+            .read_guard()
+            .get_user_by_name("sha_user")
+            .is_none());
+    }
 }
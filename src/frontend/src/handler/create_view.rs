@@ -99,3 +99,60 @@ pub async fn handle_create_view(
 
     Ok(PgResponse::empty_result(StatementType::CREATE_VIEW))
 }
+
+/// Handles `CREATE TEMPORARY VIEW`, which is kept entirely in the session and never written to
+/// the meta catalog, so it is gone as soon as the session ends.
+pub async fn handle_create_temporary_view(
+    handler_args: HandlerArgs,
+    name: ObjectName,
+    columns: Vec<Ident>,
+    query: Query,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session.clone();
+    let db_name = session.database();
+    let (schema_name, view_name) = Binder::resolve_schema_qualified_name(db_name, name)?;
+    if schema_name.is_some() {
+        return Err(risingwave_common::error::ErrorCode::NotImplemented(
+            "schema-qualified temporary view".to_string(),
+            None.into(),
+        )
+        .into());
+    }
+
+    // plan the query to validate it and to infer the output schema.
+    let schema = {
+        let context = OptimizerContext::from_handler_args(handler_args);
+        let super::query::BatchQueryPlanResult { schema, .. } =
+            super::query::gen_batch_plan_by_statement(
+                &session,
+                context.into(),
+                Statement::Query(Box::new(query.clone())),
+            )?;
+        schema
+    };
+
+    let columns = if columns.is_empty() {
+        schema.fields().to_vec()
+    } else {
+        if columns.len() != schema.fields().len() {
+            return Err(risingwave_common::error::ErrorCode::InternalError(
+                "view has different number of columns than the query's columns".to_string(),
+            )
+            .into());
+        }
+        schema
+            .fields()
+            .iter()
+            .zip_eq_fast(columns)
+            .map(|(f, c)| {
+                let mut field = f.clone();
+                field.name = c.real_value();
+                field
+            })
+            .collect()
+    };
+
+    session.create_temporary_view(view_name, format!("{}", query), columns)?;
+
+    Ok(PgResponse::empty_result(StatementType::CREATE_VIEW))
+}
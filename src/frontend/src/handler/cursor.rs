@@ -0,0 +1,114 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+
+use futures::StreamExt;
+use pgwire::pg_field_descriptor::PgFieldDescriptor;
+use pgwire::pg_response::{PgResponse, StatementType};
+use pgwire::types::Row;
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_sqlparser::ast::{Ident, Query, Statement};
+
+use super::query::handle_query;
+use super::{HandlerArgs, PgResponseStream, RwPgResponse};
+
+/// A server-side cursor opened by `DECLARE ... CURSOR FOR ...`.
+///
+/// The underlying query is executed eagerly at `DECLARE` time; `FETCH` simply drains rows out of
+/// the resulting row stream, buffering any rows that were pulled out of the stream but not yet
+/// returned to the client in `buffer`.
+pub struct Cursor {
+    row_stream: PgResponseStream,
+    row_desc: Vec<PgFieldDescriptor>,
+    buffer: VecDeque<Row>,
+    /// Whether `row_stream` has been fully drained.
+    exhausted: bool,
+}
+
+impl Cursor {
+    /// Fetch up to `count` rows, or fewer if the cursor is exhausted first.
+    pub(crate) async fn next_batch(
+        &mut self,
+        count: usize,
+    ) -> Result<(Vec<Row>, Vec<PgFieldDescriptor>)> {
+        let mut rows = Vec::with_capacity(count.min(1024));
+        while rows.len() < count {
+            if let Some(row) = self.buffer.pop_front() {
+                rows.push(row);
+                continue;
+            }
+            if self.exhausted {
+                break;
+            }
+            match self.row_stream.next().await {
+                Some(Ok(row_set)) => self.buffer.extend(row_set),
+                Some(Err(err)) => {
+                    return Err(RwError::from(ErrorCode::InternalError(err.to_string())))
+                }
+                None => self.exhausted = true,
+            }
+        }
+        Ok((rows, self.row_desc.clone()))
+    }
+}
+
+pub async fn handle_declare_cursor(
+    handler_args: HandlerArgs,
+    cursor_name: Ident,
+    query: Box<Query>,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session.clone();
+    let query_result = handle_query(handler_args, Statement::Query(query), vec![]).await?;
+
+    let cursor = Cursor {
+        row_desc: query_result.get_row_desc(),
+        row_stream: query_result.into_values_stream(),
+        buffer: VecDeque::new(),
+        exhausted: false,
+    };
+    session.add_cursor(cursor_name.real_value(), cursor).await?;
+
+    Ok(PgResponse::empty_result(StatementType::DECLARE_CURSOR))
+}
+
+pub async fn handle_fetch_cursor(
+    handler_args: HandlerArgs,
+    cursor_name: Ident,
+    count: Option<u64>,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+    // `None` means `FETCH NEXT`, i.e. fetch a single row. `FETCH ALL` is encoded as `u64::MAX`.
+    let count = count.unwrap_or(1).min(usize::MAX as u64) as usize;
+
+    let (rows, row_desc) = session
+        .fetch_cursor(&cursor_name.real_value(), count)
+        .await?;
+
+    Ok(PgResponse::new_for_stream(
+        StatementType::FETCH,
+        None,
+        rows.into(),
+        row_desc,
+    ))
+}
+
+pub async fn handle_close_cursor(
+    handler_args: HandlerArgs,
+    cursor_name: Option<Ident>,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+    session.drop_cursor(cursor_name.map(|name| name.real_value())).await?;
+    Ok(PgResponse::empty_result(StatementType::CLOSE_CURSOR))
+}
@@ -32,6 +32,11 @@ pub async fn handle_drop_view(
     let search_path = session.config().get_search_path();
     let user_name = &session.auth_context().user_name;
 
+    if schema_name.is_none() && session.get_temporary_view(&table_name).is_some() {
+        session.drop_temporary_view(&table_name)?;
+        return Ok(PgResponse::empty_result(StatementType::DROP_VIEW));
+    }
+
     let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
 
     let view_id = {
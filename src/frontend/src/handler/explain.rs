@@ -33,7 +33,7 @@ use crate::optimizer::plan_node::{Convention, Explain};
 use crate::optimizer::OptimizerContext;
 use crate::scheduler::BatchPlanFragmenter;
 use crate::stream_fragmenter::build_graph;
-use crate::utils::explain_stream_graph;
+use crate::utils::{explain_stream_graph, explain_stream_graph_as_dot};
 
 pub async fn handle_explain(
     handler_args: HandlerArgs,
@@ -70,6 +70,7 @@ pub async fn handle_explain(
                 source_schema,
                 source_watermarks,
                 append_only,
+                on_conflict,
                 ..
             } => match check_create_table_with_source(&handler_args.with_options, source_schema)? {
                 Some(s) => {
@@ -82,6 +83,7 @@ pub async fn handle_explain(
                         source_watermarks,
                         ColumnIdGenerator::new_initial(),
                         append_only,
+                        on_conflict,
                     )
                     .await?
                     .0
@@ -95,6 +97,7 @@ pub async fn handle_explain(
                         ColumnIdGenerator::new_initial(),
                         source_watermarks,
                         append_only,
+                        on_conflict,
                     )?
                     .0
                 }
@@ -182,6 +185,28 @@ pub async fn handle_explain(
                     );
                 }
             }
+            ExplainType::Dot => match plan.convention() {
+                Convention::Stream => {
+                    let graph = build_graph(plan);
+                    rows.extend(
+                        explain_stream_graph_as_dot(&graph)
+                            .lines()
+                            .map(|s| Row::new(vec![Some(s.to_string().into())])),
+                    );
+                }
+                _ => {
+                    return Err(ErrorCode::NotImplemented(
+                        "explain (dot) is only supported for streaming queries".to_string(),
+                        None.into(),
+                    )
+                    .into());
+                }
+            },
+            ExplainType::Json => {
+                return Err(
+                    ErrorCode::NotImplemented("explain (json)".to_string(), None.into()).into(),
+                );
+            }
         }
         rows
     };
@@ -127,6 +127,7 @@ pub fn handle_bind(
                 bound,
                 param_types,
                 dependent_relations,
+                as_of,
             } = bound_result;
 
             let new_bound = bound.bind_parameter(params, param_formats)?;
@@ -136,6 +137,7 @@ pub fn handle_bind(
                 param_types,
                 dependent_relations,
                 bound: new_bound,
+                as_of,
             };
             Ok(Portal::Portal(PortalResult {
                 bound_result: new_bound_result,
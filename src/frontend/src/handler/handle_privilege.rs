@@ -70,6 +70,41 @@ fn make_prost_privilege(
                 grant_objs.push(PbObject::TableId(table.id().table_id));
             }
         }
+        GrantObjects::Tables(tables) => {
+            let db_name = session.database();
+            let search_path = session.config().get_search_path();
+            let user_name = &session.auth_context().user_name;
+
+            for name in tables {
+                let (schema_name, table_name) =
+                    Binder::resolve_schema_qualified_name(db_name, name)?;
+                let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+                let (table, _) = reader.get_table_by_name(db_name, schema_path, &table_name)?;
+                grant_objs.push(PbObject::TableId(table.id().table_id));
+            }
+        }
+        GrantObjects::Sinks(sinks) => {
+            let db_name = session.database();
+            let search_path = session.config().get_search_path();
+            let user_name = &session.auth_context().user_name;
+
+            for name in sinks {
+                let (schema_name, sink_name) =
+                    Binder::resolve_schema_qualified_name(db_name, name)?;
+                let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+                let (sink, _) = reader.get_sink_by_name(db_name, schema_path, &sink_name)?;
+                grant_objs.push(PbObject::SinkId(sink.id.sink_id));
+            }
+        }
+        GrantObjects::AllTablesInSchema { schemas } => {
+            for schema in schemas {
+                let schema_name = Binder::resolve_schema_name(schema)?;
+                let schema = reader.get_schema_by_name(session.database(), &schema_name)?;
+                grant_objs.push(PbObject::AllTablesSchemaId(schema.id()));
+            }
+        }
         GrantObjects::Sources(sources) => {
             let db_name = session.database();
             let search_path = session.config().get_search_path();
@@ -38,6 +38,11 @@ mod alter_relation_rename;
 mod alter_system;
 mod alter_table_column;
 pub mod alter_user;
+mod analyze;
+mod cancel_job;
+mod comment;
+mod copy;
+pub mod create_aggregate;
 pub mod create_connection;
 mod create_database;
 pub mod create_function;
@@ -50,6 +55,7 @@ pub mod create_table;
 pub mod create_table_as;
 pub mod create_user;
 pub mod create_view;
+pub mod cursor;
 mod describe;
 mod drop_connection;
 mod drop_database;
@@ -201,6 +207,23 @@ pub async fn handle(
             )
             .await
         }
+        Statement::CreateAggregate {
+            or_replace,
+            name,
+            args,
+            returns,
+            params,
+        } => {
+            create_aggregate::handle_create_aggregate(
+                handler_args,
+                or_replace,
+                name,
+                args,
+                returns,
+                params,
+            )
+            .await
+        }
         Statement::CreateTable {
             name,
             columns,
@@ -214,6 +237,7 @@ pub async fn handle(
             source_schema,
             source_watermarks,
             append_only,
+            on_conflict,
         } => {
             if or_replace {
                 return Err(ErrorCode::NotImplemented(
@@ -230,6 +254,13 @@ pub async fn handle(
                 .into());
             }
             if let Some(query) = query {
+                if on_conflict.is_some() {
+                    return Err(ErrorCode::NotImplemented(
+                        "ON CONFLICT on CREATE TABLE AS".to_string(),
+                        None.into(),
+                    )
+                    .into());
+                }
                 return create_table_as::handle_create_as(
                     handler_args,
                     name,
@@ -249,6 +280,7 @@ pub async fn handle(
                 source_schema,
                 source_watermarks,
                 append_only,
+                on_conflict,
             )
             .await
         }
@@ -269,7 +301,9 @@ pub async fn handle(
             handle_privilege::handle_revoke_privilege(handler_args, stmt).await
         }
         Statement::Describe { name } => describe::handle_describe(handler_args, name),
-        Statement::ShowObjects(show_object) => show::handle_show_object(handler_args, show_object),
+        Statement::ShowObjects(show_object) => {
+            show::handle_show_object(handler_args, show_object).await
+        }
         Statement::ShowCreateObject { create_type, name } => {
             show::handle_show_create_object(handler_args, create_type, name)
         }
@@ -332,8 +366,14 @@ pub async fn handle(
         | Statement::Insert { .. }
         | Statement::Delete { .. }
         | Statement::Update { .. } => query::handle_query(handler_args, stmt, formats).await,
+        Statement::Copy {
+            table_name,
+            columns,
+            values,
+        } => copy::handle_copy(handler_args, table_name, columns, values).await,
         Statement::CreateView {
             materialized,
+            temporary,
             name,
             columns,
             query,
@@ -356,12 +396,34 @@ pub async fn handle(
                 .into());
             }
             if materialized {
+                assert!(!temporary, "the parser should reject this combination");
                 create_mv::handle_create_mv(handler_args, name, *query, columns).await
+            } else if temporary {
+                create_view::handle_create_temporary_view(handler_args, name, columns, *query)
+                    .await
             } else {
                 create_view::handle_create_view(handler_args, name, columns, *query).await
             }
         }
+        Statement::Comment {
+            object_type,
+            object_name,
+            comment,
+        } => comment::handle_comment(handler_args, object_type, object_name, comment).await,
         Statement::Flush => flush::handle_flush(handler_args).await,
+        Statement::Analyze { table_name } => {
+            analyze::handle_analyze(handler_args, table_name).await
+        }
+        Statement::CancelJobs(job_ids) => cancel_job::handle_cancel(handler_args, job_ids).await,
+        Statement::DeclareCursor { cursor_name, query } => {
+            cursor::handle_declare_cursor(handler_args, cursor_name, query).await
+        }
+        Statement::FetchCursor { cursor_name, count } => {
+            cursor::handle_fetch_cursor(handler_args, cursor_name, count).await
+        }
+        Statement::CloseCursor { cursor_name } => {
+            cursor::handle_close_cursor(handler_args, cursor_name).await
+        }
         Statement::SetVariable {
             local: _,
             variable,
@@ -450,26 +512,49 @@ pub async fn handle(
         // 1. Fully support transaction is too hard and gives few benefits to us.
         // 2. Some client e.g. psycopg2 will use this statement.
         // TODO: Track issues #2595 #2541
-        Statement::StartTransaction { .. } => Ok(PgResponse::empty_result_with_notice(
-            START_TRANSACTION,
-            "Ignored temporarily. See detail in issue#2541".to_string(),
-        )),
-        Statement::BEGIN { .. } => Ok(PgResponse::empty_result_with_notice(
-            BEGIN,
-            "Ignored temporarily. See detail in issue#2541".to_string(),
-        )),
-        Statement::Abort { .. } => Ok(PgResponse::empty_result_with_notice(
-            ABORT,
-            "Ignored temporarily. See detail in issue#2541".to_string(),
-        )),
-        Statement::Commit { .. } => Ok(PgResponse::empty_result_with_notice(
-            COMMIT,
-            "Ignored temporarily. See detail in issue#2541".to_string(),
-        )),
-        Statement::Rollback { .. } => Ok(PgResponse::empty_result_with_notice(
-            ROLLBACK,
-            "Ignored temporarily. See detail in issue#2541".to_string(),
-        )),
+        //
+        // The only exception is `BEGIN`/`START TRANSACTION READ ONLY`, which pins the session to
+        // the Hummock snapshot at the time of `BEGIN` so that every statement until
+        // `COMMIT`/`ROLLBACK`/`ABORT` sees the same consistent snapshot.
+        Statement::StartTransaction { modes } => {
+            if modes.contains(&TransactionMode::AccessMode(TransactionAccessMode::ReadOnly)) {
+                handler_args.session.begin_read_only_txn().await?;
+            }
+            Ok(PgResponse::empty_result_with_notice(
+                START_TRANSACTION,
+                "Ignored temporarily. See detail in issue#2541".to_string(),
+            ))
+        }
+        Statement::BEGIN { modes } => {
+            if modes.contains(&TransactionMode::AccessMode(TransactionAccessMode::ReadOnly)) {
+                handler_args.session.begin_read_only_txn().await?;
+            }
+            Ok(PgResponse::empty_result_with_notice(
+                BEGIN,
+                "Ignored temporarily. See detail in issue#2541".to_string(),
+            ))
+        }
+        Statement::Abort { .. } => {
+            handler_args.session.end_txn();
+            Ok(PgResponse::empty_result_with_notice(
+                ABORT,
+                "Ignored temporarily. See detail in issue#2541".to_string(),
+            ))
+        }
+        Statement::Commit { .. } => {
+            handler_args.session.end_txn();
+            Ok(PgResponse::empty_result_with_notice(
+                COMMIT,
+                "Ignored temporarily. See detail in issue#2541".to_string(),
+            ))
+        }
+        Statement::Rollback { .. } => {
+            handler_args.session.end_txn();
+            Ok(PgResponse::empty_result_with_notice(
+                ROLLBACK,
+                "Ignored temporarily. See detail in issue#2541".to_string(),
+            ))
+        }
         Statement::SetTransaction { .. } => Ok(PgResponse::empty_result_with_notice(
             SET_TRANSACTION,
             "Ignored temporarily. See detail in issue#2541".to_string(),
@@ -262,4 +262,66 @@ mod tests {
             .unwrap();
         assert!(&session.check_privileges(&check_items).is_ok());
     }
+
+    #[tokio::test]
+    async fn test_check_privileges_on_table() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        let session = frontend.session_ref();
+        frontend
+            .run_sql("CREATE TABLE t (v1 int, v2 int)")
+            .await
+            .unwrap();
+        frontend
+            .run_sql(
+                "CREATE USER user WITH NOSUPERUSER PASSWORD 'md5827ccb0eea8a706c4c34a16891f84e7b'",
+            )
+            .await
+            .unwrap();
+
+        let table_id = {
+            let catalog_reader = session.env().catalog_reader();
+            catalog_reader
+                .read_guard()
+                .get_table_by_name(
+                    DEFAULT_DATABASE_NAME,
+                    crate::catalog::root_catalog::SchemaPath::Name("public"),
+                    "t",
+                )
+                .unwrap()
+                .0
+                .id()
+                .table_id
+        };
+        let select_items = vec![ObjectCheckItem::new(
+            DEFAULT_SUPER_USER_ID,
+            PbAction::Select,
+            PbObject::TableId(table_id),
+        )];
+        let insert_items = vec![ObjectCheckItem::new(
+            DEFAULT_SUPER_USER_ID,
+            PbAction::Insert,
+            PbObject::TableId(table_id),
+        )];
+
+        let database = DEFAULT_DATABASE_NAME.to_string();
+        let user_name = "user".to_string();
+        let user_id = {
+            let user_reader = session.env().user_info_reader();
+            user_reader
+                .read_guard()
+                .get_user_by_name("user")
+                .unwrap()
+                .id
+        };
+        let user_session = frontend.session_user_ref(database, user_name, user_id);
+        assert!(&user_session.check_privileges(&select_items).is_err());
+        assert!(&user_session.check_privileges(&insert_items).is_err());
+
+        frontend
+            .run_sql("GRANT SELECT, INSERT ON t TO user")
+            .await
+            .unwrap();
+        assert!(&user_session.check_privileges(&select_items).is_ok());
+        assert!(&user_session.check_privileges(&insert_items).is_ok());
+    }
 }
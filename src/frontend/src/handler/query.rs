@@ -26,6 +26,7 @@ use risingwave_common::catalog::Schema;
 use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_common::session_config::QueryMode;
 use risingwave_common::types::DataType;
+use risingwave_common::util::epoch::Epoch;
 use risingwave_sqlparser::ast::{SetExpr, Statement};
 
 use super::extended_handle::{PortalResult, PrepareStatement, PreparedResult};
@@ -115,6 +116,7 @@ pub struct BoundResult {
     pub(crate) bound: BoundStatement,
     pub(crate) param_types: Vec<DataType>,
     pub(crate) dependent_relations: HashSet<TableId>,
+    pub(crate) as_of: Option<Epoch>,
 }
 
 fn gen_bound(
@@ -138,6 +140,7 @@ fn gen_bound(
         bound,
         param_types: binder.export_param_types()?,
         dependent_relations: binder.included_relations(),
+        as_of: binder.as_of(),
     })
 }
 
@@ -150,6 +153,7 @@ pub struct BatchQueryPlanResult {
     // subset of the final one. i.e. the final one may contain more implicit dependencies on
     // indices.
     pub(crate) dependent_relations: Vec<TableId>,
+    pub(crate) as_of: Option<Epoch>,
 }
 
 fn gen_batch_query_plan(
@@ -162,6 +166,7 @@ fn gen_batch_query_plan(
         must_dist,
         bound,
         dependent_relations,
+        as_of,
         ..
     } = bind_result;
 
@@ -204,6 +209,7 @@ fn gen_batch_query_plan(
         schema,
         stmt_type,
         dependent_relations: dependent_relations.into_iter().collect_vec(),
+        as_of,
     })
 }
 
@@ -257,6 +263,7 @@ struct BatchPlanFragmenterResult {
     pub(crate) stmt_type: StatementType,
     pub(crate) _dependent_relations: Vec<TableId>,
     pub(crate) notice: String,
+    pub(crate) as_of: Option<Epoch>,
 }
 
 fn gen_batch_plan_fragmenter(
@@ -269,6 +276,7 @@ fn gen_batch_plan_fragmenter(
         schema,
         stmt_type,
         dependent_relations,
+        as_of,
     } = plan_result;
 
     let context = plan.plan_base().ctx.clone();
@@ -293,6 +301,7 @@ fn gen_batch_plan_fragmenter(
         stmt_type,
         _dependent_relations: dependent_relations,
         notice,
+        as_of,
     })
 }
 
@@ -307,6 +316,7 @@ async fn execute(
         schema,
         stmt_type,
         notice,
+        as_of,
         ..
     } = plan_fragmenter_result;
 
@@ -326,7 +336,10 @@ async fn execute(
     let first_field_format = formats.first().copied().unwrap_or(Format::Text);
 
     let mut row_stream = {
-        let query_epoch = session.config().get_query_epoch();
+        // A `FOR SYSTEM_TIME AS OF` clause takes precedence over the `query_epoch` session
+        // variable, since it expresses the same kind of historical read but scoped to a single
+        // query rather than the whole session.
+        let query_epoch = as_of.or_else(|| session.config().get_query_epoch());
         let query_snapshot = if let Some(query_epoch) = query_epoch {
             PinnedHummockSnapshot::Other(query_epoch)
         } else {
@@ -13,9 +13,11 @@
 // limitations under the License.
 
 use itertools::Itertools;
+use lazy_static::lazy_static;
 use pgwire::pg_field_descriptor::PgFieldDescriptor;
 use pgwire::pg_response::{PgResponse, StatementType};
 use pgwire::types::Row;
+use regex::Regex;
 use risingwave_common::catalog::{ColumnDesc, DEFAULT_SCHEMA_NAME};
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::DataType;
@@ -59,7 +61,51 @@ fn schema_or_default(schema: &Option<Ident>) -> String {
         .map_or_else(|| DEFAULT_SCHEMA_NAME.to_string(), |s| s.real_value())
 }
 
-pub fn handle_show_object(handler_args: HandlerArgs, command: ShowObject) -> Result<RwPgResponse> {
+pub async fn handle_show_object(
+    handler_args: HandlerArgs,
+    command: ShowObject,
+) -> Result<RwPgResponse> {
+    if matches!(command, ShowObject::Jobs) {
+        let progress = handler_args
+            .session
+            .env()
+            .meta_client()
+            .list_ddl_progress()
+            .await?;
+        let rows = progress
+            .into_iter()
+            .map(|p| {
+                Row::new(vec![
+                    Some(p.id.to_string().into()),
+                    Some(p.statement.into()),
+                    Some(p.progress.into()),
+                ])
+            })
+            .collect_vec();
+        return Ok(PgResponse::new_for_stream(
+            StatementType::SHOW_COMMAND,
+            None,
+            rows.into(),
+            vec![
+                PgFieldDescriptor::new(
+                    "Id".to_owned(),
+                    DataType::Varchar.to_oid(),
+                    DataType::Varchar.type_len(),
+                ),
+                PgFieldDescriptor::new(
+                    "Statement".to_owned(),
+                    DataType::Varchar.to_oid(),
+                    DataType::Varchar.type_len(),
+                ),
+                PgFieldDescriptor::new(
+                    "Progress".to_owned(),
+                    DataType::Varchar.to_oid(),
+                    DataType::Varchar.type_len(),
+                ),
+            ],
+        ));
+    }
+
     let session = handler_args.session;
     let catalog_reader = session.env().catalog_reader().read_guard();
 
@@ -98,6 +144,7 @@ pub fn handle_show_object(handler_args: HandlerArgs, command: ShowObject) -> Res
             .iter_sink()
             .map(|t| t.name.clone())
             .collect(),
+        ShowObject::Jobs => unreachable!("handled above"),
         ShowObject::Columns { table } => {
             let columns = get_columns_from_table(&session, table)?;
             let rows = col_descs_to_rows(columns);
@@ -191,6 +238,21 @@ pub fn handle_show_object(handler_args: HandlerArgs, command: ShowObject) -> Res
     ))
 }
 
+lazy_static! {
+    /// Matches a `WITH` option whose key looks like it holds a connector credential, e.g.
+    /// `password = '...'`, `ssl.key.password = '...'` or `access_key = '...'`.
+    static ref SENSITIVE_OPTION_RE: Regex = Regex::new(
+        r"(?i)((?:^|[,(\s])[\w.]*(?:password|secret|token|access_key|private_key)[\w.]*\s*=\s*')[^']*(')"
+    )
+    .unwrap();
+}
+
+/// Masks the value of any `WITH` option that looks like a connector secret in a `CREATE ...`
+/// definition, so that `SHOW CREATE` never leaks credentials back to the client.
+fn mask_sensitive_options(sql: &str) -> String {
+    SENSITIVE_OPTION_RE.replace_all(sql, "$1****$2").into_owned()
+}
+
 pub fn handle_show_create_object(
     handle_args: HandlerArgs,
     show_create_type: ShowCreateType,
@@ -223,7 +285,25 @@ pub fn handle_show_create_object(
                 .ok_or_else(|| CatalogError::NotFound("table", name.to_string()))?;
             table.create_sql()
         }
-        _ => {
+        ShowCreateType::Source => {
+            let source = schema
+                .get_source_by_name(&object_name)
+                .ok_or_else(|| CatalogError::NotFound("source", name.to_string()))?;
+            source.create_sql()
+        }
+        ShowCreateType::Sink => {
+            let sink = schema
+                .get_sink_by_name(&object_name)
+                .ok_or_else(|| CatalogError::NotFound("sink", name.to_string()))?;
+            sink.definition.clone()
+        }
+        ShowCreateType::Index => {
+            let index = schema
+                .get_index_by_name(&object_name)
+                .ok_or_else(|| CatalogError::NotFound("index", name.to_string()))?;
+            index.index_table.create_sql()
+        }
+        ShowCreateType::Function => {
             return Err(ErrorCode::NotImplemented(
                 format!("show create on: {}", show_create_type),
                 None.into(),
@@ -231,6 +311,9 @@ pub fn handle_show_create_object(
             .into());
         }
     };
+    // WITH options may carry connector secrets (e.g. `password`, `access_key`); mask them before
+    // returning the definition to the client.
+    let sql = mask_sensitive_options(&sql);
     let name = format!("{}.{}", schema_name, object_name);
 
     Ok(PgResponse::new_for_stream(
@@ -140,9 +140,24 @@ impl Default for FrontendOpts {
 }
 
 use std::future::Future;
+use std::path::PathBuf;
 use std::pin::Pin;
 
 use pgwire::pg_protocol::TlsConfig;
+use risingwave_common::config::{load_config, ServerConfig};
+
+/// Builds the TLS config for the pgwire server from the `[server]` section of the config file.
+/// Returns `None`, disabling TLS, unless both `ssl_cert` and `ssl_key` are configured.
+fn tls_config_from_server_config(config: &ServerConfig) -> Option<TlsConfig> {
+    let cert = config.ssl_cert.as_ref()?;
+    let key = config.ssl_key.as_ref()?;
+    let ca_cert = config.ssl_ca_cert.as_ref().map(PathBuf::from);
+    Some(TlsConfig::new(
+        PathBuf::from(cert),
+        PathBuf::from(key),
+        ca_cert,
+    ))
+}
 
 /// Start frontend
 pub fn start(opts: FrontendOpts) -> Pin<Box<dyn Future<Output = ()> + Send>> {
@@ -150,8 +165,12 @@ pub fn start(opts: FrontendOpts) -> Pin<Box<dyn Future<Output = ()> + Send>> {
     // slow compile in release mode.
     Box::pin(async move {
         let listen_addr = opts.listen_addr.clone();
+        let tls_config = {
+            let config = load_config(&opts.config_path, Some(opts.override_opts.clone()));
+            tls_config_from_server_config(&config.server)
+        };
         let session_mgr = Arc::new(SessionManagerImpl::new(opts).await.unwrap());
-        pg_serve(&listen_addr, session_mgr, Some(TlsConfig::new_default()))
+        pg_serve(&listen_addr, session_mgr, tls_config)
             .await
             .unwrap();
     })
@@ -38,6 +38,8 @@ pub trait FrontendMetaClient: Send + Sync {
 
     async fn cancel_creating_jobs(&self, infos: Vec<CreatingJobInfo>) -> Result<()>;
 
+    async fn cancel_creating_jobs_by_ids(&self, job_ids: Vec<u32>) -> Result<()>;
+
     async fn list_table_fragments(
         &self,
         table_ids: &[u32],
@@ -76,6 +78,10 @@ impl FrontendMetaClient for FrontendMetaClientImpl {
         self.0.cancel_creating_jobs(infos).await
     }
 
+    async fn cancel_creating_jobs_by_ids(&self, job_ids: Vec<u32>) -> Result<()> {
+        self.0.cancel_creating_jobs_by_ids(job_ids).await
+    }
+
     async fn list_table_fragments(
         &self,
         table_ids: &[u32],
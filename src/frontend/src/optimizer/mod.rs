@@ -396,6 +396,7 @@ impl PlanRoot {
         pk_column_ids: Vec<ColumnId>,
         row_id_index: Option<usize>,
         append_only: bool,
+        conflict_behavior: ConflictBehavior,
         watermark_descs: Vec<WatermarkDesc>,
         version: Option<TableVersion>,
     ) -> Result<StreamMaterialize> {
@@ -431,11 +432,6 @@ impl PlanRoot {
             stream_plan = StreamRowIdGen::new(stream_plan, row_id_index).into();
         }
 
-        let conflict_behavior = match append_only {
-            true => ConflictBehavior::NoCheck,
-            false => ConflictBehavior::Overwrite,
-        };
-
         let pk_column_indices = {
             let mut id_to_idx = HashMap::new();
 
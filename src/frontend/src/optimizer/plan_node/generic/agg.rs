@@ -82,7 +82,15 @@ impl<PlanRef: GenericPlanRef> Agg<PlanRef> {
             && self.agg_calls.iter().all(|call| {
                 matches!(
                     call.agg_kind,
-                    AggKind::Min | AggKind::Max | AggKind::Sum | AggKind::Count
+                    AggKind::Min
+                        | AggKind::Max
+                        | AggKind::Sum
+                        | AggKind::Count
+                        | AggKind::BoolAnd
+                        | AggKind::BoolOr
+                        | AggKind::BitAnd
+                        | AggKind::BitOr
+                        | AggKind::BitXor
                 ) && !call.distinct
             })
     }
@@ -91,7 +99,15 @@ impl<PlanRef: GenericPlanRef> Agg<PlanRef> {
     pub(crate) fn is_agg_result_affected_by_order(&self) -> bool {
         self.agg_calls
             .iter()
-            .any(|call| matches!(call.agg_kind, AggKind::StringAgg | AggKind::ArrayAgg))
+            .any(|call| {
+                matches!(
+                    call.agg_kind,
+                    AggKind::StringAgg
+                        | AggKind::ArrayAgg
+                        | AggKind::FirstValue
+                        | AggKind::LastValue
+                )
+            })
     }
 
     pub fn new(agg_calls: Vec<PlanAggCall>, group_key: Vec<usize>, input: PlanRef) -> Self {
@@ -364,7 +380,11 @@ impl<PlanRef: stream::StreamPlanRef> Agg<PlanRef> {
                 | AggKind::Max
                 | AggKind::StringAgg
                 | AggKind::ArrayAgg
-                | AggKind::FirstValue => {
+                | AggKind::FirstValue
+                | AggKind::LastValue
+                | AggKind::Mode
+                | AggKind::PercentileCont
+                | AggKind::PercentileDisc => {
                     if !in_append_only {
                         // columns with order requirement in state table
                         let sort_keys = {
@@ -375,17 +395,35 @@ impl<PlanRef: stream::StreamPlanRef> Agg<PlanRef> {
                                 AggKind::Max => {
                                     vec![(OrderType::descending(), agg_call.inputs[0].index)]
                                 }
-                                AggKind::StringAgg | AggKind::ArrayAgg => agg_call
+                                AggKind::StringAgg
+                                | AggKind::ArrayAgg
+                                | AggKind::Mode
+                                | AggKind::PercentileCont
+                                | AggKind::PercentileDisc
+                                | AggKind::FirstValue => agg_call
                                     .order_by
                                     .iter()
                                     .map(|o| (o.order_type, o.column_index))
                                     .collect(),
+                                // `last_value` picks the last row in the given order, which is
+                                // the same as picking the first row in the reverse order.
+                                AggKind::LastValue => agg_call
+                                    .order_by
+                                    .iter()
+                                    .map(|o| (o.order_type.reverse(), o.column_index))
+                                    .collect(),
                                 _ => unreachable!(),
                             }
                         };
                         // other columns that should be contained in state table
                         let include_keys = match agg_call.agg_kind {
-                            AggKind::StringAgg | AggKind::ArrayAgg => {
+                            AggKind::StringAgg
+                            | AggKind::ArrayAgg
+                            | AggKind::Mode
+                            | AggKind::PercentileCont
+                            | AggKind::PercentileDisc
+                            | AggKind::FirstValue
+                            | AggKind::LastValue => {
                                 agg_call.inputs.iter().map(|i| i.index).collect()
                             }
                             _ => vec![],
@@ -403,7 +441,12 @@ impl<PlanRef: stream::StreamPlanRef> Agg<PlanRef> {
                 | AggKind::StddevPop
                 | AggKind::StddevSamp
                 | AggKind::VarPop
-                | AggKind::VarSamp => AggCallState::ResultValue,
+                | AggKind::VarSamp
+                | AggKind::BoolAnd
+                | AggKind::BoolOr
+                | AggKind::BitAnd
+                | AggKind::BitOr
+                | AggKind::BitXor => AggCallState::ResultValue,
                 AggKind::ApproxCountDistinct => {
                     if !in_append_only {
                         // FIXME: now the approx count distinct on a non-append-only stream does not
@@ -636,7 +679,16 @@ impl PlanAggCall {
 
     pub fn partial_to_total_agg_call(&self, partial_output_idx: usize) -> PlanAggCall {
         let total_agg_kind = match &self.agg_kind {
-            AggKind::Min | AggKind::Max | AggKind::StringAgg | AggKind::FirstValue => self.agg_kind,
+            AggKind::Min
+            | AggKind::Max
+            | AggKind::StringAgg
+            | AggKind::FirstValue
+            | AggKind::LastValue
+            | AggKind::BoolAnd
+            | AggKind::BoolOr
+            | AggKind::BitAnd
+            | AggKind::BitOr
+            | AggKind::BitXor => self.agg_kind,
             AggKind::Count | AggKind::ApproxCountDistinct | AggKind::Sum0 => AggKind::Sum0,
             AggKind::Sum => AggKind::Sum,
             AggKind::Avg => {
@@ -645,6 +697,9 @@ impl PlanAggCall {
             AggKind::ArrayAgg => {
                 panic!("2-phase ArrayAgg is not supported yet")
             }
+            AggKind::Mode | AggKind::PercentileCont | AggKind::PercentileDisc => {
+                panic!("2-phase ordered-set aggregate is not supported yet")
+            }
             AggKind::StddevPop | AggKind::StddevSamp | AggKind::VarPop | AggKind::VarSamp => {
                 panic!("Stddev/Var aggregation should have been rewritten to Sum, Count and Case")
             }
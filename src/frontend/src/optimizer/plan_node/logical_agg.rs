@@ -199,7 +199,16 @@ impl LogicalAgg {
     fn all_local_aggs_are_stateless(&self, stream_input_append_only: bool) -> bool {
         self.agg_calls().iter().all(|c| {
             matches!(c.agg_kind, AggKind::Sum | AggKind::Count)
-                || (matches!(c.agg_kind, AggKind::Min | AggKind::Max) && stream_input_append_only)
+                || (matches!(
+                    c.agg_kind,
+                    AggKind::Min
+                        | AggKind::Max
+                        | AggKind::BoolAnd
+                        | AggKind::BoolOr
+                        | AggKind::BitAnd
+                        | AggKind::BitOr
+                        | AggKind::BitXor
+                ) && stream_input_append_only)
         })
     }
 
@@ -443,6 +452,7 @@ impl LogicalAggBuilder {
         // TODO(stonepage): refactor it and unify the 2-phase agg rewriting logic
         let mut has_non_distinct_string_agg = false;
         let mut has_non_distinct_array_agg = false;
+        let mut has_non_distinct_approx_count_distinct = false;
         self.agg_calls.iter().for_each(|agg_call| {
             if agg_call.distinct {
                 has_distinct = true;
@@ -456,15 +466,38 @@ impl LogicalAggBuilder {
             if !agg_call.distinct && agg_call.agg_kind == AggKind::ArrayAgg {
                 has_non_distinct_array_agg = true;
             }
+            if !agg_call.distinct && agg_call.agg_kind == AggKind::ApproxCountDistinct {
+                has_non_distinct_approx_count_distinct = true;
+            }
         });
 
-        // order by is disallowed occur with distinct because we can not diectly rewrite agg with
-        // order by into 2-phase agg.
+        // Order by is disallowed to occur with distinct in general, because we can not directly
+        // rewrite agg with order by into 2-phase agg. The only exception is a distinct aggregate
+        // whose order by keys are a subset of its own arguments (already enforced by
+        // `Binder::bind_agg`), and only as long as rewriting distinct aggregates won't need an
+        // `Expand` (i.e. there's at most one distinct-by column group), since `DistinctAggRule`
+        // doesn't thread `ORDER BY` through `Expand`.
         if has_distinct && has_order_by {
-            return Err(ErrorCode::InvalidInputSyntax(
-                "Order by aggregates are disallowed to occur with distinct aggregates".into(),
-            )
-            .into());
+            let distinct_aggs = self.agg_calls.iter().filter(|c| c.distinct).collect_vec();
+            let n_different_distinct = distinct_aggs
+                .iter()
+                .unique_by(|c| c.input_indices()[0])
+                .count();
+            let order_by_is_safe = n_different_distinct <= 1
+                && self.agg_calls.iter().all(|c| {
+                    c.order_by.is_empty()
+                        || (c.distinct
+                            && c
+                                .order_by
+                                .iter()
+                                .all(|o| c.input_indices().contains(&o.column_index)))
+                });
+            if !order_by_is_safe {
+                return Err(ErrorCode::InvalidInputSyntax(
+                    "Order by aggregates are disallowed to occur with distinct aggregates".into(),
+                )
+                .into());
+            }
         }
 
         // when there are distinct aggregates, non-distinct aggregates will be rewritten as
@@ -484,6 +517,17 @@ impl LogicalAggBuilder {
             )
             .into());
         }
+        // `approx_count_distinct`'s `HyperLogLog` registers can't be merged by summing the
+        // per-group approximate counts the way `Sum0` merges `Count`/`ApproxCountDistinct` in the
+        // ordinary two-phase rewrite: doing so would silently return a wildly inflated estimate
+        // instead of a correct one, so we reject the combination instead of rewriting it wrong.
+        if has_distinct && has_non_distinct_approx_count_distinct {
+            return Err(ErrorCode::NotImplemented(
+                "Non-distinct approx_count_distinct can't appear with distinct aggregates".into(),
+                TrackingIssue::none(),
+            )
+            .into());
+        }
 
         Ok(())
     }
@@ -532,7 +576,12 @@ impl LogicalAggBuilder {
             | AggKind::StddevSamp
             | AggKind::StddevPop
             | AggKind::VarPop
-            | AggKind::VarSamp => {
+            | AggKind::VarSamp
+            | AggKind::BoolAnd
+            | AggKind::BoolOr
+            | AggKind::BitAnd
+            | AggKind::BitOr
+            | AggKind::BitXor => {
                 order_by = OrderBy::any();
             }
             _ => {
@@ -18,7 +18,7 @@ use std::fmt;
 
 use fixedbitset::FixedBitSet;
 use itertools::{EitherOrBoth, Itertools};
-use risingwave_common::catalog::Schema;
+use risingwave_common::catalog::{ColumnId, Schema, TableDesc};
 use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_pb::plan_common::JoinType;
 use risingwave_pb::stream_plan::ChainType;
@@ -254,8 +254,8 @@ impl LogicalJoin {
 
     /// Try to simplify the outer join with the predicate on the top of the join
     ///
-    /// now it is just a naive implementation for comparison expression, we can give a more general
-    /// implementation with constant folding in future
+    /// now it is just a naive implementation for comparison expressions and `IS NOT NULL`, we can
+    /// give a more general implementation with constant folding in future
     fn simplify_outer(predicate: &Condition, left_col_num: usize, join_type: JoinType) -> JoinType {
         let (mut gen_null_in_left, mut gen_null_in_right) = match join_type {
             JoinType::LeftOuter => (false, true),
@@ -284,6 +284,16 @@ impl LogicalJoin {
                             }
                         }
                     }
+                    ExprType::IsNotNull => {
+                        if let ExprImpl::InputRef(input) = &func.inputs()[0] {
+                            let idx = input.index;
+                            if idx < left_col_num {
+                                gen_null_in_left = false;
+                            } else {
+                                gen_null_in_right = false;
+                            }
+                        }
+                    }
                     _ => {}
                 };
             }
@@ -999,29 +1009,64 @@ impl LogicalJoin {
             )));
         }
 
-        let table_desc = logical_scan.table_desc();
-
-        // Verify that right join key columns are the primary key of the lookup table.
-        let order_col_ids = table_desc.order_column_ids();
-        let order_col_ids_len = order_col_ids.len();
-        let output_column_ids = logical_scan.output_column_ids();
-
-        // Reorder the join equal predicate to match the order key.
-        let mut reorder_idx = vec![];
-        for order_col_id in order_col_ids {
-            for (i, eq_idx) in predicate.right_eq_indexes().into_iter().enumerate() {
-                if order_col_id == output_column_ids[eq_idx] {
-                    reorder_idx.push(i);
-                    break;
+        // Reorder the join equal predicate to match the order key of `table_desc`. Returns
+        // `None` if the equivalence condition doesn't contain exactly the order key.
+        fn match_order_key(
+            table_desc: &TableDesc,
+            output_column_ids: &[ColumnId],
+            predicate: &EqJoinPredicate,
+        ) -> Option<Vec<usize>> {
+            let order_col_ids = table_desc.order_column_ids();
+            let mut reorder_idx = vec![];
+            for order_col_id in &order_col_ids {
+                for (i, eq_idx) in predicate.right_eq_indexes().into_iter().enumerate() {
+                    if *order_col_id == output_column_ids[eq_idx] {
+                        reorder_idx.push(i);
+                        break;
+                    }
                 }
             }
+            if order_col_ids.len() != predicate.eq_keys().len()
+                || reorder_idx.len() < order_col_ids.len()
+            {
+                None
+            } else {
+                Some(reorder_idx)
+            }
         }
-        if order_col_ids_len != predicate.eq_keys().len() || reorder_idx.len() < order_col_ids_len {
+
+        // Verify that right join key columns are the primary key of the lookup table. If they
+        // are not, fall back to any full covering index whose key matches the join condition
+        // instead, so a temporal join can also do its point lookup through a secondary index.
+        let index_scan = if match_order_key(
+            logical_scan.table_desc(),
+            &logical_scan.output_column_ids(),
+            &predicate,
+        )
+        .is_none()
+        {
+            logical_scan.indexes().iter().find_map(|index| {
+                let index_scan = logical_scan.to_index_scan_if_index_covered(index)?;
+                match_order_key(
+                    index_scan.table_desc(),
+                    &index_scan.output_column_ids(),
+                    &predicate,
+                )
+                .map(|_| index_scan)
+            })
+        } else {
+            None
+        };
+        let logical_scan = &index_scan.unwrap_or_else(|| logical_scan.clone());
+
+        let table_desc = logical_scan.table_desc();
+        let output_column_ids = logical_scan.output_column_ids();
+        let Some(reorder_idx) = match_order_key(table_desc, &output_column_ids, &predicate) else {
             return Err(RwError::from(ErrorCode::NotSupported(
                 "Temporal join requires the lookup table's primary key contained exactly in the equivalence condition".into(),
                 "Please add the primary key of the lookup table to the join condition and remove any other conditions".into(),
             )));
-        }
+        };
         let predicate = predicate.reorder(&reorder_idx);
 
         // Extract the predicate from logical scan. Only pure scan is supported.
@@ -1117,9 +1162,6 @@ impl LogicalJoin {
         if !MaxOneRowVisitor.visit(self.right()) {
             return Ok(None);
         }
-        if self.right().schema().len() != 1 {
-            return Ok(None);
-        }
 
         // Check if the join condition is a correlated comparison
         if predicate.conjunctions.len() > 1 {
@@ -1132,7 +1174,7 @@ impl LogicalJoin {
         };
 
         let condition_cross_inputs = left_ref.index < self.left().schema().len()
-            && right_ref.index == self.left().schema().len() /* right side has only one column */;
+            && right_ref.index == self.left().schema().len() /* the join predicate is only allowed to reference the first column of the right side */;
         if !condition_cross_inputs {
             // Maybe we should panic here because it means some predicates are not pushed down.
             return Ok(None);
@@ -1155,7 +1197,18 @@ impl LogicalJoin {
         }
 
         let left = self.left().to_stream(ctx)?;
-        let right = self.right().to_stream_with_dist_required(
+        // The right side may carry extra columns beyond the scalar we compare against (e.g. an
+        // order-by column kept around by a `LogicalTopN` with `limit 1`), so trim it down to its
+        // first column before feeding it to `StreamDynamicFilter`, which requires a schema of
+        // exactly one column.
+        let right_input = if self.right().schema().len() == 1 {
+            self.right().clone()
+        } else {
+            let mut out_fields = FixedBitSet::with_capacity(self.right().schema().len());
+            out_fields.set(0, true);
+            LogicalProject::with_out_fields(self.right().clone(), &out_fields).into()
+        };
+        let right = right_input.to_stream_with_dist_required(
             &RequiredDist::PhysicalDist(Distribution::Broadcast),
             ctx,
         )?;
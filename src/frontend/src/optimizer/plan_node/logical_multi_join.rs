@@ -37,6 +37,33 @@ use crate::utils::{
     ConnectedComponentLabeller,
 };
 
+/// Default number of output rows assumed for a base relation when no table statistics are
+/// available.
+const DEFAULT_BASE_ROW_COUNT: f64 = 1000.0;
+/// Default selectivity assumed for a single scan-level filter predicate when no statistics are
+/// available, following the same fallback Postgres uses for un-`ANALYZE`d tables.
+const DEFAULT_FILTER_SELECTIVITY: f64 = 0.33;
+/// Default selectivity assumed for an equi-join predicate when no statistics are available.
+const DEFAULT_EQ_JOIN_SELECTIVITY: f64 = 0.1;
+/// Above this number of relations, exhaustive `2^n` subset DP becomes too expensive to run
+/// during planning, so we fall back to the structural greedy heuristic instead.
+const DP_JOIN_ORDERING_RELATION_LIMIT: usize = 10;
+
+/// Estimates the output row count of `plan` when no table statistics are available, using
+/// generic per-operator selectivity constants. This is a coarse proxy cost model, not a real
+/// cardinality estimate.
+fn estimate_cardinality(plan: &PlanRef) -> f64 {
+    if let Some(filter) = plan.as_logical_filter() {
+        let input_card = estimate_cardinality(&filter.input());
+        input_card * DEFAULT_FILTER_SELECTIVITY.powi(filter.predicate().conjunctions.len() as i32)
+    } else if let Some(scan) = plan.as_logical_scan() {
+        DEFAULT_BASE_ROW_COUNT
+            * DEFAULT_FILTER_SELECTIVITY.powi(scan.predicate().conjunctions.len() as i32)
+    } else {
+        DEFAULT_BASE_ROW_COUNT
+    }
+}
+
 /// `LogicalMultiJoin` combines two or more relations according to some condition.
 ///
 /// Each output row has fields from one the inputs. The set of output rows is a subset
@@ -485,6 +512,94 @@ impl LogicalMultiJoin {
         Ok(join_ordering)
     }
 
+    /// Orders the join inputs to minimize the total estimated size of intermediate join
+    /// results.
+    ///
+    /// For up to [`DP_JOIN_ORDERING_RELATION_LIMIT`] relations, this performs an exhaustive
+    /// subset DP (in the style of Selinger's dynamic programming algorithm) over all left-deep
+    /// orderings, using [`estimate_cardinality`] as a proxy cost model since no table
+    /// statistics are available. Beyond the limit, `2^n` subset enumeration becomes too
+    /// expensive, so we fall back to the structural [`Self::heuristic_ordering`].
+    pub(crate) fn cost_based_ordering(&self) -> Result<Vec<usize>> {
+        let n = self.inputs.len();
+        if n <= 1 {
+            return Ok((0..n).collect());
+        }
+        if n > DP_JOIN_ORDERING_RELATION_LIMIT {
+            return self.heuristic_ordering();
+        }
+
+        let (eq_join_conditions, _) = self
+            .on
+            .clone()
+            .split_by_input_col_nums(&self.input_col_nums(), true);
+        let mut connected = vec![vec![false; n]; n];
+        for k in eq_join_conditions.keys() {
+            connected[k.0][k.1] = true;
+            connected[k.1][k.0] = true;
+        }
+
+        let base_cardinality: Vec<f64> = self.inputs.iter().map(estimate_cardinality).collect();
+
+        let full_mask = 1usize << n;
+        // `best_cost[mask]`/`best_card[mask]`: lowest total intermediate result size to join
+        // exactly the relations in `mask`, and the estimated output cardinality of that join.
+        let mut best_cost = vec![f64::INFINITY; full_mask];
+        let mut best_card = vec![0.0; full_mask];
+        let mut best_last = vec![usize::MAX; full_mask];
+
+        for (i, &card) in base_cardinality.iter().enumerate() {
+            best_cost[1 << i] = 0.0;
+            best_card[1 << i] = card;
+        }
+
+        for mask in 1..full_mask {
+            if mask.count_ones() < 2 {
+                continue;
+            }
+            for j in 0..n {
+                let bit = 1 << j;
+                if mask & bit == 0 {
+                    continue;
+                }
+                let rest = mask ^ bit;
+                if best_cost[rest].is_infinite() {
+                    continue;
+                }
+                // Assume a join is selective when it is connected to the rest of the join so
+                // far by an eq join condition; otherwise, treat it as a full cross join.
+                let selectivity = if (0..n).any(|k| rest & (1 << k) != 0 && connected[j][k]) {
+                    DEFAULT_EQ_JOIN_SELECTIVITY
+                } else {
+                    1.0
+                };
+                let card = best_card[rest] * base_cardinality[j] * selectivity;
+                let cost = best_cost[rest] + card;
+                if cost < best_cost[mask] {
+                    best_cost[mask] = cost;
+                    best_card[mask] = card;
+                    best_last[mask] = j;
+                }
+            }
+        }
+
+        let mut ordering = Vec::with_capacity(n);
+        let mut mask = full_mask - 1;
+        while mask.count_ones() > 1 {
+            let j = best_last[mask];
+            assert_ne!(
+                j,
+                usize::MAX,
+                "every non-singleton mask should be reachable from a smaller mask"
+            );
+            ordering.push(j);
+            mask ^= 1 << j;
+        }
+        ordering.push(mask.trailing_zeros() as usize);
+        ordering.reverse();
+        Ok(ordering)
+    }
+
     /// transform multijoin into bushy tree join.
     ///
     /// 1. First, use equivalent condition derivation to get derive join relation.
@@ -883,6 +998,79 @@ mod test {
     use crate::optimizer::optimizer_context::OptimizerContext;
     use crate::optimizer::plan_node::LogicalValues;
     use crate::optimizer::property::FunctionalDependency;
+
+    #[tokio::test]
+    async fn test_cost_based_ordering_prefers_connected_relations() {
+        // Three equally-sized relations chained A - B - C by eq join conditions. Since B is
+        // connected to both A and C but A and C are not directly connected, the optimal
+        // left-deep ordering joins B in last, avoiding ever evaluating the unconnected A-C
+        // cross product.
+        let ty = DataType::Int32;
+        let ctx = OptimizerContext::mock().await;
+        let fields: Vec<Field> = (1..10)
+            .map(|i| Field::with_name(ty.clone(), format!("v{}", i)))
+            .collect();
+        let relation_a = LogicalValues::new(
+            vec![],
+            Schema {
+                fields: fields[0..3].to_vec(),
+            },
+            ctx.clone(),
+        );
+        let relation_b = LogicalValues::new(
+            vec![],
+            Schema {
+                fields: fields[3..6].to_vec(),
+            },
+            ctx.clone(),
+        );
+        let relation_c = LogicalValues::new(
+            vec![],
+            Schema {
+                fields: fields[6..9].to_vec(),
+            },
+            ctx,
+        );
+
+        let a_eq_b: ExprImpl = ExprImpl::FunctionCall(Box::new(
+            FunctionCall::new(
+                Type::Equal,
+                vec![
+                    ExprImpl::InputRef(Box::new(InputRef::new(2, ty.clone()))),
+                    ExprImpl::InputRef(Box::new(InputRef::new(3, ty.clone()))),
+                ],
+            )
+            .unwrap(),
+        ));
+        let join_ab = LogicalJoin::new(
+            relation_a.into(),
+            relation_b.into(),
+            JoinType::Inner,
+            Condition::with_expr(a_eq_b),
+        );
+
+        let b_eq_c: ExprImpl = ExprImpl::FunctionCall(Box::new(
+            FunctionCall::new(
+                Type::Equal,
+                vec![
+                    ExprImpl::InputRef(Box::new(InputRef::new(5, ty.clone()))),
+                    ExprImpl::InputRef(Box::new(InputRef::new(6, ty))),
+                ],
+            )
+            .unwrap(),
+        ));
+        let join_abc = LogicalJoin::new(
+            join_ab.into(),
+            relation_c.into(),
+            JoinType::Inner,
+            Condition::with_expr(b_eq_c),
+        );
+
+        let multi_join = LogicalMultiJoinBuilder::new(join_abc.into()).build();
+
+        assert_eq!(multi_join.cost_based_ordering().unwrap(), vec![2, 1, 0]);
+    }
+
     #[tokio::test]
     async fn fd_derivation_multi_join() {
         // t1: [v0, v1], t2: [v2, v3, v4], t3: [v5, v6]
@@ -36,6 +36,10 @@ use crate::utils::{ColIndexMapping, ColIndexMappingRewriteExt, Condition};
 pub struct PlanWindowFunction {
     pub function_type: WindowFunctionType,
     pub return_type: DataType,
+    pub args: Vec<InputRef>,
+    /// The constant offset of a `lag`/`lead` function, evaluated at bind time. `None` for
+    /// functions that don't take an offset.
+    pub offset: Option<i64>,
     pub partition_by: Vec<InputRef>,
     pub order_by: Vec<ColumnOrder>,
 }
@@ -52,11 +56,26 @@ impl<'a> std::fmt::Debug for PlanWindowFunctionDisplay<'a> {
             f.debug_struct("WindowFunction")
                 .field("function_type", &window_function.function_type)
                 .field("return_type", &window_function.return_type)
+                .field("args", &window_function.args)
+                .field("offset", &window_function.offset)
                 .field("partition_by", &window_function.partition_by)
                 .field("order_by", &window_function.order_by)
                 .finish()
         } else {
-            write!(f, "{}() OVER(", window_function.function_type)?;
+            write!(
+                f,
+                "{}({}) OVER(",
+                window_function.function_type,
+                window_function
+                    .args
+                    .iter()
+                    .format_with(", ", |input_ref, f| {
+                        f(&InputRefDisplay {
+                            input_ref,
+                            input_schema: self.input_schema,
+                        })
+                    })
+            )?;
 
             let mut delim = "";
             if !window_function.partition_by.is_empty() {
@@ -184,8 +203,39 @@ impl LogicalOverAgg {
             partition_by,
             order_by,
         } = window_funcs.into_iter().next().unwrap();
-        assert!(args.is_empty());
-        assert!(return_type == DataType::Int64);
+
+        let offset = if function_type.is_offset_function() {
+            match args.get(1) {
+                Some(offset_expr) => Some(
+                    offset_expr
+                        .clone()
+                        .cast_implicit(DataType::Int64)?
+                        .eval_row_const()?
+                        .map(|v| *v.as_int64())
+                        .ok_or_else(|| {
+                            ErrorCode::NotImplemented(
+                                "NULL offset in lag/lead".to_string(),
+                                None.into(),
+                            )
+                        })?,
+                ),
+                None => Some(1),
+            }
+        } else {
+            None
+        };
+        let args = args
+            .into_iter()
+            .take(1)
+            .map(|e| match e.as_input_ref() {
+                Some(i) => Ok(*i.clone()),
+                None => Err(ErrorCode::NotImplemented(
+                    "non-column expression as argument of window function".to_string(),
+                    None.into(),
+                )
+                .into()),
+            })
+            .collect::<Result<Vec<_>>>()?;
 
         // TODO: rewrite ORDER BY & PARTITION BY expr to InputRef like `LogicalAgg`
         let order_by = order_by
@@ -216,6 +266,8 @@ impl LogicalOverAgg {
             PlanWindowFunction {
                 function_type,
                 return_type,
+                args,
+                offset,
                 partition_by,
                 order_by,
             },
@@ -253,13 +305,68 @@ impl fmt::Display for LogicalOverAgg {
 
 impl ColPrunable for LogicalOverAgg {
     fn prune_col(&self, required_cols: &[usize], ctx: &mut ColumnPruningContext) -> PlanRef {
-        let mapping = ColIndexMapping::with_remaining_columns(required_cols, self.schema().len());
-        let new_input = {
-            let input = self.input();
-            let required = (0..input.schema().len()).collect_vec();
-            input.prune_col(&required, ctx)
+        let input_len = self.input().schema().len();
+
+        // Columns required by the window function itself (its argument and its `PARTITION BY`
+        // / `ORDER BY` keys) must be kept regardless of whether they show up in the output.
+        let input_required_cols = {
+            let mut tmp = FixedBitSet::with_capacity(input_len);
+            tmp.extend(
+                required_cols
+                    .iter()
+                    .filter(|&&index| index < input_len)
+                    .copied(),
+            );
+            tmp.extend(self.window_function.args.iter().map(InputRef::index));
+            tmp.extend(self.window_function.partition_by.iter().map(InputRef::index));
+            tmp.extend(self.window_function.order_by.iter().map(|o| o.column_index));
+            tmp.ones().collect_vec()
         };
-        LogicalProject::with_mapping(self.clone_with_input(new_input).into(), mapping).into()
+        let input_col_change =
+            ColIndexMapping::with_remaining_columns(&input_required_cols, input_len);
+        let new_window_function = {
+            let mut window_function = self.window_function.clone();
+            for input_ref in &mut window_function.args {
+                input_ref.index = input_col_change.map(input_ref.index);
+            }
+            for input_ref in &mut window_function.partition_by {
+                input_ref.index = input_col_change.map(input_ref.index);
+            }
+            for order in &mut window_function.order_by {
+                order.column_index = input_col_change.map(order.column_index);
+            }
+            window_function
+        };
+        let new_input = self.input().prune_col(&input_required_cols, ctx);
+        let over_agg = Self::new(new_window_function, new_input);
+
+        // `input_required_cols` gives, in schema order, the original column each passthrough
+        // column of `over_agg` corresponds to; the window function's result is always appended
+        // last, at original index `input_len`.
+        let new_output_cols = {
+            let mut tmp = input_required_cols.clone();
+            tmp.push(input_len);
+            tmp
+        };
+        if new_output_cols == required_cols {
+            // current schema perfectly fit the required columns
+            over_agg.into()
+        } else {
+            // some columns are not needed, or the order need to be adjusted.
+            // so we did a projection to remove/reorder the columns.
+            let mapping =
+                &ColIndexMapping::with_remaining_columns(&new_output_cols, self.schema().len());
+            let output_required_cols = required_cols
+                .iter()
+                .map(|&idx| mapping.map(idx))
+                .collect_vec();
+            let src_size = over_agg.schema().len();
+            LogicalProject::with_mapping(
+                over_agg.into(),
+                ColIndexMapping::with_remaining_columns(&output_required_cols, src_size),
+            )
+            .into()
+        }
     }
 }
 
@@ -14,6 +14,7 @@
 
 use std::fmt;
 
+use fixedbitset::FixedBitSet;
 use itertools::Itertools;
 use risingwave_common::error::Result;
 
@@ -239,14 +240,34 @@ impl fmt::Display for LogicalProjectSet {
 
 impl ColPrunable for LogicalProjectSet {
     fn prune_col(&self, required_cols: &[usize], ctx: &mut ColumnPruningContext) -> PlanRef {
-        // TODO: column pruning for ProjectSet https://github.com/risingwavelabs/risingwave/issues/8593
-        let mapping = ColIndexMapping::with_remaining_columns(required_cols, self.schema().len());
-        let new_input = {
-            let input = self.input();
-            let required = (0..input.schema().len()).collect_vec();
-            input.prune_col(&required, ctx)
+        // All `select_list` items must still be evaluated even if their result is unused: a
+        // table function's set size affects how many rows `ProjectSet` emits, so we can't drop
+        // any of them just because they aren't required downstream. What we *can* prune is the
+        // input columns that no `select_list` item actually references.
+        let input = self.input();
+        let input_required_cols = {
+            let mut tmp = FixedBitSet::with_capacity(input.schema().len());
+            self.select_list()
+                .iter()
+                .for_each(|expr| tmp.union_with(&expr.collect_input_refs(input.schema().len())));
+            tmp.ones().collect_vec()
         };
-        LogicalProject::with_mapping(self.clone_with_input(new_input).into(), mapping).into()
+        let mut input_col_change =
+            ColIndexMapping::with_remaining_columns(&input_required_cols, input.schema().len());
+        let new_select_list = self
+            .select_list()
+            .iter()
+            .cloned()
+            .map(|expr| input_col_change.rewrite_expr(expr))
+            .collect();
+        let new_input = input.prune_col(&input_required_cols, ctx);
+
+        let mapping = ColIndexMapping::with_remaining_columns(required_cols, self.schema().len());
+        LogicalProject::with_mapping(
+            LogicalProjectSet::new(new_input, new_select_list).into(),
+            mapping,
+        )
+        .into()
     }
 }
 
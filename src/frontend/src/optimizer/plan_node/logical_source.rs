@@ -322,8 +322,60 @@ impl fmt::Display for LogicalSource {
 
 impl ColPrunable for LogicalSource {
     fn prune_col(&self, required_cols: &[usize], _ctx: &mut ColumnPruningContext) -> PlanRef {
-        let mapping = ColIndexMapping::with_remaining_columns(required_cols, self.schema().len());
-        LogicalProject::with_mapping(self.clone().into(), mapping).into()
+        if self.core.for_table {
+            // A table's associated source must keep producing every column of the table it
+            // backs, so we can't drop any of them here; just reorder/remove in a `Project` on
+            // top as usual.
+            let mapping =
+                ColIndexMapping::with_remaining_columns(required_cols, self.schema().len());
+            return LogicalProject::with_mapping(self.clone().into(), mapping).into();
+        }
+
+        // Columns the query never reads are dropped from the source's own column list (not just
+        // hidden behind a `Project`), so the connector's parser can skip decoding them.
+        let kept_cols = {
+            let mut tmp = required_cols.to_vec();
+            if self.core.gen_row_id {
+                // The generated row id doubles as the source's primary key, so it must survive
+                // pruning even if the query itself never selects it.
+                tmp.push(self.core.row_id_index.unwrap());
+            }
+            tmp.sort_unstable();
+            tmp.dedup();
+            tmp
+        };
+
+        let col_change = ColIndexMapping::with_remaining_columns(&kept_cols, self.schema().len());
+        let core = generic::Source {
+            catalog: self.core.catalog.clone(),
+            column_catalog: kept_cols
+                .iter()
+                .map(|&i| self.core.column_catalog[i].clone())
+                .collect(),
+            row_id_index: self.core.row_id_index.map(|idx| col_change.map(idx)),
+            gen_row_id: self.core.gen_row_id,
+            for_table: self.core.for_table,
+            ctx: self.core.ctx.clone(),
+        };
+        let source = Self {
+            base: PlanBase::new_logical_with_core(&core),
+            core,
+            kafka_timestamp_range: self.kafka_timestamp_range,
+        };
+
+        if kept_cols.as_slice() == required_cols {
+            source.into()
+        } else {
+            let output_required_cols = required_cols
+                .iter()
+                .map(|&i| col_change.map(i))
+                .collect_vec();
+            let mapping = ColIndexMapping::with_remaining_columns(
+                &output_required_cols,
+                source.schema().len(),
+            );
+            LogicalProject::with_mapping(source.into(), mapping).into()
+        }
     }
 }
 
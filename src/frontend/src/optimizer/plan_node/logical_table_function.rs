@@ -15,18 +15,18 @@
 use std::fmt;
 
 use risingwave_common::catalog::{Field, Schema};
-use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::error::Result;
 use risingwave_common::types::DataType;
 
 use super::{
     ColPrunable, ExprRewritable, LogicalFilter, PlanBase, PlanRef, PredicatePushdown, ToBatch,
     ToStream,
 };
-use crate::expr::{Expr, ExprRewriter, TableFunction};
+use crate::expr::{Expr, ExprImpl, ExprRewriter, ExprType, FunctionCall, InputRef, TableFunction};
 use crate::optimizer::optimizer_context::OptimizerContextRef;
 use crate::optimizer::plan_node::{
-    BatchTableFunction, ColumnPruningContext, PredicatePushdownContext, RewriteStreamContext,
-    ToStreamContext,
+    BatchTableFunction, ColumnPruningContext, LogicalProject, LogicalProjectSet, LogicalValues,
+    PredicatePushdownContext, RewriteStreamContext, ToStreamContext,
 };
 use crate::optimizer::property::FunctionalDependencySet;
 use crate::utils::{ColIndexMapping, Condition};
@@ -58,6 +58,41 @@ impl LogicalTableFunction {
             table_function,
         }
     }
+
+    /// There is no dedicated streaming executor for table functions, unlike `BatchTableFunction`
+    /// on the batch side. Instead, rewrite `FROM tf(...)` into the equivalent of
+    /// `SELECT tf(...) FROM (VALUES (NULL))`, i.e. a [`LogicalProjectSet`] over a dummy
+    /// single-row input, which `StreamProjectSet` already knows how to execute.
+    ///
+    /// `ProjectSet` exposes a struct-typed table function's return value as a single column, so
+    /// when `table_function` returns a struct we additionally flatten it back into the individual
+    /// fields that [`LogicalTableFunction::new`] exposes, to keep the schema shape this node
+    /// presents to the rest of the plan unchanged (other than the leading hidden row id, which
+    /// `logical_rewrite_for_stream` reports via the returned [`ColIndexMapping`]).
+    fn rewrite_as_project_set(&self) -> PlanRef {
+        let dummy = LogicalValues::create(vec![vec![]], Schema::default(), self.base.ctx());
+        let project_set = LogicalProjectSet::create(
+            dummy,
+            vec![ExprImpl::TableFunction(Box::new(self.table_function.clone()))],
+        );
+        if let DataType::Struct(ty) = self.table_function.return_type() {
+            let row_id = InputRef::new(0, DataType::Int64).into();
+            let fields = ty.fields.iter().enumerate().map(|(i, field_type)| {
+                FunctionCall::new_unchecked(
+                    ExprType::Field,
+                    vec![
+                        InputRef::new(1, self.table_function.return_type()).into(),
+                        ExprImpl::literal_int(i as i32),
+                    ],
+                    field_type.clone(),
+                )
+                .into()
+            });
+            LogicalProject::create(project_set, std::iter::once(row_id).chain(fields).collect())
+        } else {
+            project_set
+        }
+    }
 }
 
 impl_plan_tree_node_for_leaf! { LogicalTableFunction }
@@ -111,21 +146,18 @@ impl ToBatch for LogicalTableFunction {
 }
 
 impl ToStream for LogicalTableFunction {
-    fn to_stream(&self, _ctx: &mut ToStreamContext) -> Result<PlanRef> {
-        Err(
-            ErrorCode::NotImplemented("LogicalTableFunction::to_stream".to_string(), None.into())
-                .into(),
-        )
+    fn to_stream(&self, ctx: &mut ToStreamContext) -> Result<PlanRef> {
+        self.rewrite_as_project_set().to_stream(ctx)
     }
 
     fn logical_rewrite_for_stream(
         &self,
         _ctx: &mut RewriteStreamContext,
     ) -> Result<(PlanRef, ColIndexMapping)> {
-        Err(ErrorCode::NotImplemented(
-            "LogicalTableFunction::logical_rewrite_for_stream".to_string(),
-            None.into(),
-        )
-        .into())
+        let plan = self.rewrite_as_project_set();
+        // `rewrite_as_project_set` always prepends exactly one hidden row id column ahead of the
+        // columns that this node's own (unrewritten) schema exposes.
+        let col_change = ColIndexMapping::with_shift_offset(self.schema().len(), 1);
+        Ok((plan, col_change))
     }
 }
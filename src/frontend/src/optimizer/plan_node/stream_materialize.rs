@@ -212,6 +212,8 @@ impl StreamMaterialize {
             version,
             watermark_columns,
             dist_key_in_pk: vec![],
+            description: None,
+            column_comments: Default::default(),
         })
     }
 
@@ -151,6 +151,8 @@ impl TableCatalogBuilder {
             version: None, // the internal table is not versioned and can't be schema changed
             watermark_columns,
             dist_key_in_pk: self.dist_key_in_pk.unwrap_or(vec![]),
+            description: None,
+            column_comments: Default::default(),
         }
     }
 
@@ -38,15 +38,23 @@ impl Rule for DistinctAggRule {
         let agg: &LogicalAgg = plan.as_logical_agg()?;
         let (mut agg_calls, mut agg_group_keys, input) = agg.clone().decompose();
 
-        if self.for_stream && !agg_group_keys.is_empty() {
-            // Due to performance issue, we don't do 2-phase agg for stream distinct agg with group
-            // by. See https://github.com/risingwavelabs/risingwave/issues/7271 for more.
-            return None;
-        }
-
         let original_group_keys_len = agg_group_keys.len();
         let (node, flag_values, has_expand) =
             Self::build_expand(input, &mut agg_group_keys, &mut agg_calls)?;
+
+        if self.for_stream && has_expand && !agg_group_keys.is_empty() {
+            // Due to performance issue, we don't do 2-phase agg with an actual `Expand` for
+            // stream distinct agg with group by, since `Expand` multiplies the number of rows
+            // flowing into the (stateful) group-by aggregation. See
+            // https://github.com/risingwavelabs/risingwave/issues/7271 for more.
+            //
+            // `has_expand` is false when there's only one distinct column (after deduplicating
+            // `column_subsets`), so `build_expand` above degenerates to a no-op `LogicalProject`
+            // instead of inserting an actual `Expand`. In that case the 2-phase rewrite is just as
+            // cheap as the single-phase one, so the restriction above doesn't need to apply.
+            return None;
+        }
+
         let mid_agg = Self::build_middle_agg(node, agg_group_keys, agg_calls.clone(), has_expand);
         Some(Self::build_final_agg(
             mid_agg,
@@ -235,10 +243,25 @@ impl DistinctAggRule {
             let flag_value = if agg_call.distinct {
                 agg_call.distinct = false;
 
+                // remember the old -> new index mapping before overwriting `inputs`, so that
+                // `order_by` (allowed to coexist with `distinct` only when its sort keys are
+                // also direct arguments of the aggregate, see `Binder::bind_agg`) can be
+                // remapped the same way.
+                let old_to_new_index: HashMap<usize, usize> = agg_call
+                    .inputs
+                    .iter()
+                    .map(|input_ref| input_ref.index)
+                    .enumerate()
+                    .map(|(i, old_index)| (old_index, index_of_distinct_agg_argument + i))
+                    .collect();
+
                 agg_call.inputs.iter_mut().for_each(|input_ref| {
                     input_ref.index = index_of_distinct_agg_argument;
                     index_of_distinct_agg_argument += 1;
                 });
+                agg_call.order_by.iter_mut().for_each(|o| {
+                    o.column_index = old_to_new_index[&o.column_index];
+                });
 
                 // distinct-agg with real filter has its corresponding middle agg, which is count(*)
                 // with its original filter.
@@ -282,16 +305,30 @@ impl DistinctAggRule {
                     | AggKind::StringAgg
                     | AggKind::ArrayAgg
                     | AggKind::FirstValue
+                    | AggKind::LastValue
                     | AggKind::StddevPop
                     | AggKind::StddevSamp
                     | AggKind::VarPop
-                    | AggKind::VarSamp => (),
+                    | AggKind::VarSamp
+                    | AggKind::Mode
+                    | AggKind::PercentileCont
+                    | AggKind::PercentileDisc
+                    | AggKind::BoolAnd
+                    | AggKind::BoolOr
+                    | AggKind::BitAnd
+                    | AggKind::BitOr
+                    | AggKind::BitXor => (),
                     AggKind::Count => {
                         agg_call.agg_kind = AggKind::Sum0;
                     }
-                    // TODO: fix it as a real 2-phase plan of ApproxCountDistinct
                     AggKind::ApproxCountDistinct => {
-                        agg_call.agg_kind = AggKind::Sum0;
+                        // Unlike `Count`, per-group approximate counts can't be merged by
+                        // summing: `HyperLogLog` needs its raw registers merged, not its
+                        // already-estimated counts. `LogicalAgg::syntax_check` rejects this
+                        // combination before it can reach here.
+                        unreachable!(
+                            "non-distinct approx_count_distinct can't appear with distinct aggregates"
+                        )
                     }
                 }
 
@@ -26,7 +26,7 @@ impl Rule for IndexDeltaJoinRule {
     fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
         let join = plan.as_stream_hash_join()?;
         if join.eq_join_predicate().has_non_eq() || join.join_type() != JoinType::Inner {
-            return Some(plan);
+            return None;
         }
 
         /// FIXME: Exchanges still may exist after table scan, because table scan's distribution
@@ -42,6 +42,9 @@ impl Rule for IndexDeltaJoinRule {
             }
         }
 
+        // Both inputs must (transitively) be table/index scans: a join that consumes the output
+        // of another delta join (e.g. the outer join of an N-way chain) doesn't match here, so
+        // only the innermost binary join of a chain is converted.
         let input_left_dyn = match_through_exchange(join.inputs()[0].clone())?;
         let input_left = input_left_dyn.as_stream_table_scan()?;
         let input_right_dyn = match_through_exchange(join.inputs()[1].clone())?;
@@ -142,10 +145,10 @@ impl Rule for IndexDeltaJoinRule {
                         .into(),
                 )
             } else {
-                Some(plan)
+                None
             }
         } else {
-            Some(plan)
+            None
         }
     }
 }
@@ -83,6 +83,10 @@ const INDEX_COST_MATRIX: [[usize; INDEX_MAX_LEN]; 5] = [
 const LOOKUP_COST_CONST: usize = 3;
 const MAX_COMBINATION_SIZE: usize = 3;
 const MAX_CONJUNCTION_SIZE: usize = 8;
+/// The row count the cost matrix above implicitly assumes for a full table scan
+/// (`INDEX_COST_MATRIX[4][0]`), used to rescale that guess once `ANALYZE` has recorded an actual
+/// row count for the table.
+const DEFAULT_TABLE_ROW_COUNT: u64 = INDEX_COST_MATRIX[4][0] as u64;
 
 pub struct IndexSelectionRule {}
 
@@ -643,7 +647,24 @@ impl IndexSelectionRule {
 
     fn estimate_full_table_scan_cost(&self, scan: &LogicalScan, row_size: usize) -> IndexCost {
         let mut table_scan_io_estimator = TableScanIoEstimator::new(scan, row_size);
-        table_scan_io_estimator.estimate(&Condition::true_cond())
+        let cost = table_scan_io_estimator.estimate(&Condition::true_cond());
+        match Self::table_row_count(scan) {
+            Some(row_count) => cost.scale_to_row_count(row_count),
+            None => cost,
+        }
+    }
+
+    /// The row count `ANALYZE` recorded for `scan`'s table, if it's ever been run.
+    fn table_row_count(scan: &LogicalScan) -> Option<u64> {
+        let table_id = scan.table_desc().table_id;
+        let stats = scan
+            .ctx()
+            .session_ctx()
+            .env()
+            .catalog_reader()
+            .read_guard()
+            .get_table_stats(table_id)?;
+        Some(stats.row_count)
     }
 
     fn create_null_safe_equal_expr(
@@ -865,6 +886,15 @@ impl IndexCost {
     fn le(&self, other: &IndexCost) -> bool {
         self.0 < other.0
     }
+
+    /// Rescales a full-table-scan cost computed under the assumption of
+    /// [`DEFAULT_TABLE_ROW_COUNT`] rows to one for a table actually known to have `row_count`
+    /// rows.
+    fn scale_to_row_count(&self, row_count: u64) -> IndexCost {
+        let scaled =
+            self.0 as u128 * row_count.max(1) as u128 / DEFAULT_TABLE_ROW_COUNT as u128;
+        IndexCost::new(scaled.min(IndexCost::maximum() as u128) as usize)
+    }
 }
 
 impl ExprVisitor<IndexCost> for TableScanIoEstimator<'_> {
@@ -16,14 +16,15 @@ use super::super::plan_node::*;
 use super::Rule;
 use crate::optimizer::rule::BoxedRule;
 
-/// Reorders a multi join into a left deep join via the heuristic ordering
+/// Reorders a multi join into a left deep join via a cost-based ordering, using a DP search
+/// over small numbers of relations and a structural heuristic beyond that.
 pub struct LeftDeepTreeJoinOrderingRule {}
 
 impl Rule for LeftDeepTreeJoinOrderingRule {
     fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
         let join = plan.as_logical_multi_join()?;
         // check if join is inner and can be merged into multijoin
-        let join_ordering = join.heuristic_ordering().ok()?; // maybe panic here instead?
+        let join_ordering = join.cost_based_ordering().ok()?; // maybe panic here instead?
         let left_deep_join = join.as_reordered_left_deep_join(&join_ordering);
         Some(left_deep_join)
     }
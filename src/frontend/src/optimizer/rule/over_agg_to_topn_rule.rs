@@ -64,6 +64,8 @@ impl Rule for OverAggToTopNRule {
         let PlanWindowFunction {
             function_type,
             return_type: _,
+            args: _,
+            offset: _,
             partition_by,
             order_by,
         } = &over_agg.window_function;
@@ -71,6 +73,11 @@ impl Rule for OverAggToTopNRule {
             WindowFunctionType::RowNumber => false,
             WindowFunctionType::Rank => true,
             WindowFunctionType::DenseRank => unreachable!("Not implemented. Banned in planner."),
+            WindowFunctionType::Lag | WindowFunctionType::Lead => {
+                // This rule only turns a rank-based window function combined with a bounding
+                // filter into a group `TopN`; `lag`/`lead` don't produce a rank to filter on.
+                return None;
+            }
         };
 
         let (rank_pred, other_pred) = {
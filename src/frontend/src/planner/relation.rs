@@ -17,6 +17,7 @@ use std::rc::Rc;
 use itertools::Itertools;
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::{DataType, Interval, ScalarImpl};
+use risingwave_pb::plan_common::JoinType;
 
 use crate::binder::{
     BoundBaseTable, BoundJoin, BoundShare, BoundSource, BoundSystemTable, BoundWatermark,
@@ -24,10 +25,11 @@ use crate::binder::{
 };
 use crate::expr::{ExprImpl, ExprType, FunctionCall, InputRef, TableFunction};
 use crate::optimizer::plan_node::{
-    LogicalHopWindow, LogicalJoin, LogicalProject, LogicalScan, LogicalShare, LogicalSource,
-    LogicalTableFunction, PlanRef,
+    LogicalApply, LogicalHopWindow, LogicalJoin, LogicalProject, LogicalScan, LogicalShare,
+    LogicalSource, LogicalTableFunction, PlanRef,
 };
 use crate::planner::Planner;
+use crate::utils::Condition;
 
 const ERROR_WINDOW_SIZE_ARG: &str =
     "The size arg of window table function should be an interval literal.";
@@ -81,19 +83,49 @@ impl Planner {
     }
 
     pub(super) fn plan_join(&mut self, join: BoundJoin) -> Result<PlanRef> {
-        let left = self.plan_relation(join.left)?;
-        let right = self.plan_relation(join.right)?;
         let join_type = join.join_type;
         let on_clause = join.cond;
         if on_clause.has_subquery() {
-            Err(ErrorCode::NotImplemented(
+            return Err(ErrorCode::NotImplemented(
                 "Subquery in join on condition is unsupported".into(),
                 None.into(),
             )
-            .into())
-        } else {
-            Ok(LogicalJoin::create(left, right, join_type, on_clause))
+            .into());
         }
+
+        let left = self.plan_relation(join.left)?;
+        let mut right = join.right;
+        // A `CorrelatedInputRef` created for a `LATERAL` subquery's own immediate scope is
+        // recorded at depth 1 (it crosses exactly one `bind_query` level), unlike the depth-0
+        // convention used by `Subquery::is_correlated`, which already accounts for that level.
+        if right.is_correlated(1) {
+            // The right side is a `LATERAL` subquery referring to columns of `left`: plan it as
+            // a `LogicalApply` and let the existing decorrelation rules push it down into an
+            // ordinary join.
+            if !matches!(join_type, JoinType::Inner | JoinType::LeftOuter) {
+                return Err(ErrorCode::NotImplemented(
+                    "LATERAL is only supported for CROSS JOIN and LEFT JOIN".into(),
+                    None.into(),
+                )
+                .into());
+            }
+            let correlated_id = self.ctx.next_correlated_id();
+            let correlated_indices =
+                right.collect_correlated_indices_by_depth_and_assign_id(1, correlated_id);
+            let right = self.plan_relation(right)?;
+            return Ok(LogicalApply::create(
+                left,
+                right,
+                join_type,
+                Condition::with_expr(on_clause),
+                correlated_id,
+                correlated_indices,
+                false,
+            ));
+        }
+
+        let right = self.plan_relation(right)?;
+        Ok(LogicalJoin::create(left, right, join_type, on_clause))
     }
 
     pub(super) fn plan_window_table_function(
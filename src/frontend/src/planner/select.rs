@@ -263,6 +263,12 @@ impl Planner {
     ///
     /// We will use a = b to replace a in (select b from ....) for (NOT) IN thus avoiding adding a
     /// `LogicalFilter` on `LogicalApply`.
+    ///
+    /// `NOT IN` needs an extra `a IS NOT NULL` filter on top of the anti join: per SQL's NULL
+    /// semantics, `a NOT IN (subquery)` is NULL (filtered out of a `WHERE` clause, same as
+    /// `FALSE`) whenever `a` is `NULL`, or whenever `a` doesn't match any row but the subquery
+    /// produced a `NULL`. We fold the latter case into the anti join's `ON` condition by also
+    /// treating a `NULL` subquery row as a match, then filter out `a IS NULL` separately.
     fn handle_exists_and_in(
         &mut self,
         expr: ExprImpl,
@@ -280,11 +286,24 @@ impl Planner {
             subquery.collect_correlated_indices_by_depth_and_assign_id(0, correlated_id);
         let output_column_type = subquery.query.data_types()[0].clone();
         let right_plan = self.plan_query(subquery.query)?.into_subplan();
+        let mut not_null_check = None;
         let on = match subquery.kind {
             SubqueryKind::Existential => ExprImpl::literal_bool(true),
             SubqueryKind::In(left_expr) => {
-                let right_expr = InputRef::new(input.schema().len(), output_column_type);
-                FunctionCall::new(ExprType::Equal, vec![left_expr, right_expr.into()])?.into()
+                let right_expr: ExprImpl =
+                    InputRef::new(input.schema().len(), output_column_type).into();
+                let eq = FunctionCall::new(
+                    ExprType::Equal,
+                    vec![left_expr.clone(), right_expr.clone()],
+                )?;
+                if negated {
+                    not_null_check =
+                        Some(FunctionCall::new(ExprType::IsNotNull, vec![left_expr])?.into());
+                    let right_is_null = FunctionCall::new(ExprType::IsNull, vec![right_expr])?;
+                    FunctionCall::new(ExprType::Or, vec![eq.into(), right_is_null.into()])?.into()
+                } else {
+                    eq.into()
+                }
             }
             kind => {
                 return Err(ErrorCode::NotImplemented(
@@ -303,6 +322,9 @@ impl Planner {
             join_type,
             false,
         );
+        if let Some(not_null_check) = not_null_check {
+            *input = LogicalFilter::create_with_expr(input.clone(), not_null_check);
+        }
         Ok(())
     }
 
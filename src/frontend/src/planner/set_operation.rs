@@ -12,10 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::DataType;
+use risingwave_expr::expr::AggKind;
 
 use crate::binder::{BoundSetExpr, BoundSetOperation};
-use crate::optimizer::plan_node::LogicalUnion;
+use crate::expr::{ExprImpl, ExprType, FunctionCall, InputRef};
+use crate::optimizer::plan_node::{
+    LogicalAgg, LogicalFilter, LogicalProject, LogicalUnion, PlanAggCall,
+};
 use crate::planner::Planner;
+use crate::utils::Condition;
 use crate::PlanRef;
 
 impl Planner {
@@ -33,8 +39,91 @@ impl Planner {
                 Ok(LogicalUnion::create(all, vec![left, right]))
             }
             BoundSetOperation::Except | BoundSetOperation::Intersect => {
-                Err(ErrorCode::NotImplemented(format!("set expr: {:?}", op), None.into()).into())
+                if all {
+                    return Err(ErrorCode::NotImplemented(
+                        format!("{:?} ALL", op),
+                        None.into(),
+                    )
+                    .into());
+                }
+                let left = self.plan_set_expr(left, vec![], &[])?;
+                let right = self.plan_set_expr(right, vec![], &[])?;
+                self.plan_except_or_intersect(op, left, right)
             }
         }
     }
+
+    /// Lowers `EXCEPT`/`INTERSECT` (without `ALL`) to an aggregation-based plan so that it can be
+    /// used in both batch and streaming contexts, analogous to how `SELECT DISTINCT` is lowered
+    /// to a group-by with no aggregate calls.
+    ///
+    /// Each side is tagged with its origin (`0` for `left`, `1` for `right`) and unioned
+    /// together. Grouping the union by the original columns and taking `min`/`max` of the tag
+    /// then tells us, for each distinct row, whether it came from the left side only, the right
+    /// side only, or both:
+    /// - `EXCEPT`: keep rows whose tag is always `0`, i.e. `max(tag) = 0`.
+    /// - `INTERSECT`: keep rows that have both tags, i.e. `min(tag) = 0 and max(tag) = 1`.
+    fn plan_except_or_intersect(
+        &mut self,
+        op: BoundSetOperation,
+        left: PlanRef,
+        right: PlanRef,
+    ) -> Result<PlanRef> {
+        let n = left.schema().len();
+        let tag_type = DataType::Int32;
+
+        let tag_project = |input: PlanRef, tag: i32| {
+            let mut exprs = input
+                .schema()
+                .fields()
+                .iter()
+                .enumerate()
+                .map(|(i, field)| InputRef::new(i, field.data_type.clone()).into())
+                .collect::<Vec<ExprImpl>>();
+            exprs.push(ExprImpl::literal_int(tag));
+            LogicalProject::create(input, exprs)
+        };
+        let tagged_left = tag_project(left, 0);
+        let tagged_right = tag_project(right, 1);
+        let union_all = LogicalUnion::create(true, vec![tagged_left, tagged_right]);
+
+        let tag_agg_call = |agg_kind| PlanAggCall {
+            agg_kind,
+            return_type: tag_type.clone(),
+            inputs: vec![InputRef::new(n, tag_type.clone())],
+            distinct: false,
+            order_by: vec![],
+            filter: Condition::true_cond(),
+        };
+        let agg = LogicalAgg::new(
+            vec![tag_agg_call(AggKind::Min), tag_agg_call(AggKind::Max)],
+            (0..n).collect(),
+            union_all,
+        );
+        let min_tag = InputRef::new(n, tag_type.clone());
+        let max_tag = InputRef::new(n + 1, tag_type.clone());
+
+        let predicate = match op {
+            BoundSetOperation::Except => {
+                FunctionCall::new(ExprType::Equal, vec![max_tag.into(), ExprImpl::literal_int(0)])?
+                    .into()
+            }
+            BoundSetOperation::Intersect => {
+                let has_left: ExprImpl =
+                    FunctionCall::new(ExprType::Equal, vec![min_tag.into(), ExprImpl::literal_int(0)])?
+                        .into();
+                let has_right: ExprImpl =
+                    FunctionCall::new(ExprType::Equal, vec![max_tag.into(), ExprImpl::literal_int(1)])?
+                        .into();
+                FunctionCall::new(ExprType::And, vec![has_left, has_right])?.into()
+            }
+            BoundSetOperation::Union => unreachable!(),
+        };
+        let filter = LogicalFilter::create_with_expr(agg.into(), predicate);
+
+        let output_exprs = (0..n)
+            .map(|i| InputRef::new(i, filter.schema().fields()[i].data_type.clone()).into())
+            .collect();
+        Ok(LogicalProject::create(filter, output_exprs))
+    }
 }
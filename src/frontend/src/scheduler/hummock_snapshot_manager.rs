@@ -98,6 +98,12 @@ pub struct HummockSnapshotGuard {
 }
 
 impl HummockSnapshotGuard {
+    /// The committed epoch pinned by this guard, e.g. to be reused across statements in a
+    /// read-only transaction.
+    pub fn committed_epoch(&self) -> u64 {
+        self.snapshot.committed_epoch
+    }
+
     pub fn get_batch_query_epoch(&self, checkpoint: bool) -> BatchQueryEpoch {
         let epoch = if checkpoint {
             batch_query_epoch::Epoch::Committed(self.snapshot.committed_epoch)
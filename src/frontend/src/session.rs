@@ -14,7 +14,7 @@
 
 use std::collections::HashMap;
 use std::io::{Error, ErrorKind};
-use std::sync::atomic::{AtomicI32, Ordering};
+use std::sync::atomic::{AtomicI32, AtomicU32, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
@@ -23,10 +23,10 @@ use parking_lot::{RwLock, RwLockReadGuard};
 use pgwire::pg_field_descriptor::PgFieldDescriptor;
 use pgwire::pg_response::PgResponse;
 use pgwire::pg_server::{BoxedError, Session, SessionId, SessionManager, UserAuthenticator};
-use pgwire::types::Format;
+use pgwire::types::{Format, Row};
 use rand::RngCore;
 use risingwave_common::array::DataChunk;
-use risingwave_common::catalog::DEFAULT_SCHEMA_NAME;
+use risingwave_common::catalog::{Field, DEFAULT_SCHEMA_NAME};
 #[cfg(test)]
 use risingwave_common::catalog::{
     DEFAULT_DATABASE_NAME, DEFAULT_SUPER_USER, DEFAULT_SUPER_USER_ID,
@@ -60,7 +60,9 @@ use tracing::info;
 use crate::binder::{Binder, BoundStatement};
 use crate::catalog::catalog_service::{CatalogReader, CatalogWriter, CatalogWriterImpl};
 use crate::catalog::root_catalog::Catalog;
-use crate::catalog::{check_schema_writable, DatabaseId, SchemaId};
+use crate::catalog::view_catalog::ViewCatalog;
+use crate::catalog::{check_schema_writable, CatalogError, DatabaseId, SchemaId, ViewId};
+use crate::handler::cursor::Cursor;
 use crate::handler::extended_handle::{
     handle_bind, handle_execute, handle_parse, Portal, PrepareStatement,
 };
@@ -74,8 +76,10 @@ use crate::observer::FrontendObserverNode;
 use crate::scheduler::streaming_manager::{StreamingJobTracker, StreamingJobTrackerRef};
 use crate::scheduler::worker_node_manager::{WorkerNodeManager, WorkerNodeManagerRef};
 use crate::scheduler::SchedulerError::QueryCancelError;
+use crate::scheduler::plan_fragmenter::QueryId;
 use crate::scheduler::{
-    DistributedQueryMetrics, HummockSnapshotManager, HummockSnapshotManagerRef, QueryManager,
+    DistributedQueryMetrics, HummockSnapshotGuard, HummockSnapshotManager,
+    HummockSnapshotManagerRef, QueryManager,
 };
 use crate::telemetry::FrontendTelemetryCreator;
 use crate::user::user_authentication::md5_hash_with_salt;
@@ -425,6 +429,20 @@ pub struct SessionImpl {
     /// This flag is set only when current query is executed in local mode, and used to cancel
     /// local query.
     current_query_cancel_flag: Mutex<Option<Trigger>>,
+
+    /// Named cursors opened by `DECLARE ... CURSOR FOR ...` and consumed by `FETCH`/`CLOSE`.
+    cursors: tokio::sync::Mutex<HashMap<String, Cursor>>,
+
+    /// Views created with `CREATE TEMPORARY VIEW`. Visible only to this session and never
+    /// persisted to the meta catalog; they disappear once the session ends.
+    temporary_views: Mutex<HashMap<String, Arc<ViewCatalog>>>,
+    /// Counter assigning `ViewId`s to [`Self::temporary_views`], counting down from `u32::MAX`
+    /// so they never collide with the meta-assigned ids of persisted views.
+    next_temporary_view_id: AtomicU32,
+
+    /// The snapshot pinned by an in-progress `BEGIN READ ONLY` transaction, kept alive so the
+    /// pinned epoch stays visible until `COMMIT`/`ROLLBACK`/`ABORT`.
+    txn_snapshot: Mutex<Option<HummockSnapshotGuard>>,
 }
 
 impl SessionImpl {
@@ -441,6 +459,10 @@ impl SessionImpl {
             config_map: Default::default(),
             id,
             current_query_cancel_flag: Mutex::new(None),
+            cursors: Default::default(),
+            temporary_views: Default::default(),
+            next_temporary_view_id: AtomicU32::new(u32::MAX),
+            txn_snapshot: Mutex::new(None),
         }
     }
 
@@ -458,6 +480,10 @@ impl SessionImpl {
             // Mock session use non-sense id.
             id: (0, 0),
             current_query_cancel_flag: Mutex::new(None),
+            cursors: Default::default(),
+            temporary_views: Default::default(),
+            next_temporary_view_id: AtomicU32::new(u32::MAX),
+            txn_snapshot: Mutex::new(None),
         }
     }
 
@@ -493,6 +519,108 @@ impl SessionImpl {
         self.id
     }
 
+    pub async fn add_cursor(&self, cursor_name: String, cursor: Cursor) -> Result<()> {
+        let mut cursors = self.cursors.lock().await;
+        if cursors.contains_key(&cursor_name) {
+            return Err(CatalogError::Duplicated("cursor", cursor_name).into());
+        }
+        cursors.insert(cursor_name, cursor);
+        Ok(())
+    }
+
+    pub async fn drop_cursor(&self, cursor_name: Option<String>) -> Result<()> {
+        let mut cursors = self.cursors.lock().await;
+        match cursor_name {
+            Some(cursor_name) => {
+                cursors
+                    .remove(&cursor_name)
+                    .ok_or_else(|| CatalogError::NotFound("cursor", cursor_name))?;
+            }
+            None => cursors.clear(),
+        }
+        Ok(())
+    }
+
+    pub async fn fetch_cursor(
+        &self,
+        cursor_name: &str,
+        count: usize,
+    ) -> Result<(Vec<Row>, Vec<PgFieldDescriptor>)> {
+        let mut cursors = self.cursors.lock().await;
+        let cursor = cursors
+            .get_mut(cursor_name)
+            .ok_or_else(|| CatalogError::NotFound("cursor", cursor_name.to_string()))?;
+        cursor.next_batch(count).await
+    }
+
+    /// Registers a `CREATE TEMPORARY VIEW`, assigning it a [`ViewId`] unique within this session.
+    pub fn create_temporary_view(
+        &self,
+        name: String,
+        sql: String,
+        columns: Vec<Field>,
+    ) -> Result<()> {
+        let mut temporary_views = self.temporary_views.lock().unwrap();
+        if temporary_views.contains_key(&name) {
+            return Err(CatalogError::Duplicated("view", name).into());
+        }
+        let id: ViewId = self.next_temporary_view_id.fetch_sub(1, Ordering::Relaxed);
+        temporary_views.insert(
+            name.clone(),
+            Arc::new(ViewCatalog {
+                id,
+                name,
+                owner: self.user_id(),
+                properties: Default::default(),
+                sql,
+                columns,
+            }),
+        );
+        Ok(())
+    }
+
+    pub fn get_temporary_view(&self, name: &str) -> Option<Arc<ViewCatalog>> {
+        self.temporary_views.lock().unwrap().get(name).cloned()
+    }
+
+    /// Snapshot of all temporary views in this session, for the binder to consult.
+    pub fn temporary_views(&self) -> HashMap<String, Arc<ViewCatalog>> {
+        self.temporary_views.lock().unwrap().clone()
+    }
+
+    pub fn drop_temporary_view(&self, name: &str) -> Result<()> {
+        self.temporary_views
+            .lock()
+            .unwrap()
+            .remove(name)
+            .ok_or_else(|| CatalogError::NotFound("view", name.to_string()))?;
+        Ok(())
+    }
+
+    /// Pins the current Hummock snapshot for a `BEGIN READ ONLY` transaction, so every
+    /// statement until `COMMIT`/`ROLLBACK`/`ABORT` observes the same epoch. Implemented by
+    /// reusing the `QUERY_EPOCH` session variable that already lets a single query pin an
+    /// explicit epoch.
+    pub async fn begin_read_only_txn(&self) -> Result<()> {
+        let snapshot = self
+            .env()
+            .hummock_snapshot_manager()
+            .acquire(&QueryId::default())
+            .await?;
+        self.set_config("query_epoch", vec![snapshot.committed_epoch().to_string()])?;
+        *self.txn_snapshot.lock().unwrap() = Some(snapshot);
+        Ok(())
+    }
+
+    /// Ends the current transaction, releasing any snapshot pinned by `begin_read_only_txn` and
+    /// reverting to always reading the latest data.
+    pub fn end_txn(&self) {
+        self.txn_snapshot.lock().unwrap().take();
+        // `query_epoch` defaults to `0`, which means "read the latest data".
+        self.set_config("query_epoch", vec!["0".to_string()])
+            .expect("resetting query_epoch to 0 should never fail");
+    }
+
     pub fn check_relation_name_duplicated(&self, name: ObjectName) -> Result<()> {
         let db_name = self.database();
         let catalog_reader = self.env().catalog_reader().read_guard();
@@ -682,9 +810,16 @@ impl SessionManager<PgResponseStream, PrepareStatement, Portal> for SessionManag
                             salt,
                         }
                     } else {
+                        // SHA-256-encrypted passwords are stored in the catalog, but the pgwire
+                        // layer doesn't yet speak the SASL/SCRAM-SHA-256 handshake needed to
+                        // verify them, so such users can't log in until that's implemented.
                         return Err(Box::new(Error::new(
                             ErrorKind::Unsupported,
-                            format!("Unsupported auth type: {}", auth_info.encryption_type),
+                            format!(
+                                "Unsupported auth type: {}. SCRAM-SHA-256 authentication is not \
+                                 yet implemented; use an MD5 or plaintext password instead.",
+                                auth_info.encryption_type
+                            ),
                         )));
                     }
                 }
@@ -32,7 +32,7 @@ use risingwave_common::util::column_index_mapping::ColIndexMapping;
 use risingwave_pb::backup_service::MetaSnapshotMetadata;
 use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
 use risingwave_pb::catalog::{
-    PbDatabase, PbFunction, PbIndex, PbSchema, PbSink, PbSource, PbTable, PbView,
+    PbComment, PbDatabase, PbFunction, PbIndex, PbSchema, PbSink, PbSource, PbTable, PbView,
 };
 use risingwave_pb::ddl_service::{create_connection_request, DdlProgress};
 use risingwave_pb::hummock::HummockSnapshot;
@@ -432,6 +432,10 @@ impl CatalogWriter for MockCatalogWriter {
     async fn alter_source_name(&self, _source_id: u32, _source_name: &str) -> Result<()> {
         unreachable!()
     }
+
+    async fn comment_on(&self, _comment: PbComment) -> Result<()> {
+        unreachable!()
+    }
 }
 
 impl MockCatalogWriter {
@@ -713,6 +717,10 @@ impl FrontendMetaClient for MockFrontendMetaClient {
         Ok(())
     }
 
+    async fn cancel_creating_jobs_by_ids(&self, _job_ids: Vec<u32>) -> RpcResult<()> {
+        Ok(())
+    }
+
     async fn list_table_fragments(
         &self,
         _table_ids: &[u32],
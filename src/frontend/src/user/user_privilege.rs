@@ -31,7 +31,13 @@ static AVAILABLE_ACTION_ON_SOURCE: &[Action] = &[
 ];
 static AVAILABLE_ACTION_ON_MVIEW: &[Action] = &[Action::Select { columns: None }];
 static AVAILABLE_ACTION_ON_VIEW: &[Action] = AVAILABLE_ACTION_ON_MVIEW;
-static AVAILABLE_ACTION_ON_SINK: &[Action] = &[];
+static AVAILABLE_ACTION_ON_TABLE: &[Action] = &[
+    Action::Select { columns: None },
+    Action::Insert { columns: None },
+    Action::Update { columns: None },
+    Action::Delete,
+];
+static AVAILABLE_ACTION_ON_SINK: &[Action] = &[Action::Insert { columns: None }];
 static AVAILABLE_ACTION_ON_FUNCTION: &[Action] = &[];
 
 pub fn check_privilege_type(privilege: &Privileges, objects: &GrantObjects) -> Result<()> {
@@ -54,10 +60,10 @@ pub fn check_privilege_type(privilege: &Privileges, objects: &GrantObjects) -> R
                 GrantObjects::Sinks(_) => actions
                     .iter()
                     .all(|action| AVAILABLE_ACTION_ON_SINK.contains(action)),
-                GrantObjects::Sequences(_)
-                | GrantObjects::AllSequencesInSchema { .. }
-                | GrantObjects::Tables(_)
-                | GrantObjects::AllTablesInSchema { .. } => true,
+                GrantObjects::Tables(_) | GrantObjects::AllTablesInSchema { .. } => actions
+                    .iter()
+                    .all(|action| AVAILABLE_ACTION_ON_TABLE.contains(action)),
+                GrantObjects::Sequences(_) | GrantObjects::AllSequencesInSchema { .. } => true,
             };
             if !valid {
                 return Err(ErrorCode::BindError(
@@ -81,6 +87,10 @@ pub fn available_privilege_actions(objects: &GrantObjects) -> Result<Vec<Action>
         GrantObjects::Mviews(_) | GrantObjects::AllMviewsInSchema { .. } => {
             Ok(AVAILABLE_ACTION_ON_MVIEW.to_vec())
         }
+        GrantObjects::Tables(_) | GrantObjects::AllTablesInSchema { .. } => {
+            Ok(AVAILABLE_ACTION_ON_TABLE.to_vec())
+        }
+        GrantObjects::Sinks(_) => Ok(AVAILABLE_ACTION_ON_SINK.to_vec()),
         _ => Err(
             ErrorCode::BindError("Invalid privilege type for the given object.".to_string()).into(),
         ),
@@ -108,7 +118,7 @@ pub fn available_prost_privilege(object: PbObject) -> PbGrantPrivilege {
             AVAILABLE_ACTION_ON_SOURCE.to_vec()
         }
         PbObject::TableId(_) | PbObject::AllTablesSchemaId { .. } => {
-            AVAILABLE_ACTION_ON_MVIEW.to_vec()
+            AVAILABLE_ACTION_ON_TABLE.to_vec()
         }
         PbObject::ViewId(_) => AVAILABLE_ACTION_ON_VIEW.to_vec(),
         PbObject::SinkId(_) => AVAILABLE_ACTION_ON_SINK.to_vec(),
@@ -38,6 +38,27 @@ pub fn explain_stream_graph(graph: &StreamFragmentGraph, is_verbose: bool) -> St
     output
 }
 
+/// Renders the fragments and their dispatch edges in `graph` as a Graphviz DOT digraph, for
+/// `explain (dot) create materialized view ...`.
+pub fn explain_stream_graph_as_dot(graph: &StreamFragmentGraph) -> String {
+    let mut output = String::with_capacity(1024);
+    output.push_str("digraph StreamFragmentGraph {\n");
+    for (id, fragment) in graph.fragments.iter().sorted_by_key(|(id, _)| **id) {
+        output.push_str(&format!(
+            "  \"{id}\" [label=\"Fragment {id}\\ntype_mask={}\"];\n",
+            fragment.fragment_type_mask
+        ));
+    }
+    for edge in &graph.edges {
+        output.push_str(&format!(
+            "  \"{}\" -> \"{}\";\n",
+            edge.upstream_id, edge.downstream_id
+        ));
+    }
+    output.push_str("}\n");
+    output
+}
+
 /// A formatter to display the final stream plan graph, used for `explain (distsql) create
 /// materialized view ...`
 struct StreamGraphFormatter {
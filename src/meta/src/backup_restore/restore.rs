@@ -54,6 +54,9 @@ pub struct RestoreOpts {
     /// Password if etcd auth has been enabled.
     #[clap(long, default_value = "")]
     pub etcd_password: String,
+    /// Endpoint of the SQL database to restore to, used when `meta_store_type` is `Sql`.
+    #[clap(long, default_value_t = String::from(""))]
+    pub sql_endpoint: String,
     /// Url of storage to fetch meta snapshot from.
     #[clap(long)]
     pub backup_storage_url: String,
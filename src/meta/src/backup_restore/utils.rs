@@ -23,13 +23,14 @@ use risingwave_object_store::object::object_metrics::ObjectStoreMetrics;
 use risingwave_object_store::object::parse_remote_object_store;
 
 use crate::backup_restore::RestoreOpts;
-use crate::storage::{EtcdMetaStore, MemStore, WrappedEtcdClient as EtcdClient};
+use crate::storage::{EtcdMetaStore, MemStore, SqlMetaStore, WrappedEtcdClient as EtcdClient};
 use crate::MetaStoreBackend;
 
 #[derive(Clone)]
 pub enum MetaStoreBackendImpl {
     Etcd(EtcdMetaStore),
     Mem(MemStore),
+    Sql(SqlMetaStore),
 }
 
 #[macro_export]
@@ -38,6 +39,7 @@ macro_rules! dispatch_meta_store {
         match $impl {
             MetaStoreBackendImpl::Etcd($store) => $body,
             MetaStoreBackendImpl::Mem($store) => $body,
+            MetaStoreBackendImpl::Sql($store) => $body,
         }
     }};
 }
@@ -57,6 +59,9 @@ pub async fn get_meta_store(opts: RestoreOpts) -> BackupResult<MetaStoreBackendI
             },
         },
         MetaBackend::Mem => MetaStoreBackend::Mem,
+        MetaBackend::Sql => MetaStoreBackend::Sql {
+            endpoint: opts.sql_endpoint.clone(),
+        },
     };
     match meta_store_backend {
         MetaStoreBackend::Etcd {
@@ -74,6 +79,11 @@ pub async fn get_meta_store(opts: RestoreOpts) -> BackupResult<MetaStoreBackendI
             Ok(MetaStoreBackendImpl::Etcd(EtcdMetaStore::new(client)))
         }
         MetaStoreBackend::Mem => Ok(MetaStoreBackendImpl::Mem(MemStore::new())),
+        MetaStoreBackend::Sql { endpoint } => Ok(MetaStoreBackendImpl::Sql(
+            SqlMetaStore::connect(&endpoint)
+                .await
+                .map_err(|e| anyhow::anyhow!("failed to connect meta store db {}", e))?,
+        )),
     }
 }
 
@@ -534,6 +534,15 @@ where
     }
 
     /// Start an infinite loop to take scheduled barriers and send them.
+    ///
+    /// Note on per-job barrier cadence: `barrier_interval_ms` and `checkpoint_frequency` are
+    /// cluster-wide [`SystemParams`](risingwave_pb::meta::SystemParams), and `min_interval` below
+    /// ticks once for the whole cluster. Every running streaming job is injected into the same
+    /// barrier, which is what makes the distributed snapshot consistent (à la Chandy-Lamport); a
+    /// job cannot skip a barrier or be on a different epoch than its peers. Supporting a
+    /// per-database or per-job barrier cadence, as opposed to a cluster-wide one, would therefore
+    /// need each job to advance on its own epoch sequence instead of sharing this one, which is a
+    /// much larger change than tuning this loop's interval.
     async fn run(&self, mut shutdown_rx: Receiver<()>) {
         // Initialize the barrier manager.
         let interval = Duration::from_millis(
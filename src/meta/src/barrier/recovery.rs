@@ -316,44 +316,60 @@ where
         }
 
         let node_actors = self.fragment_manager.all_node_actors(false).await;
-        for (node_id, actors) in &info.actor_map {
-            let node = info.node_map.get(node_id).unwrap();
-            let client = self.env.stream_client_pool().get(node).await?;
-
-            client
-                .broadcast_actor_info_table(BroadcastActorInfoTableRequest {
-                    info: actor_infos.clone(),
-                })
-                .await?;
+        // Update actors on all compute nodes concurrently, same as `reset_compute_nodes`, so
+        // recovery latency doesn't grow linearly with the number of nodes in the cluster.
+        let futures = info.actor_map.iter().map(|(node_id, actors)| {
+            let actor_infos = actor_infos.clone();
+            let node_actors = node_actors.get(node_id).cloned().unwrap_or_default();
+            async move {
+                let node = info.node_map.get(node_id).unwrap();
+                let client = self.env.stream_client_pool().get(node).await?;
+
+                client
+                    .broadcast_actor_info_table(BroadcastActorInfoTableRequest {
+                        info: actor_infos,
+                    })
+                    .await?;
+
+                let request_id = Uuid::new_v4().to_string();
+                tracing::debug!(request_id = request_id.as_str(), actors = ?actors, "update actors");
+                client
+                    .update_actors(UpdateActorsRequest {
+                        request_id,
+                        actors: node_actors,
+                    })
+                    .await
+            }
+        });
 
-            let request_id = Uuid::new_v4().to_string();
-            tracing::debug!(request_id = request_id.as_str(), actors = ?actors, "update actors");
-            client
-                .update_actors(UpdateActorsRequest {
-                    request_id,
-                    actors: node_actors.get(node_id).cloned().unwrap_or_default(),
-                })
-                .await?;
-        }
+        try_join_all(futures).await?;
 
         Ok(())
     }
 
     /// Build all actors in compute nodes.
     async fn build_actors(&self, info: &BarrierActorInfo) -> MetaResult<()> {
-        for (node_id, actors) in &info.actor_map {
+        // Build actors on all compute nodes concurrently; unlike `update_actors`, which must
+        // finish broadcasting actor info to every node before any of them can build, builds on
+        // different nodes are independent of each other.
+        let futures = info.actor_map.iter().map(|(node_id, actors)| {
             let node = info.node_map.get(node_id).unwrap();
-            let client = self.env.stream_client_pool().get(node).await?;
+            let actors = actors.to_owned();
+            async move {
+                let client = self.env.stream_client_pool().get(node).await?;
+
+                let request_id = Uuid::new_v4().to_string();
+                tracing::debug!(request_id = request_id.as_str(), actors = ?actors, "build actors");
+                client
+                    .build_actors(BuildActorsRequest {
+                        request_id,
+                        actor_id: actors,
+                    })
+                    .await
+            }
+        });
 
-            let request_id = Uuid::new_v4().to_string();
-            tracing::debug!(request_id = request_id.as_str(), actors = ?actors, "build actors");
-            client
-                .build_actors(BuildActorsRequest {
-                    request_id,
-                    actor_id: actors.to_owned(),
-                })
-                .await?;
-        }
+        try_join_all(futures).await?;
 
         Ok(())
     }
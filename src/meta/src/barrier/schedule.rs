@@ -327,5 +327,10 @@ impl ScheduledBarriers {
                 .num_uncheckpointed_barrier
                 .fetch_add(1, Ordering::Relaxed);
         }
+        self.inner
+            .metrics
+            .barrier_checkpoint_nums
+            .with_label_values(&[if checkpoint { "true" } else { "false" }])
+            .inc();
     }
 }
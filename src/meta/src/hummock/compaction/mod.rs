@@ -174,6 +174,7 @@ impl CompactStatus {
             target_sub_level_id: ret.input.target_sub_level_id,
             task_type: ret.compaction_task_type as i32,
             split_by_state_table: group.compaction_config.split_by_state_table,
+            max_sub_compaction: group.compaction_config.max_sub_compaction,
         };
         Some(compact_task)
     }
@@ -355,6 +356,7 @@ impl From<ScaleCompactorInfo> for GetScaleCompactorResponse {
             running_cores: info.running_cores,
             total_cores: info.total_cores,
             waiting_compaction_bytes: info.waiting_compaction_bytes,
+            smoothed_suggest_cores: 0.0,
         }
     }
 }
@@ -55,6 +55,8 @@ struct TaskHeartbeat {
     task: CompactTask,
     num_ssts_sealed: u32,
     num_ssts_uploaded: u32,
+    num_bytes_read: u64,
+    num_bytes_sealed: u64,
     expire_at: u64,
 }
 
@@ -282,6 +284,23 @@ impl CompactorManager {
         self.task_heartbeats.write().remove(&context_id).is_some()
     }
 
+    /// Lists the latest reported progress of all currently assigned compaction tasks, for
+    /// inspection via meta RPC and `risectl hummock list-compact-task-progress`.
+    pub fn list_task_progress(&self) -> Vec<CompactTaskProgress> {
+        self.task_heartbeats
+            .read()
+            .values()
+            .flat_map(|heartbeats| heartbeats.values())
+            .map(|heartbeat| CompactTaskProgress {
+                task_id: heartbeat.task.task_id,
+                num_ssts_sealed: heartbeat.num_ssts_sealed,
+                num_ssts_uploaded: heartbeat.num_ssts_uploaded,
+                num_bytes_read: heartbeat.num_bytes_read,
+                num_bytes_sealed: heartbeat.num_bytes_sealed,
+            })
+            .collect_vec()
+    }
+
     pub fn get_expired_tasks(
         &self,
         split_cancel: Vec<HummockCompactionTaskId>,
@@ -360,6 +379,8 @@ impl CompactorManager {
                 task,
                 num_ssts_sealed: 0,
                 num_ssts_uploaded: 0,
+                num_bytes_read: 0,
+                num_bytes_sealed: 0,
                 expire_at: now + self.task_expiry_seconds,
             },
         );
@@ -390,12 +411,16 @@ impl CompactorManager {
                 if let Some(task_ref) = heartbeats.get_mut(&progress.task_id) {
                     if task_ref.num_ssts_sealed < progress.num_ssts_sealed
                         || task_ref.num_ssts_uploaded < progress.num_ssts_uploaded
+                        || task_ref.num_bytes_read < progress.num_bytes_read
+                        || task_ref.num_bytes_sealed < progress.num_bytes_sealed
                     {
                         // Refresh the expiry of the task as it is showing progress.
                         task_ref.expire_at = now + self.task_expiry_seconds;
                         // Update the task state to the latest state.
                         task_ref.num_ssts_sealed = progress.num_ssts_sealed;
                         task_ref.num_ssts_uploaded = progress.num_ssts_uploaded;
+                        task_ref.num_bytes_read = progress.num_bytes_read;
+                        task_ref.num_bytes_sealed = progress.num_bytes_sealed;
                     }
                 }
             }
@@ -488,6 +513,7 @@ mod tests {
                 task_id: expired[0].1.task_id,
                 num_ssts_sealed: 0,
                 num_ssts_uploaded: 0,
+                ..Default::default()
             }],
         );
         assert_eq!(compactor_manager.get_expired_tasks(vec![]).len(), 1);
@@ -499,6 +525,7 @@ mod tests {
                 task_id: expired[0].1.task_id + 1,
                 num_ssts_sealed: 1,
                 num_ssts_uploaded: 1,
+                ..Default::default()
             }],
         );
         assert_eq!(compactor_manager.get_expired_tasks(vec![]).len(), 1);
@@ -510,6 +537,7 @@ mod tests {
                 task_id: expired[0].1.task_id,
                 num_ssts_sealed: 1,
                 num_ssts_uploaded: 1,
+                ..Default::default()
             }],
         );
         assert_eq!(compactor_manager.get_expired_tasks(vec![]).len(), 0);
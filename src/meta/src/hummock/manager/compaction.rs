@@ -122,6 +122,18 @@ where
             })
             .collect_vec()
     }
+
+    /// Lists the compact tasks currently assigned to a compactor, for inspection via
+    /// `risectl hummock list-compact-tasks`.
+    #[named]
+    pub async fn list_compact_task_assignments(&self) -> Vec<CompactTaskAssignment> {
+        read_lock!(self, compaction)
+            .await
+            .compact_task_assignment
+            .values()
+            .cloned()
+            .collect_vec()
+    }
 }
 
 #[cfg(test)]
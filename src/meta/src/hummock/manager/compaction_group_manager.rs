@@ -401,6 +401,7 @@ impl<S: MetaStore> HummockManager<S> {
         let mut versioning_guard = write_lock!(self, versioning).await;
         let versioning = versioning_guard.deref_mut();
         let current_version = &versioning.current_version;
+        let group_write_throughput = versioning.group_write_throughput();
         let mut compaction_groups = vec![];
         for levels in current_version.levels.values() {
             let config = self
@@ -414,12 +415,27 @@ impl<S: MetaStore> HummockManager<S> {
                 parent_id: levels.parent_group_id,
                 member_table_ids: levels.member_table_ids.clone(),
                 compaction_config: Some(config.as_ref().clone()),
+                write_throughput: group_write_throughput
+                    .get(&levels.group_id)
+                    .copied()
+                    .unwrap_or(0),
             };
             compaction_groups.push(group);
         }
         compaction_groups
     }
 
+    /// Returns, for each compaction group, the sum over its member tables of the recent per-table
+    /// write throughput samples recorded by [`commit_epoch`](HummockManager::commit_epoch).
+    /// Currently surfaced for observability only (see
+    /// [`list_compaction_group`](Self::list_compaction_group)/`risectl hummock
+    /// list-compaction-group`); no group-split or compaction-priority decision consults it yet.
+    #[named]
+    pub async fn compaction_group_write_throughput(&self) -> HashMap<CompactionGroupId, u64> {
+        let versioning = read_lock!(self, versioning).await;
+        versioning.group_write_throughput()
+    }
+
     /// Splits a compaction group into two. The new one will contain `table_ids`.
     /// Returns the newly created compaction group id.
     pub async fn split_compaction_group(
@@ -798,6 +814,9 @@ fn update_compaction_config(target: &mut CompactionConfig, items: &[MutableConfi
             MutableConfig::Level0StopWriteThresholdSubLevelNumber(c) => {
                 target.level0_stop_write_threshold_sub_level_number = *c;
             }
+            MutableConfig::CompressionAlgorithm(c) => {
+                target.compression_algorithm = c.compression_algorithm.clone();
+            }
         }
     }
 }
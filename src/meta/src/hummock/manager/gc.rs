@@ -66,16 +66,19 @@ where
     pub async fn delete_version_deltas(&self, batch_size: usize) -> Result<(usize, usize)> {
         let mut versioning_guard = write_lock!(self, versioning).await;
         let versioning = versioning_guard.deref_mut();
+        // Deltas still needed by a pinned version, a safe point (e.g. an in-progress backup), or
+        // the time-travel retention window must be kept, so readers relying on them keep working.
+        // Everything older can be aggressively vacuumed.
+        let min_pinned_version_id = versioning.min_pinned_version_id();
+        let min_delta_id_to_retain = versioning.min_delta_id_to_retain_for_time_travel(
+            self.env.opts.min_version_retention_duration_sec,
+        );
         let deltas_to_delete = versioning
             .hummock_version_deltas
             .range(..=versioning.checkpoint.version.as_ref().unwrap().id)
             .map(|(k, _)| *k)
+            .filter(|id| *id < min_pinned_version_id && *id < min_delta_id_to_retain)
             .collect_vec();
-        // If there is any safe point, skip this to ensure meta backup has required delta logs to
-        // replay version.
-        if !versioning.version_safe_points.is_empty() {
-            return Ok((0, deltas_to_delete.len()));
-        }
         let mut hummock_version_deltas =
             BTreeMapTransaction::new(&mut versioning.hummock_version_deltas);
         let batch = deltas_to_delete
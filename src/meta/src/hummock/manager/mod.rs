@@ -41,9 +41,9 @@ use risingwave_pb::hummock::group_delta::DeltaType;
 use risingwave_pb::hummock::subscribe_compact_tasks_response::Task;
 use risingwave_pb::hummock::{
     version_update_payload, CompactTask, CompactTaskAssignment, CompactionConfig, GroupDelta,
-    HummockPinnedSnapshot, HummockPinnedVersion, HummockSnapshot, HummockVersion,
-    HummockVersionCheckpoint, HummockVersionDelta, HummockVersionDeltas, HummockVersionStats,
-    IntraLevelDelta, LevelType, TableOption,
+    HummockPinnedSnapshot, HummockPinnedVersion, HummockRetainedSnapshot, HummockSnapshot,
+    HummockVersion, HummockVersionCheckpoint, HummockVersionDelta, HummockVersionDeltas,
+    HummockVersionStats, IntraLevelDelta, LevelType, TableOption,
 };
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
 use tokio::sync::oneshot::Sender;
@@ -111,6 +111,10 @@ pub struct HummockManager<S: MetaStore> {
     compaction_request_channel: parking_lot::RwLock<Option<CompactionRequestChannelRef>>,
     compaction_resume_notifier: parking_lot::RwLock<Option<Arc<Notify>>>,
     compaction_tasks_to_cancel: parking_lot::Mutex<Vec<HummockCompactionTaskId>>,
+    // Exponential moving average of `ScaleCompactorInfo::scale_out_cores`, so that autoscalers
+    // polling `GetScaleCompactor` see a hysteresis-friendly signal instead of one that flaps with
+    // every LSM stat tick.
+    compactor_scale_out_cores_ema: parking_lot::Mutex<f64>,
 
     pub compactor_manager: CompactorManagerRef,
     event_sender: HummockManagerEventSender,
@@ -312,6 +316,7 @@ where
             compaction_request_channel: parking_lot::RwLock::new(None),
             compaction_resume_notifier: parking_lot::RwLock::new(None),
             compaction_tasks_to_cancel: parking_lot::Mutex::new(vec![]),
+            compactor_scale_out_cores_ema: parking_lot::Mutex::new(0.0),
             compactor_manager,
             latest_snapshot: ArcSwap::from_pointee(HummockSnapshot {
                 committed_epoch: INVALID_EPOCH,
@@ -493,6 +498,11 @@ where
             .into_iter()
             .map(|p| (p.context_id, p))
             .collect();
+        versioning_guard.retained_snapshots = HummockRetainedSnapshot::list(self.env.meta_store())
+            .await?
+            .into_iter()
+            .map(|s| (s.id, s))
+            .collect();
 
         versioning_guard.objects_to_delete.clear();
         versioning_guard.mark_objects_for_deletion();
@@ -530,6 +540,9 @@ where
             }
         }
 
+        self.metrics
+            .hummock_manager_txn_ops
+            .observe(trx.num_operations() as f64);
         meta_store.txn(trx).await.map_err(Into::into)
     }
 
@@ -613,6 +626,67 @@ where
         Ok(())
     }
 
+    /// Creates a new named, persisted snapshot pinning the current hummock version, so it can be
+    /// used for a backup or to spin up a read-only replica against a fixed historical state. The
+    /// pin survives meta restarts and is held until [`Self::drop_retained_snapshot`] is called.
+    #[named]
+    pub async fn create_retained_snapshot(&self, name: String) -> Result<HummockRetainedSnapshot> {
+        let id = self
+            .env
+            .id_gen_manager()
+            .generate::<{ IdCategory::HummockRetainedSnapshot }>()
+            .await?;
+        let mut versioning_guard = write_lock!(self, versioning).await;
+        let versioning = versioning_guard.deref_mut();
+        let snapshot = HummockRetainedSnapshot {
+            id,
+            name,
+            version_id: versioning.current_version.id,
+            create_time_ms: Epoch::physical_now(),
+        };
+        let mut retained_snapshots = BTreeMapTransaction::new(&mut versioning.retained_snapshots);
+        retained_snapshots.insert(id, snapshot.clone());
+        commit_multi_var!(self, None, Transaction::default(), retained_snapshots)?;
+
+        #[cfg(test)]
+        {
+            drop(versioning_guard);
+            self.check_state_consistency().await;
+        }
+
+        Ok(snapshot)
+    }
+
+    /// Lists all currently retained named snapshots.
+    #[named]
+    pub async fn list_retained_snapshots(&self) -> Vec<HummockRetainedSnapshot> {
+        read_lock!(self, versioning)
+            .await
+            .retained_snapshots
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    /// Drops a named snapshot previously created by [`Self::create_retained_snapshot`], allowing
+    /// its pinned version (and any deltas only retained for it) to be vacuumed again.
+    #[named]
+    pub async fn drop_retained_snapshot(&self, id: u64) -> Result<()> {
+        let mut versioning_guard = write_lock!(self, versioning).await;
+        let versioning = versioning_guard.deref_mut();
+        let mut retained_snapshots = BTreeMapTransaction::new(&mut versioning.retained_snapshots);
+        retained_snapshots.remove(id);
+        commit_multi_var!(self, None, Transaction::default(), retained_snapshots)?;
+
+        #[cfg(test)]
+        {
+            drop(versioning_guard);
+            self.check_state_consistency().await;
+        }
+
+        Ok(())
+    }
+
     #[named]
     pub async fn pin_specific_snapshot(
         &self,
@@ -810,7 +884,13 @@ where
             return Ok(None);
         }
 
-        let can_trivial_move = matches!(selector.task_type(), compact_task::TaskType::Dynamic);
+        // Space-reclaim and TTL tasks always rewrite to drop keys, so they can never be trivial
+        // moves. Dynamic and manual compaction may pick a file that doesn't overlap the target
+        // level at all, in which case the move can skip the compactor and object-store IO.
+        let can_trivial_move = matches!(
+            selector.task_type(),
+            compact_task::TaskType::Dynamic | compact_task::TaskType::Manual
+        );
 
         let mut stats = LocalSelectorStatistic::default();
         let member_table_ids = &current_version
@@ -1263,8 +1343,6 @@ where
             // 2. trivial_move
 
             let label = if CompactStatus::is_trivial_move_task(compact_task) {
-                // TODO: only support can_trivial_move in DynamicLevelCompcation, will check
-                // task_type next PR
                 "trivial-move"
             } else {
                 "unassigned"
@@ -1500,6 +1578,8 @@ where
         // Create a new_version, possibly merely to bump up the version id and max_committed_epoch.
         new_hummock_version.max_committed_epoch = epoch;
 
+        versioning.record_table_write_throughput(&table_stats_change);
+
         // Apply stats changes.
         let mut version_stats = VarTransaction::new(&mut versioning.version_stats);
         add_prost_table_stats_map(&mut version_stats.table_stats, &table_stats_change);
@@ -1607,6 +1687,7 @@ where
                 let compact_task_assignment_copy = compaction_guard.compact_task_assignment.clone();
                 let pinned_versions_copy = versioning_guard.pinned_versions.clone();
                 let pinned_snapshots_copy = versioning_guard.pinned_snapshots.clone();
+                let retained_snapshots_copy = versioning_guard.retained_snapshots.clone();
                 let hummock_version_deltas_copy = versioning_guard.hummock_version_deltas.clone();
                 let version_stats_copy = versioning_guard.version_stats.clone();
                 let branched_ssts = versioning_guard.branched_ssts.clone();
@@ -1616,6 +1697,7 @@ where
                         compact_task_assignment_copy,
                         pinned_versions_copy,
                         pinned_snapshots_copy,
+                        retained_snapshots_copy,
                         hummock_version_deltas_copy,
                         version_stats_copy,
                     ),
@@ -1653,6 +1735,34 @@ where
         read_lock!(self, versioning).await.branched_ssts.clone()
     }
 
+    /// Reconstructs the hummock version as of `committed_epoch`, for time-travel reads, by
+    /// replaying retained version deltas forward from the checkpoint. Returns `None` if the
+    /// epoch predates the oldest version delta still retained (see
+    /// `min_version_retention_duration_sec`), in which case the history is gone and the caller
+    /// should fall back to the current version.
+    ///
+    /// Mirrors the delta-replay done by [`crate::backup_restore::meta_snapshot_builder`] when
+    /// restoring a historical meta snapshot.
+    #[named]
+    pub async fn version_at_epoch(&self, committed_epoch: HummockEpoch) -> Option<HummockVersion> {
+        let versioning = read_lock!(self, versioning).await;
+        let checkpoint_version = versioning.checkpoint.version.as_ref().unwrap();
+        if committed_epoch < checkpoint_version.max_committed_epoch {
+            return None;
+        }
+        let mut redo_state = checkpoint_version.clone();
+        for version_delta in versioning.hummock_version_deltas.values() {
+            if version_delta.prev_id != redo_state.id {
+                continue;
+            }
+            if version_delta.max_committed_epoch > committed_epoch {
+                break;
+            }
+            redo_state.apply_version_delta(version_delta);
+        }
+        Some(redo_state)
+    }
+
     /// Get version deltas from meta store
     #[cfg_attr(coverage, no_coverage)]
     pub async fn list_version_deltas(
@@ -1964,6 +2074,12 @@ where
         &self.cluster_manager
     }
 
+    /// Latest exponential moving average of the suggested compactor scale-out core count. See
+    /// [`Self::report_scale_compactor_info`].
+    pub fn compactor_scale_out_cores_ema(&self) -> f64 {
+        *self.compactor_scale_out_cores_ema.lock()
+    }
+
     pub async fn report_scale_compactor_info(&self) {
         let info = self.get_scale_compactor_info().await;
         let suggest_scale_out_core = info.scale_out_cores();
@@ -1971,6 +2087,16 @@ where
             .scale_compactor_core_num
             .set(suggest_scale_out_core as i64);
 
+        // Smooth the raw suggestion with an EMA so that an autoscaler polling the gauge doesn't
+        // react to every transient spike or dip in compaction debt.
+        const EMA_ALPHA: f64 = 0.3;
+        let smoothed = {
+            let mut ema = self.compactor_scale_out_cores_ema.lock();
+            *ema = EMA_ALPHA * suggest_scale_out_core as f64 + (1.0 - EMA_ALPHA) * *ema;
+            *ema
+        };
+        self.metrics.scale_compactor_core_num_smoothed.set(smoothed);
+
         tracing::debug!(
             "report_scale_compactor_info {:?} suggest_scale_out_core {:?}",
             info,
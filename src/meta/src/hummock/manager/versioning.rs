@@ -13,24 +13,25 @@
 // limitations under the License.
 
 use std::cmp;
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 
 use function_name::named;
 use itertools::Itertools;
-use risingwave_common::util::epoch::INVALID_EPOCH;
+use risingwave_common::util::epoch::{Epoch, INVALID_EPOCH};
 use risingwave_hummock_sdk::compaction_group::hummock_version_ext::{
     build_initial_compaction_group_levels, get_compaction_group_ids, BranchedSstInfo,
     HummockVersionExt,
 };
 use risingwave_hummock_sdk::compaction_group::{StateTableId, StaticCompactionGroupId};
+use risingwave_hummock_sdk::table_stats::PbTableStatsMap;
 use risingwave_hummock_sdk::{
     CompactionGroupId, HummockContextId, HummockSstableObjectId, HummockVersionId, FIRST_VERSION_ID,
 };
 use risingwave_pb::common::WorkerNode;
 use risingwave_pb::hummock::write_limits::WriteLimit;
 use risingwave_pb::hummock::{
-    HummockPinnedSnapshot, HummockPinnedVersion, HummockVersion, HummockVersionCheckpoint,
-    HummockVersionDelta, HummockVersionStats,
+    HummockPinnedSnapshot, HummockPinnedVersion, HummockRetainedSnapshot, HummockVersion,
+    HummockVersionCheckpoint, HummockVersionDelta, HummockVersionStats,
 };
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
 
@@ -42,6 +43,10 @@ use crate::hummock::model::CompactionGroup;
 use crate::hummock::HummockManager;
 use crate::storage::MetaStore;
 
+/// Number of recent `commit_epoch` write sizes kept per table in
+/// [`Versioning::table_write_throughput_statistic`].
+const TABLE_WRITE_THROUGHPUT_WINDOW_SIZE: usize = 60;
+
 /// `HummockVersionSafePoint` prevents hummock versions GE than it from being GC.
 /// It's used by meta node itself to temporarily pin versions.
 pub struct HummockVersionSafePoint {
@@ -85,11 +90,20 @@ pub struct Versioning {
     pub version_safe_points: Vec<HummockVersionId>,
     /// Tables that write limit is trigger for.
     pub write_limit: HashMap<CompactionGroupId, WriteLimit>,
+    /// Recent per-table write sizes (key size + value size), one entry per `commit_epoch`, oldest
+    /// first, capped at [`TABLE_WRITE_THROUGHPUT_WINDOW_SIZE`]. Aggregated per compaction group by
+    /// [`Versioning::group_write_throughput`] and surfaced for observability; not yet consulted by
+    /// any group-split or compaction-priority decision.
+    pub table_write_throughput_statistic: HashMap<StateTableId, VecDeque<u64>>,
 
     // Persistent states below
     pub hummock_version_deltas: BTreeMap<HummockVersionId, HummockVersionDelta>,
     pub pinned_versions: BTreeMap<HummockContextId, HummockPinnedVersion>,
     pub pinned_snapshots: BTreeMap<HummockContextId, HummockPinnedSnapshot>,
+    /// User-created named snapshots, keyed by snapshot id. Each one pins its `version_id`
+    /// against vacuum, similar to `pinned_versions`, but is explicitly created/dropped by the
+    /// user and persists across meta restarts.
+    pub retained_snapshots: BTreeMap<u64, HummockRetainedSnapshot>,
     /// Stats for latest hummock version.
     pub version_stats: HummockVersionStats,
     pub checkpoint: HummockVersionCheckpoint,
@@ -103,12 +117,30 @@ impl Versioning {
             .values()
             .map(|v| v.min_pinned_id)
             .chain(self.version_safe_points.iter().cloned())
+            .chain(self.retained_snapshots.values().map(|s| s.version_id))
         {
             min_pinned_version_id = cmp::min(id, min_pinned_version_id);
         }
         min_pinned_version_id
     }
 
+    /// Returns the id of the oldest version delta that must be kept so that a version at least
+    /// `retention_sec` old can still be reconstructed via
+    /// [`HummockManager::version_at_epoch`](crate::hummock::HummockManager::version_at_epoch).
+    /// Returns [`HummockVersionId::MAX`], i.e. retains nothing extra, when `retention_sec` is 0.
+    pub fn min_delta_id_to_retain_for_time_travel(&self, retention_sec: u64) -> HummockVersionId {
+        if retention_sec == 0 {
+            return HummockVersionId::MAX;
+        }
+        let retain_since_time = Epoch::physical_now().saturating_sub(retention_sec * 1000);
+        self.hummock_version_deltas
+            .iter()
+            .find(|(_, delta)| {
+                Epoch(delta.max_committed_epoch).physical_time() >= retain_since_time
+            })
+            .map_or(HummockVersionId::MAX, |(id, _)| *id)
+    }
+
     /// Marks all objects <= `min_pinned_version_id` for deletion.
     pub(super) fn mark_objects_for_deletion(&mut self) {
         let min_pinned_version_id = self.min_pinned_version_id();
@@ -160,6 +192,44 @@ impl Versioning {
         }
         !found_sstable_repeated
     }
+
+    /// Records the write size of `table_stats_change` (as produced by one `commit_epoch` call) for
+    /// each affected table, evicting the oldest sample once the per-table window is full.
+    pub(super) fn record_table_write_throughput(&mut self, table_stats_change: &PbTableStatsMap) {
+        for (table_id, stats) in table_stats_change {
+            let write_size = (stats.total_key_size + stats.total_value_size).max(0) as u64;
+            let samples = self
+                .table_write_throughput_statistic
+                .entry(*table_id)
+                .or_default();
+            samples.push_back(write_size);
+            if samples.len() > TABLE_WRITE_THROUGHPUT_WINDOW_SIZE {
+                samples.pop_front();
+            }
+        }
+    }
+
+    /// For each compaction group in `current_version`, sums the recent per-table write
+    /// throughput samples of its member tables.
+    pub(super) fn group_write_throughput(&self) -> HashMap<CompactionGroupId, u64> {
+        self.current_version
+            .levels
+            .values()
+            .map(|levels| {
+                let throughput: u64 = levels
+                    .member_table_ids
+                    .iter()
+                    .flat_map(|table_id| {
+                        self.table_write_throughput_statistic
+                            .get(table_id)
+                            .into_iter()
+                            .flatten()
+                    })
+                    .sum();
+                (levels.group_id, throughput)
+            })
+            .collect()
+    }
 }
 
 impl<S> HummockManager<S>
@@ -30,7 +30,7 @@ use risingwave_pb::common::{HostAddress, WorkerType};
 use risingwave_pb::hummock::subscribe_compact_tasks_response::Task;
 use risingwave_pb::hummock::{
     compact_task, CompactTask, CompactTaskProgress, CompactorWorkload, HummockSnapshot,
-    HummockVersion, SubscribeCompactTasksResponse, VacuumTask,
+    HummockVersion, KeyRange, SubscribeCompactTasksResponse, VacuumTask,
 };
 use risingwave_rpc_client::error::{Result, RpcError};
 use risingwave_rpc_client::{CompactTaskItem, HummockMetaClient};
@@ -253,6 +253,8 @@ impl HummockMetaClient for MockHummockMetaClient {
         _compaction_group_id: u64,
         _table_id: u32,
         _level: u32,
+        _sst_ids: Vec<u64>,
+        _key_range: Option<KeyRange>,
     ) -> Result<()> {
         todo!()
     }
@@ -67,9 +67,14 @@ where
     // Start vacuum in non-deterministic compaction test
     if !meta_opts.compaction_deterministic_test {
         workers.push(start_vacuum_scheduler(
-            vacuum_manager,
+            vacuum_manager.clone(),
             Duration::from_secs(meta_opts.vacuum_interval_sec),
         ));
+        workers.push(start_full_gc_scheduler(
+            vacuum_manager,
+            Duration::from_secs(meta_opts.full_gc_interval_sec),
+            Duration::from_secs(meta_opts.min_sst_retention_time_sec),
+        ));
     }
     workers
 }
@@ -126,6 +131,37 @@ where
     (join_handle, shutdown_tx)
 }
 
+/// Starts a task to periodically diff object store SST listing against the hummock version and
+/// delete orphan SSTs left behind by failed uploads or aborted compactions.
+fn start_full_gc_scheduler<S>(
+    vacuum: VacuumManagerRef<S>,
+    interval: Duration,
+    sst_retention_time: Duration,
+) -> (JoinHandle<()>, Sender<()>)
+where
+    S: MetaStore,
+{
+    let (shutdown_tx, mut shutdown_rx) = tokio::sync::oneshot::channel();
+    let join_handle = tokio::spawn(async move {
+        let mut min_trigger_interval = tokio::time::interval(interval);
+        min_trigger_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        min_trigger_interval.tick().await; // the first tick is immediate
+        loop {
+            tokio::select! {
+                _ = min_trigger_interval.tick() => {},
+                _ = &mut shutdown_rx => {
+                    tracing::info!("Full GC scheduler is stopped");
+                    return;
+                }
+            }
+            if let Err(err) = vacuum.start_full_gc(sst_retention_time).await {
+                tracing::warn!("Full GC error {:#?}", err);
+            }
+        }
+    });
+    (join_handle, shutdown_tx)
+}
+
 pub fn start_checkpoint_loop<S: MetaStore>(
     hummock_manager: HummockManagerRef<S>,
     interval: Duration,
@@ -17,6 +17,7 @@ mod compaction_group_config;
 mod compaction_status;
 mod pinned_snapshot;
 mod pinned_version;
+mod retained_snapshot;
 mod version;
 mod version_delta;
 mod version_stats;
@@ -25,6 +26,7 @@ pub use compaction_group_config::CompactionGroup;
 pub use compaction_status::*;
 pub use pinned_snapshot::*;
 pub use pinned_version::*;
+pub use retained_snapshot::*;
 pub use version::*;
 pub use version_delta::*;
 
@@ -38,3 +40,4 @@ const HUMMOCK_COMPACTION_STATUS_CF_NAME: &str = "cf/hummock_4";
 const HUMMOCK_COMPACT_TASK_ASSIGNMENT: &str = "cf/hummock_5";
 const HUMMOCK_COMPACTION_GROUP_CONFIG_CF_NAME: &str = "cf/hummock_6";
 const HUMMOCK_VERSION_STATS_CF_NAME: &str = "cf/hummock_7";
+const HUMMOCK_RETAINED_SNAPSHOT_CF_NAME: &str = "cf/hummock_8";
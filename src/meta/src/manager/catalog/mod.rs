@@ -36,7 +36,7 @@ use risingwave_common::catalog::{
 use risingwave_common::{bail, ensure};
 use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
 use risingwave_pb::catalog::{
-    Connection, Database, Function, Index, Schema, Sink, Source, Table, View,
+    Comment, Connection, Database, Function, Index, Schema, Sink, Source, Table, View,
 };
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
 use risingwave_pb::user::grant_privilege::{ActionWithGrantOption, Object};
@@ -1247,6 +1247,46 @@ where
         Ok(version)
     }
 
+    /// Handles `COMMENT ON TABLE`/`COMMENT ON MATERIALIZED VIEW`/`COMMENT ON COLUMN`, storing the
+    /// description on the target table (a materialized view is just a table with a different
+    /// `table_type`) so it can be surfaced through `pg_description`.
+    pub async fn comment_on(&self, comment: Comment) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        database_core.ensure_table_id(comment.table_id)?;
+
+        let mut table = database_core.tables.get(&comment.table_id).unwrap().clone();
+        match comment.column_id {
+            Some(column_id) => {
+                if let Some(description) = &comment.description {
+                    table
+                        .column_comments
+                        .insert(column_id, description.clone());
+                } else {
+                    table.column_comments.remove(&column_id);
+                }
+            }
+            None => table.description = comment.description,
+        }
+
+        let mut tables = BTreeMapTransaction::new(&mut database_core.tables);
+        tables.insert(comment.table_id, table.clone());
+        commit_meta!(self, tables)?;
+
+        let version = self
+            .notify_frontend(
+                Operation::Update,
+                Info::RelationGroup(RelationGroup {
+                    relations: vec![Relation {
+                        relation_info: RelationInfo::Table(table).into(),
+                    }],
+                }),
+            )
+            .await;
+
+        Ok(version)
+    }
+
     pub async fn start_create_source_procedure(&self, source: &Source) -> MetaResult<()> {
         let core = &mut *self.core.lock().await;
         let database_core = &mut core.database;
@@ -155,7 +155,15 @@ where
         if worker_type == WorkerType::ComputeNode {
             self.env
                 .notification_manager()
-                .notify_frontend(Operation::Add, Info::Node(worker.worker_node))
+                .notify_frontend(Operation::Add, Info::Node(worker.worker_node.clone()))
+                .await;
+            // Notify local subscribers so that existing streaming jobs can be rebalanced onto
+            // the newly joined node.
+            self.env
+                .notification_manager()
+                .notify_local_subscribers(LocalNotification::WorkerNodeActivated(
+                    worker.worker_node,
+                ))
                 .await;
         }
 
@@ -114,6 +114,19 @@ pub struct MetaOpts {
 
     ///  compactor task limit = max_compactor_task_multiplier * cpu_core_num
     pub max_compactor_task_multiplier: u32,
+
+    /// Schedule a full GC, i.e. diff object store SST listing against the hummock version to
+    /// find orphan SSTs, with this interval.
+    pub full_gc_interval_sec: u64,
+
+    /// The window, in seconds, for which version deltas are retained even past the point GC
+    /// would otherwise vacuum them, so a historical hummock version can still be reconstructed
+    /// for time-travel reads. 0 disables the extra retention.
+    pub min_version_retention_duration_sec: u64,
+
+    /// Whether to disable automatically rebalancing existing streaming jobs onto a compute node
+    /// as soon as it joins the cluster.
+    pub disable_automatic_parallelism_control: bool,
 }
 
 impl MetaOpts {
@@ -140,6 +153,9 @@ impl MetaOpts {
             telemetry_enabled: false,
             periodic_ttl_reclaim_compaction_interval_sec: 60,
             max_compactor_task_multiplier: 2,
+            full_gc_interval_sec: 3600,
+            min_version_retention_duration_sec: 3600,
+            disable_automatic_parallelism_control: false,
         }
     }
 }
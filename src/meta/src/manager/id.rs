@@ -142,6 +142,7 @@ pub mod IdCategory {
     pub const CompactionGroup: IdCategoryType = 15;
     pub const Function: IdCategoryType = 16;
     pub const Connection: IdCategoryType = 17;
+    pub const HummockRetainedSnapshot: IdCategoryType = 18;
 }
 
 pub type IdGeneratorManagerRef<S> = Arc<IdGeneratorManager<S>>;
@@ -165,6 +166,7 @@ pub struct IdGeneratorManager<S> {
     parallel_unit: Arc<StoredIdGenerator<S>>,
     compaction_group: Arc<StoredIdGenerator<S>>,
     connection: Arc<StoredIdGenerator<S>>,
+    hummock_retained_snapshot: Arc<StoredIdGenerator<S>>,
 }
 
 impl<S> IdGeneratorManager<S>
@@ -224,6 +226,10 @@ where
             connection: Arc::new(
                 StoredIdGenerator::new(meta_store.clone(), "connection", None).await,
             ),
+            hummock_retained_snapshot: Arc::new(
+                StoredIdGenerator::new(meta_store.clone(), "hummock_retained_snapshot", Some(1))
+                    .await,
+            ),
         }
     }
 
@@ -245,6 +251,7 @@ where
             IdCategory::HummockCompactionTask => &self.hummock_compaction_task,
             IdCategory::CompactionGroup => &self.compaction_group,
             IdCategory::Connection => &self.connection,
+            IdCategory::HummockRetainedSnapshot => &self.hummock_retained_snapshot,
             _ => unreachable!(),
         }
     }
@@ -40,6 +40,9 @@ pub type NotificationVersion = u64;
 #[derive(Clone, Debug)]
 pub enum LocalNotification {
     WorkerNodeIsDeleted(WorkerNode),
+    /// Fired when a worker node transitions from `Starting` to `Running`, i.e. it has joined the
+    /// cluster and is ready to host actors.
+    WorkerNodeActivated(WorkerNode),
     CompactionTaskNeedCancel(CompactTask),
     SystemParamsChange(SystemParamsReader),
 }
@@ -18,6 +18,12 @@ use crate::storage::{MetaStore, MetaStoreError, MetaStoreResult, DEFAULT_COLUMN_
 
 /// `BarrierManagerState` defines the necessary state of `GlobalBarrierManager`, this will be stored
 /// persistently to meta store. Add more states when needed.
+///
+/// Persisting `in_flight_prev_epoch` before it is used to build the next barrier (see
+/// `GlobalBarrierManager::run_inner`) is what makes epoch generation monotonic across a meta
+/// failover: the new leader resumes from the last persisted epoch rather than from its own clock,
+/// and [`Epoch::next`] falls back to a logical bump whenever the physical clock hasn't advanced
+/// past it, so a reboot or clock skew can never produce an epoch that regresses or repeats.
 pub struct BarrierManagerState {
     /// The last sent `prev_epoch`
     pub in_flight_prev_epoch: Epoch,
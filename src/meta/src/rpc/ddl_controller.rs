@@ -14,7 +14,7 @@
 
 use itertools::Itertools;
 use risingwave_common::util::column_index_mapping::ColIndexMapping;
-use risingwave_pb::catalog::{Connection, Database, Function, Schema, Source, Table, View};
+use risingwave_pb::catalog::{Comment, Connection, Database, Function, Schema, Source, Table, View};
 use risingwave_pb::ddl_service::alter_relation_name_request::Relation;
 use risingwave_pb::ddl_service::DdlProgress;
 use risingwave_pb::stream_plan::StreamFragmentGraph as StreamFragmentGraphProto;
@@ -67,6 +67,7 @@ pub enum DdlCommand {
     DropStreamingJob(StreamingJobId),
     ReplaceTable(StreamingJob, StreamFragmentGraphProto, ColIndexMapping),
     AlterRelationName(Relation, String),
+    CommentOn(Comment),
     CreateConnection(Connection),
     DropConnection(String),
 }
@@ -148,6 +149,7 @@ where
                 DdlCommand::AlterRelationName(relation, name) => {
                     ctrl.alter_relation_name(relation, &name).await
                 }
+                DdlCommand::CommentOn(comment) => ctrl.comment_on(comment).await,
                 DdlCommand::CreateConnection(connection) => {
                     ctrl.create_connection(connection).await
                 }
@@ -751,4 +753,8 @@ where
             }
         }
     }
+
+    async fn comment_on(&self, comment: Comment) -> MetaResult<NotificationVersion> {
+        self.catalog_manager.comment_on(comment).await
+    }
 }
@@ -16,13 +16,14 @@ use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 use std::time::Duration;
 
-use prometheus::core::{AtomicF64, GenericGaugeVec};
+use prometheus::core::{AtomicF64, GenericGauge, GenericGaugeVec};
 use prometheus::{
     exponential_buckets, histogram_opts, register_gauge_vec_with_registry,
-    register_histogram_vec_with_registry, register_histogram_with_registry,
-    register_int_counter_vec_with_registry, register_int_counter_with_registry,
-    register_int_gauge_vec_with_registry, register_int_gauge_with_registry, Histogram,
-    HistogramVec, IntCounter, IntCounterVec, IntGauge, IntGaugeVec, Registry,
+    register_gauge_with_registry, register_histogram_vec_with_registry,
+    register_histogram_with_registry, register_int_counter_vec_with_registry,
+    register_int_counter_with_registry, register_int_gauge_vec_with_registry,
+    register_int_gauge_with_registry, Histogram, HistogramVec, IntCounter, IntCounterVec,
+    IntGauge, IntGaugeVec, Registry,
 };
 use risingwave_object_store::object::object_metrics::ObjectStoreMetrics;
 use risingwave_pb::common::WorkerType;
@@ -59,6 +60,10 @@ pub struct MetaMetrics {
     pub all_barrier_nums: IntGauge,
     /// The number of in-flight barriers
     pub in_flight_barrier_nums: IntGauge,
+    /// The number of barriers scheduled so far, broken down by whether they carry a durable
+    /// checkpoint. Lets operators confirm `checkpoint_frequency` is taking effect and tune it
+    /// against the resulting object-store write amplification.
+    pub barrier_checkpoint_nums: IntCounterVec,
 
     /// ********************************** Recovery ************************************
     pub recovery_failure_cnt: IntCounter,
@@ -107,6 +112,9 @@ pub struct MetaMetrics {
     pub delta_log_count: IntGauge,
     /// latency of version checkpoint
     pub version_checkpoint_latency: Histogram,
+    /// Number of write operations batched into each hummock meta store transaction, e.g. a
+    /// commit-epoch's version delta plus its table stats update.
+    pub hummock_manager_txn_ops: Histogram,
     /// Latency for hummock manager to acquire lock
     pub hummock_manager_lock_time: HistogramVec,
     /// Latency for hummock manager to really process a request after acquire the lock
@@ -119,6 +127,9 @@ pub struct MetaMetrics {
     pub compact_level_compression_ratio: GenericGaugeVec<AtomicF64>,
     /// The number of compactor CPU need to be scale.
     pub scale_compactor_core_num: IntGauge,
+    /// Exponential moving average of `scale_compactor_core_num`, smoothed so that an external
+    /// autoscaler (e.g. K8s HPA/KEDA) polling this gauge isn't driven by every short-lived spike.
+    pub scale_compactor_core_num_smoothed: GenericGauge<AtomicF64>,
     /// Per level number of running compaction task
     pub level_compact_task_cnt: IntGaugeVec,
     pub time_after_last_observation: AtomicU64,
@@ -176,6 +187,13 @@ impl MetaMetrics {
             registry
         )
         .unwrap();
+        let barrier_checkpoint_nums = register_int_counter_vec_with_registry!(
+            "meta_barrier_checkpoint_nums",
+            "num of barriers scheduled so far, by whether they carry a durable checkpoint",
+            &["checkpoint"],
+            registry
+        )
+        .unwrap();
 
         let max_committed_epoch = register_int_gauge_with_registry!(
             "storage_max_committed_epoch",
@@ -330,6 +348,13 @@ impl MetaMetrics {
         );
         let version_checkpoint_latency = register_histogram_with_registry!(opts, registry).unwrap();
 
+        let opts = histogram_opts!(
+            "storage_hummock_manager_txn_ops",
+            "number of write operations batched into each hummock meta store transaction",
+            exponential_buckets(1.0, 2.0, 10).unwrap()
+        );
+        let hummock_manager_txn_ops = register_histogram_with_registry!(opts, registry).unwrap();
+
         let hummock_manager_lock_time = register_histogram_vec_with_registry!(
             "hummock_manager_lock_time",
             "latency for hummock manager to acquire the rwlock",
@@ -360,6 +385,13 @@ impl MetaMetrics {
         )
         .unwrap();
 
+        let scale_compactor_core_num_smoothed = register_gauge_with_registry!(
+            "storage_compactor_suggest_core_count_smoothed",
+            "exponential moving average of storage_compactor_suggest_core_count",
+            registry
+        )
+        .unwrap();
+
         let meta_type = register_int_gauge_vec_with_registry!(
             "meta_num",
             "role of meta nodes in the cluster",
@@ -422,6 +454,7 @@ impl MetaMetrics {
             barrier_send_latency,
             all_barrier_nums,
             in_flight_barrier_nums,
+            barrier_checkpoint_nums,
             recovery_failure_cnt,
             recovery_latency,
 
@@ -443,6 +476,7 @@ impl MetaMetrics {
             current_version_object_size,
             delta_log_count,
             version_checkpoint_latency,
+            hummock_manager_txn_ops,
             current_version_id,
             checkpoint_version_id,
             min_pinned_version_id,
@@ -455,6 +489,7 @@ impl MetaMetrics {
             compact_pending_bytes,
             compact_level_compression_ratio,
             scale_compactor_core_num,
+            scale_compactor_core_num_smoothed,
             level_compact_task_cnt,
             object_store_metric,
             source_is_up,
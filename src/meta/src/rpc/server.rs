@@ -71,7 +71,10 @@ use crate::rpc::service::telemetry_service::TelemetryInfoServiceImpl;
 use crate::rpc::service::user_service::UserServiceImpl;
 use crate::storage::{EtcdMetaStore, MemStore, MetaStore, WrappedEtcdClient as EtcdClient};
 use crate::stream::{GlobalStreamManager, SourceManager};
-use crate::telemetry::{MetaReportCreator, MetaTelemetryInfoFetcher, TrackingId};
+use crate::telemetry::{
+    new_cluster_snapshot_handle, start_cluster_snapshot_refresher, MetaReportCreator,
+    MetaTelemetryInfoFetcher, TrackingId,
+};
 use crate::{hummock, MetaResult};
 
 #[derive(Debug)]
@@ -81,6 +84,9 @@ pub enum MetaStoreBackend {
         credentials: Option<(String, String)>,
     },
     Mem,
+    Sql {
+        endpoint: String,
+    },
 }
 
 #[derive(Clone)]
@@ -171,6 +177,25 @@ pub async fn rpc_serve(
             )
             .await
         }
+        MetaStoreBackend::Sql { endpoint } => {
+            // No election client: the SQL backend is meant for single meta-node deployments
+            // that don't want to run an etcd cluster.
+            let meta_store = Arc::new(
+                crate::storage::SqlMetaStore::connect(&endpoint)
+                    .await
+                    .map_err(|e| anyhow::anyhow!("failed to connect meta store db {}", e))?,
+            );
+            rpc_serve_with_store(
+                meta_store,
+                None,
+                address_info,
+                max_heartbeat_interval,
+                lease_interval_secs,
+                opts,
+                init_system_params,
+            )
+            .await
+        }
     }
 }
 
@@ -433,6 +458,13 @@ pub async fn start_service_as_election_leader<S: MetaStore>(
         .unwrap(),
     );
 
+    {
+        let stream_manager = stream_manager.clone();
+        tokio::spawn(async move {
+            stream_manager.run().await.unwrap();
+        });
+    }
+
     hummock_manager
         .purge(
             &fragment_manager
@@ -509,7 +541,9 @@ pub async fn start_service_as_election_leader<S: MetaStore>(
     );
     let health_srv = HealthServiceImpl::new();
     let backup_srv = BackupServiceImpl::new(backup_manager);
-    let telemetry_srv = TelemetryInfoServiceImpl::new(meta_store.clone());
+    let telemetry_cluster_snapshot = new_cluster_snapshot_handle();
+    let telemetry_srv =
+        TelemetryInfoServiceImpl::new(meta_store.clone(), telemetry_cluster_snapshot.clone());
     let system_params_srv = SystemParamsServiceImpl::new(system_params_manager.clone());
 
     if let Some(prometheus_addr) = address_info.prometheus_addr {
@@ -544,6 +578,11 @@ pub async fn start_service_as_election_leader<S: MetaStore>(
     sub_tasks.push(SystemParamsManager::start_params_notifier(system_params_manager.clone()).await);
     sub_tasks.push(HummockManager::start_compaction_heartbeat(hummock_manager.clone()).await);
     sub_tasks.push(HummockManager::start_lsm_stat_report(hummock_manager).await);
+    sub_tasks.push(start_cluster_snapshot_refresher(
+        telemetry_cluster_snapshot.clone(),
+        cluster_manager.clone(),
+        catalog_manager.clone(),
+    ));
 
     if cfg!(not(test)) {
         sub_tasks.push(
@@ -571,7 +610,7 @@ pub async fn start_service_as_election_leader<S: MetaStore>(
     let mgr = TelemetryManager::new(
         local_system_params_manager.watch_params(),
         Arc::new(MetaTelemetryInfoFetcher::new(meta_store.clone())),
-        Arc::new(MetaReportCreator::new()),
+        Arc::new(MetaReportCreator::new(telemetry_cluster_snapshot)),
     );
 
     {
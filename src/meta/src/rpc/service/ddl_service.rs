@@ -583,6 +583,21 @@ where
         }))
     }
 
+    async fn comment_on(
+        &self,
+        request: Request<CommentOnRequest>,
+    ) -> Result<Response<CommentOnResponse>, Status> {
+        let req = request.into_inner();
+        let version = self
+            .ddl_controller
+            .run_command(DdlCommand::CommentOn(req.comment.unwrap()))
+            .await?;
+        Ok(Response::new(CommentOnResponse {
+            status: None,
+            version,
+        }))
+    }
+
     async fn get_ddl_progress(
         &self,
         _request: Request<GetDdlProgressRequest>,
@@ -428,6 +428,24 @@ where
         }))
     }
 
+    async fn rise_ctl_list_compact_tasks(
+        &self,
+        _request: Request<RiseCtlListCompactTasksRequest>,
+    ) -> Result<Response<RiseCtlListCompactTasksResponse>, Status> {
+        let task_assignments = self.hummock_manager.list_compact_task_assignments().await;
+        Ok(Response::new(RiseCtlListCompactTasksResponse {
+            task_assignments,
+        }))
+    }
+
+    async fn list_compact_task_progress(
+        &self,
+        _request: Request<ListCompactTaskProgressRequest>,
+    ) -> Result<Response<ListCompactTaskProgressResponse>, Status> {
+        let task_progress = self.hummock_manager.compactor_manager.list_task_progress();
+        Ok(Response::new(ListCompactTaskProgressResponse { task_progress }))
+    }
+
     async fn rise_ctl_list_compaction_group(
         &self,
         _request: Request<RiseCtlListCompactionGroupRequest>,
@@ -524,6 +542,38 @@ where
         let scale_out_cores = info.scale_out_cores();
         let mut resp: GetScaleCompactorResponse = info.into();
         resp.suggest_cores = scale_out_cores;
+        resp.smoothed_suggest_cores = self.hummock_manager.compactor_scale_out_cores_ema();
         Ok(Response::new(resp))
     }
+
+    async fn create_retained_snapshot(
+        &self,
+        request: Request<CreateRetainedSnapshotRequest>,
+    ) -> Result<Response<CreateRetainedSnapshotResponse>, Status> {
+        let req = request.into_inner();
+        let snapshot = self
+            .hummock_manager
+            .create_retained_snapshot(req.name)
+            .await?;
+        Ok(Response::new(CreateRetainedSnapshotResponse {
+            snapshot: Some(snapshot),
+        }))
+    }
+
+    async fn list_retained_snapshots(
+        &self,
+        _: Request<ListRetainedSnapshotsRequest>,
+    ) -> Result<Response<ListRetainedSnapshotsResponse>, Status> {
+        let snapshots = self.hummock_manager.list_retained_snapshots().await;
+        Ok(Response::new(ListRetainedSnapshotsResponse { snapshots }))
+    }
+
+    async fn drop_retained_snapshot(
+        &self,
+        request: Request<DropRetainedSnapshotRequest>,
+    ) -> Result<Response<DropRetainedSnapshotResponse>, Status> {
+        let req = request.into_inner();
+        self.hummock_manager.drop_retained_snapshot(req.id).await?;
+        Ok(Response::new(DropRetainedSnapshotResponse {}))
+    }
 }
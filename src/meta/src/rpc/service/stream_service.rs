@@ -85,10 +85,15 @@ where
         request: Request<CancelCreatingJobsRequest>,
     ) -> TonicResponse<CancelCreatingJobsResponse> {
         let req = request.into_inner();
-        let table_ids = self
-            .catalog_manager
-            .find_creating_streaming_job_ids(req.infos)
-            .await;
+        let table_ids = match req.jobs {
+            Some(cancel_creating_jobs_request::Jobs::Infos(infos)) => {
+                self.catalog_manager
+                    .find_creating_streaming_job_ids(infos.infos)
+                    .await
+            }
+            Some(cancel_creating_jobs_request::Jobs::Ids(ids)) => ids.job_ids,
+            None => vec![],
+        };
         if !table_ids.is_empty() {
             self.stream_manager
                 .cancel_streaming_jobs(table_ids.into_iter().map(TableId::from).collect_vec())
@@ -13,27 +13,42 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Instant;
 
+use arc_swap::ArcSwap;
 use risingwave_common::config::MetaBackend;
+use risingwave_common::telemetry::TelemetryReport;
 use risingwave_pb::meta::telemetry_info_service_server::TelemetryInfoService;
-use risingwave_pb::meta::{GetTelemetryInfoRequest, TelemetryInfoResponse};
+use risingwave_pb::meta::{
+    GetTelemetryInfoRequest, GetTelemetryReportPreviewRequest, GetTelemetryReportPreviewResponse,
+    TelemetryInfoResponse,
+};
 use tonic::{Request, Response, Status};
+use uuid::Uuid;
 
 use crate::storage::MetaStore;
-use crate::telemetry::TrackingId;
+use crate::telemetry::{ClusterSnapshot, MetaTelemetryReport, TrackingId};
 
 pub struct TelemetryInfoServiceImpl<S: MetaStore> {
     meta_store: Arc<S>,
+    cluster_snapshot: Arc<ArcSwap<ClusterSnapshot>>,
+    start_time: Instant,
 }
 
 impl<S: MetaStore> TelemetryInfoServiceImpl<S> {
-    pub fn new(meta_store: Arc<S>) -> Self {
-        Self { meta_store }
+    pub fn new(meta_store: Arc<S>, cluster_snapshot: Arc<ArcSwap<ClusterSnapshot>>) -> Self {
+        Self {
+            meta_store,
+            cluster_snapshot,
+            start_time: Instant::now(),
+        }
     }
 
     async fn get_tracking_id(&self) -> Option<TrackingId> {
         match self.meta_store.meta_store_type() {
-            MetaBackend::Etcd => TrackingId::from_meta_store(&self.meta_store).await.ok(),
+            MetaBackend::Etcd | MetaBackend::Sql => {
+                TrackingId::from_meta_store(&self.meta_store).await.ok()
+            }
             MetaBackend::Mem => None,
         }
     }
@@ -52,4 +67,30 @@ impl<S: MetaStore> TelemetryInfoService for TelemetryInfoServiceImpl<S> {
             None => Ok(Response::new(TelemetryInfoResponse { tracking_id: None })),
         }
     }
+
+    /// Builds the same report [`crate::telemetry::MetaReportCreator`] would upload next, but
+    /// returns it instead of posting it anywhere, so operators can inspect what telemetry would
+    /// contain before opting in via `telemetry.enabled`.
+    async fn get_telemetry_report_preview(
+        &self,
+        _request: Request<GetTelemetryReportPreviewRequest>,
+    ) -> Result<Response<GetTelemetryReportPreviewResponse>, Status> {
+        let tracking_id = self
+            .get_tracking_id()
+            .await
+            .map(String::from)
+            .unwrap_or_else(|| "preview".to_owned());
+        let report = MetaTelemetryReport::new(
+            tracking_id,
+            Uuid::new_v4().to_string(),
+            self.start_time.elapsed().as_secs(),
+            (**self.cluster_snapshot.load()).clone(),
+        );
+        let report_json = report
+            .to_json()
+            .map_err(|e| Status::internal(e.to_string()))?;
+        Ok(Response::new(GetTelemetryReportPreviewResponse {
+            report_json,
+        }))
+    }
 }
@@ -81,6 +81,12 @@ impl Transaction {
         (self.preconditions, self.operations)
     }
 
+    /// Number of write operations batched into this transaction. Used for observability of how
+    /// effective transaction batching is, e.g. in `HummockManager::commit_trx`.
+    pub fn num_operations(&self) -> usize {
+        self.operations.len()
+    }
+
     #[cfg(test)]
     pub fn get_operations(&self) -> &Vec<Operation> {
         &self.operations
@@ -18,14 +18,15 @@
     reason = "generated by crepe"
 )]
 
+use std::cell::Cell;
 use std::collections::{BTreeMap, HashMap, LinkedList};
 use std::num::NonZeroUsize;
 
 use either::Either;
 use enum_as_inner::EnumAsInner;
 use itertools::Itertools;
-use rand::seq::SliceRandom;
 use rand::thread_rng;
+use rand::Rng;
 use risingwave_common::bail;
 use risingwave_common::hash::{ParallelUnitId, ParallelUnitMapping};
 use risingwave_pb::common::{ActorInfo, ParallelUnit};
@@ -187,8 +188,14 @@ pub(super) struct Scheduler {
     /// The default hash mapping for hash-distributed fragments, if there's no requirement derived.
     default_hash_mapping: ParallelUnitMapping,
 
-    /// The default parallel unit for singleton fragments, if there's no requirement derived.
-    default_singleton_parallel_unit: ParallelUnitId,
+    /// The parallel units singleton fragments are assigned to, one at a time in round-robin
+    /// order, so that multiple singleton fragments in the same job (e.g. a singleton agg
+    /// feeding a singleton sink) don't all pile onto the same node.
+    singleton_parallel_units: Vec<ParallelUnitId>,
+
+    /// Cursor into `singleton_parallel_units`, advanced for every singleton fragment assigned a
+    /// default placement.
+    next_singleton_parallel_unit: Cell<usize>,
 }
 
 impl Scheduler {
@@ -247,12 +254,16 @@ impl Scheduler {
 
         // Build the default hash mapping uniformly.
         let default_hash_mapping = ParallelUnitMapping::build(&round_robin);
-        // Randomly choose a parallel unit as the default singleton parallel unit.
-        let default_singleton_parallel_unit = round_robin.choose(&mut thread_rng()).unwrap().id;
+        // Singleton fragments are assigned round-robin starting from a randomly chosen offset,
+        // so that singletons spread across nodes instead of all landing on the same one.
+        let mut singleton_parallel_units = round_robin.iter().map(|p| p.id).collect_vec();
+        let rotate_by = thread_rng().gen_range(0..singleton_parallel_units.len());
+        singleton_parallel_units.rotate_left(rotate_by);
 
         Ok(Self {
             default_hash_mapping,
-            default_singleton_parallel_unit,
+            singleton_parallel_units,
+            next_singleton_parallel_unit: Cell::new(0),
         })
     }
 
@@ -328,7 +339,10 @@ impl Scheduler {
 
                     // Default
                     Result::DefaultSingleton => {
-                        Distribution::Singleton(self.default_singleton_parallel_unit)
+                        let i = self.next_singleton_parallel_unit.get();
+                        self.next_singleton_parallel_unit
+                            .set((i + 1) % self.singleton_parallel_units.len());
+                        Distribution::Singleton(self.singleton_parallel_units[i])
                     }
                     Result::DefaultHash => Distribution::Hash(self.default_hash_mapping.clone()),
                 };
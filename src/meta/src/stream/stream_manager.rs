@@ -18,7 +18,10 @@ use std::sync::Arc;
 use futures::future::{try_join_all, BoxFuture};
 use itertools::Itertools;
 use risingwave_common::catalog::TableId;
+use risingwave_common::hash::ParallelUnitId;
 use risingwave_pb::catalog::Table;
+use risingwave_pb::common::WorkerNode;
+use risingwave_pb::meta::table_fragments::fragment::FragmentDistributionType;
 use risingwave_pb::stream_plan::update_mutation::MergeUpdate;
 use risingwave_pb::stream_plan::Dispatcher;
 use risingwave_pb::stream_service::{
@@ -31,10 +34,10 @@ use uuid::Uuid;
 use super::Locations;
 use crate::barrier::{BarrierScheduler, Command};
 use crate::hummock::HummockManagerRef;
-use crate::manager::{ClusterManagerRef, FragmentManagerRef, MetaSrvEnv};
-use crate::model::{ActorId, TableFragments};
+use crate::manager::{ClusterManagerRef, FragmentManagerRef, LocalNotification, MetaSrvEnv};
+use crate::model::{ActorId, FragmentId, TableFragments};
 use crate::storage::MetaStore;
-use crate::stream::SourceManagerRef;
+use crate::stream::{ParallelUnitReschedule, SourceManagerRef};
 use crate::{MetaError, MetaResult};
 
 pub type GlobalStreamManagerRef<S> = Arc<GlobalStreamManager<S>>;
@@ -522,6 +525,95 @@ where
     pub async fn cancel_streaming_jobs(&self, table_ids: Vec<TableId>) {
         self.creating_job_info.cancel_jobs(table_ids).await;
     }
+
+    /// Subscribes to cluster change notifications and, unless
+    /// `disable_automatic_parallelism_control` is set, automatically rebalances existing
+    /// streaming jobs whenever a new compute node joins, so that adding a node actually relieves
+    /// load on the existing ones instead of requiring an explicit `risectl` reschedule.
+    pub async fn run(self: Arc<Self>) -> MetaResult<()> {
+        let (local_notification_tx, mut local_notification_rx) =
+            tokio::sync::mpsc::unbounded_channel();
+        self.env
+            .notification_manager()
+            .insert_local_sender(local_notification_tx)
+            .await;
+        if self.env.opts.disable_automatic_parallelism_control {
+            tracing::info!("automatic parallelism control is disabled, worker join/rejoin will not trigger a rebalance");
+        }
+        while let Some(notification) = local_notification_rx.recv().await {
+            if let LocalNotification::WorkerNodeActivated(worker_node) = notification {
+                if self.env.opts.disable_automatic_parallelism_control {
+                    continue;
+                }
+                if let Err(e) = self.rebalance_onto(&worker_node).await {
+                    tracing::warn!(
+                        "failed to auto rebalance existing streaming jobs onto worker {}: {}",
+                        worker_node.id,
+                        e
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Expands every hash-distributed fragment that doesn't yet use any of `worker_node`'s
+    /// parallel units so that it does, spreading existing materialized views onto the node that
+    /// just joined. Never removes parallel units from a fragment in the process -- shrinking is
+    /// left to an explicit `risectl` reschedule, since it requires picking which node to vacate.
+    async fn rebalance_onto(&self, worker_node: &WorkerNode) -> MetaResult<()> {
+        let new_parallel_unit_ids: HashSet<ParallelUnitId> = worker_node
+            .parallel_units
+            .iter()
+            .map(|pu| pu.id as ParallelUnitId)
+            .collect();
+        if new_parallel_unit_ids.is_empty() {
+            return Ok(());
+        }
+
+        let mut reschedules = HashMap::new();
+        for table_fragments in self.fragment_manager.list_table_fragments().await? {
+            for fragment in table_fragments.fragments() {
+                if fragment.distribution_type() != FragmentDistributionType::Hash {
+                    continue;
+                }
+                let used_parallel_unit_ids: HashSet<ParallelUnitId> = fragment
+                    .actors
+                    .iter()
+                    .map(|actor| {
+                        table_fragments.actor_status[&actor.actor_id]
+                            .get_parallel_unit()
+                            .unwrap()
+                            .id as ParallelUnitId
+                    })
+                    .collect();
+                let added_parallel_units = new_parallel_unit_ids
+                    .difference(&used_parallel_unit_ids)
+                    .cloned()
+                    .collect_vec();
+                if !added_parallel_units.is_empty() {
+                    reschedules.insert(
+                        fragment.fragment_id as FragmentId,
+                        ParallelUnitReschedule {
+                            added_parallel_units,
+                            removed_parallel_units: vec![],
+                        },
+                    );
+                }
+            }
+        }
+
+        if reschedules.is_empty() {
+            return Ok(());
+        }
+
+        tracing::info!(
+            "auto rebalancing {} fragment(s) onto newly joined worker {}",
+            reschedules.len(),
+            worker_node.id
+        );
+        self.reschedule_actors(reschedules).await
+    }
 }
 
 #[cfg(test)]
@@ -533,7 +625,7 @@ mod tests {
 
     use risingwave_common::catalog::TableId;
     use risingwave_common::hash::ParallelUnitMapping;
-    use risingwave_pb::common::{HostAddress, WorkerType};
+    use risingwave_pb::common::{HostAddress, WorkerNode, WorkerType};
     use risingwave_pb::meta::table_fragments::fragment::FragmentDistributionType;
     use risingwave_pb::meta::table_fragments::Fragment;
     use risingwave_pb::stream_plan::stream_node::NodeBody;
@@ -923,6 +1015,66 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_rebalance_onto_skips_worker_without_parallel_units() -> MetaResult<()> {
+        let services = MockServices::start("127.0.0.1", 12336, false).await?;
+
+        let empty_worker = WorkerNode::default();
+        services
+            .global_stream_manager
+            .rebalance_onto(&empty_worker)
+            .await?;
+
+        services.stop().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_rebalance_onto_skips_worker_already_fully_covered() -> MetaResult<()> {
+        let services = MockServices::start("127.0.0.1", 12337, false).await?;
+
+        let table_id = TableId::new(0);
+        let actors = make_mview_stream_actors(&table_id, 4);
+        let mut fragments = BTreeMap::default();
+        fragments.insert(
+            0,
+            Fragment {
+                fragment_id: 0,
+                fragment_type_mask: FragmentTypeFlag::Mview as u32,
+                distribution_type: FragmentDistributionType::Hash as i32,
+                actors: actors.clone(),
+                state_table_ids: vec![0],
+                vnode_mapping: Some(ParallelUnitMapping::new_single(0).to_protobuf()),
+                ..Default::default()
+            },
+        );
+        services
+            .create_materialized_view(table_id, fragments)
+            .await?;
+
+        // All actors are already scheduled onto parallel unit 0, so rebalancing the worker that
+        // owns it should be a no-op: it must not trigger an actual reschedule.
+        let StreamingClusterInfo { worker_nodes, .. } = services
+            .global_stream_manager
+            .cluster_manager
+            .get_streaming_cluster_info()
+            .await;
+        let worker = worker_nodes.values().next().unwrap().clone();
+        services
+            .global_stream_manager
+            .rebalance_onto(&worker)
+            .await?;
+
+        let actor_ids = services
+            .fragment_manager
+            .get_table_actor_ids(&HashSet::from([table_id]))
+            .await?;
+        assert_eq!(actor_ids, (0..=3).collect::<Vec<u32>>());
+
+        services.stop().await;
+        Ok(())
+    }
+
     #[tokio::test]
     #[cfg(all(test, feature = "failpoints"))]
     async fn test_failpoints_drop_mv_recovery() {
@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use anyhow::anyhow;
+use arc_swap::ArcSwap;
 use risingwave_common::telemetry::report::{TelemetryInfoFetcher, TelemetryReportCreator};
 use risingwave_common::telemetry::{
     current_timestamp, SystemData, TelemetryNodeType, TelemetryReport, TelemetryReportBase,
@@ -22,9 +24,88 @@ use risingwave_common::telemetry::{
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::manager::{CatalogManagerRef, ClusterManagerRef};
 use crate::model::{MetadataModelError, MetadataModelResult};
 use crate::storage::{MetaStore, Snapshot};
 
+/// The properties key under which a source's connector type (e.g. `kafka`, `datagen`) is stored.
+/// Mirrors the `UPSTREAM_SOURCE_KEY` constant used by the SQL frontend and DDL service.
+const CONNECTOR_PROPERTY_KEY: &str = "connector";
+
+/// A lightweight, periodically refreshed snapshot of cluster composition used to enrich
+/// telemetry reports with anonymized feature-usage data. Kept separate from the reports
+/// themselves because [`TelemetryReportCreator::create_report`] is synchronous and must not block
+/// on meta store or catalog RPCs.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub(crate) struct ClusterSnapshot {
+    /// Number of live worker nodes, keyed by worker type (e.g. `"COMPUTE_NODE"`).
+    worker_node_count: HashMap<String, u64>,
+    /// Number of sources using each connector type (e.g. `"kafka"`). Connector names only, no
+    /// source names, table names, or connection details.
+    source_connector_count: HashMap<String, u64>,
+}
+
+impl ClusterSnapshot {
+    pub(crate) async fn collect<S: MetaStore>(
+        cluster_manager: &ClusterManagerRef<S>,
+        catalog_manager: &CatalogManagerRef<S>,
+    ) -> Self {
+        let worker_node_count = cluster_manager
+            .count_worker_node()
+            .await
+            .into_iter()
+            .map(|(worker_type, count)| (worker_type.as_str_name().to_owned(), count))
+            .collect();
+
+        let mut source_connector_count = HashMap::new();
+        for source in catalog_manager.list_sources().await {
+            if let Some(connector) = source.properties.get(CONNECTOR_PROPERTY_KEY) {
+                *source_connector_count
+                    .entry(connector.to_lowercase())
+                    .or_insert(0) += 1;
+            }
+        }
+
+        Self {
+            worker_node_count,
+            source_connector_count,
+        }
+    }
+}
+
+/// Creates a handle to a [`ClusterSnapshot`] that's shared between [`MetaReportCreator`] and the
+/// background refresher started by [`start_cluster_snapshot_refresher`].
+pub(crate) fn new_cluster_snapshot_handle() -> Arc<ArcSwap<ClusterSnapshot>> {
+    Arc::new(ArcSwap::from_pointee(ClusterSnapshot::default()))
+}
+
+/// Periodically refreshes `handle` with the latest [`ClusterSnapshot`], so telemetry reports
+/// reflect roughly up-to-date cluster composition without making report creation
+/// ([`TelemetryReportCreator::create_report`] is synchronous) block on meta store or catalog RPCs.
+pub(crate) fn start_cluster_snapshot_refresher<S: MetaStore>(
+    handle: Arc<ArcSwap<ClusterSnapshot>>,
+    cluster_manager: ClusterManagerRef<S>,
+    catalog_manager: CatalogManagerRef<S>,
+) -> (tokio::task::JoinHandle<()>, tokio::sync::oneshot::Sender<()>) {
+    let (shutdown_tx, mut shutdown_rx) = tokio::sync::oneshot::channel();
+    let join_handle = tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(10 * 60));
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {},
+                _ = &mut shutdown_rx => {
+                    tracing::info!("Telemetry cluster snapshot refresher is stopped");
+                    return;
+                }
+            }
+            let new_snapshot = ClusterSnapshot::collect(&cluster_manager, &catalog_manager).await;
+            handle.store(Arc::new(new_snapshot));
+        }
+    });
+    (join_handle, shutdown_tx)
+}
+
 /// Column in meta store
 pub const TELEMETRY_CF: &str = "cf/telemetry";
 /// `telemetry` in bytes
@@ -102,10 +183,17 @@ impl From<String> for TrackingId {
 pub(crate) struct MetaTelemetryReport {
     #[serde(flatten)]
     base: TelemetryReportBase,
+    #[serde(flatten)]
+    cluster_snapshot: ClusterSnapshot,
 }
 
 impl MetaTelemetryReport {
-    pub(crate) fn new(tracking_id: String, session_id: String, up_time: u64) -> Self {
+    pub(crate) fn new(
+        tracking_id: String,
+        session_id: String,
+        up_time: u64,
+        cluster_snapshot: ClusterSnapshot,
+    ) -> Self {
         Self {
             base: TelemetryReportBase {
                 tracking_id,
@@ -115,6 +203,7 @@ impl MetaTelemetryReport {
                 time_stamp: current_timestamp(),
                 node_type: TelemetryNodeType::Meta,
             },
+            cluster_snapshot,
         }
     }
 }
@@ -145,12 +234,14 @@ impl<S: MetaStore> TelemetryInfoFetcher for MetaTelemetryInfoFetcher<S> {
     }
 }
 
-#[derive(Copy, Clone)]
-pub(crate) struct MetaReportCreator {}
+#[derive(Clone)]
+pub(crate) struct MetaReportCreator {
+    cluster_snapshot: Arc<ArcSwap<ClusterSnapshot>>,
+}
 
 impl MetaReportCreator {
-    pub(crate) fn new() -> Self {
-        Self {}
+    pub(crate) fn new(cluster_snapshot: Arc<ArcSwap<ClusterSnapshot>>) -> Self {
+        Self { cluster_snapshot }
     }
 }
 
@@ -161,7 +252,12 @@ impl TelemetryReportCreator for MetaReportCreator {
         session_id: String,
         up_time: u64,
     ) -> anyhow::Result<MetaTelemetryReport> {
-        Ok(MetaTelemetryReport::new(tracking_id, session_id, up_time))
+        Ok(MetaTelemetryReport::new(
+            tracking_id,
+            session_id,
+            up_time,
+            (**self.cluster_snapshot.load()).clone(),
+        ))
     }
 
     fn report_type(&self) -> &str {
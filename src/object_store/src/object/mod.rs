@@ -12,11 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::iter::{Map, Take};
 use std::sync::Arc;
+use std::time::Duration;
 
 use bytes::Bytes;
 use prometheus::HistogramTimer;
 use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio_retry::strategy::{jitter, ExponentialBackoff};
 
 pub mod mem;
 pub use mem::*;
@@ -568,6 +571,10 @@ impl Drop for MonitoredStreamingReader {
     }
 }
 
+const RETRY_BASE_INTERVAL_MS: u64 = 20;
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(10);
+const RETRY_MAX_ATTEMPTS: usize = 4;
+
 pub struct MonitoredObjectStore<OS: ObjectStore> {
     inner: OS,
     object_store_metrics: Arc<ObjectStoreMetrics>,
@@ -601,6 +608,17 @@ impl<OS: ObjectStore> MonitoredObjectStore<OS> {
         self.inner.store_media_type()
     }
 
+    /// Bounded exponential-backoff retry strategy used for idempotent (read-only) operations, to
+    /// ride out transient object store errors (e.g. S3 throttling) instead of failing the whole
+    /// compaction task or read request on the first blip.
+    #[inline(always)]
+    fn retry_strategy() -> Map<Take<ExponentialBackoff>, fn(Duration) -> Duration> {
+        ExponentialBackoff::from_millis(RETRY_BASE_INTERVAL_MS)
+            .max_delay(RETRY_MAX_DELAY)
+            .take(RETRY_MAX_ATTEMPTS)
+            .map(jitter)
+    }
+
     pub async fn upload(&self, path: &str, obj: Bytes) -> ObjectResult<()> {
         let operation_type = "upload";
         self.object_store_metrics
@@ -653,17 +671,18 @@ impl<OS: ObjectStore> MonitoredObjectStore<OS> {
             .with_label_values(&[self.media_type(), operation_type])
             .start_timer();
 
-        let res = self
-            .inner
-            .read(path, block_loc)
-            .verbose_instrument_await("object_store_read")
-            .await
-            .map_err(|err| {
-                ObjectError::internal(format!(
-                    "read {:?} in block {:?} failed, error: {:?}",
-                    path, block_loc, err
-                ))
-            });
+        let res = tokio_retry::Retry::spawn(Self::retry_strategy(), || {
+            self.inner
+                .read(path, block_loc)
+                .verbose_instrument_await("object_store_read")
+        })
+        .await
+        .map_err(|err| {
+            ObjectError::internal(format!(
+                "read {:?} in block {:?} failed, error: {:?}",
+                path, block_loc, err
+            ))
+        });
 
         try_update_failure_metric(&self.object_store_metrics, &res, operation_type);
 
@@ -690,11 +709,12 @@ impl<OS: ObjectStore> MonitoredObjectStore<OS> {
             .with_label_values(&[self.media_type(), operation_type])
             .start_timer();
 
-        let res = self
-            .inner
-            .readv(path, block_locs)
-            .verbose_instrument_await("object_store_readv")
-            .await;
+        let res = tokio_retry::Retry::spawn(Self::retry_strategy(), || {
+            self.inner
+                .readv(path, block_locs)
+                .verbose_instrument_await("object_store_readv")
+        })
+        .await;
 
         try_update_failure_metric(&self.object_store_metrics, &res, operation_type);
 
@@ -740,11 +760,12 @@ impl<OS: ObjectStore> MonitoredObjectStore<OS> {
             .with_label_values(&[self.media_type(), operation_type])
             .start_timer();
 
-        let ret = self
-            .inner
-            .metadata(path)
-            .verbose_instrument_await("object_store_metadata")
-            .await;
+        let ret = tokio_retry::Retry::spawn(Self::retry_strategy(), || {
+            self.inner
+                .metadata(path)
+                .verbose_instrument_await("object_store_metadata")
+        })
+        .await;
 
         try_update_failure_metric(&self.object_store_metrics, &ret, operation_type);
         ret
@@ -20,7 +20,7 @@ use risingwave_hummock_sdk::{
 };
 use risingwave_pb::hummock::{
     CompactTask, CompactTaskProgress, CompactorWorkload, HummockSnapshot, HummockVersion,
-    VacuumTask,
+    KeyRange, VacuumTask,
 };
 
 use crate::error::Result;
@@ -66,6 +66,8 @@ pub trait HummockMetaClient: Send + Sync + 'static {
         compaction_group_id: u64,
         table_id: u32,
         level: u32,
+        sst_ids: Vec<u64>,
+        key_range: Option<KeyRange>,
     ) -> Result<()>;
     async fn report_full_scan_task(&self, object_ids: Vec<HummockSstableObjectId>) -> Result<()>;
     async fn trigger_full_gc(&self, sst_retention_time_sec: u64) -> Result<()>;
@@ -41,7 +41,8 @@ use risingwave_hummock_sdk::{
 use risingwave_pb::backup_service::backup_service_client::BackupServiceClient;
 use risingwave_pb::backup_service::*;
 use risingwave_pb::catalog::{
-    Connection, PbDatabase, PbFunction, PbIndex, PbSchema, PbSink, PbSource, PbTable, PbView,
+    Connection, PbComment, PbDatabase, PbFunction, PbIndex, PbSchema, PbSink, PbSource, PbTable,
+    PbView,
 };
 use risingwave_pb::common::{HostAddress, WorkerType};
 use risingwave_pb::ddl_service::alter_relation_name_request::Relation;
@@ -51,6 +52,7 @@ use risingwave_pb::ddl_service::*;
 use risingwave_pb::hummock::hummock_manager_service_client::HummockManagerServiceClient;
 use risingwave_pb::hummock::rise_ctl_update_compaction_config_request::mutable_config::MutableConfig;
 use risingwave_pb::hummock::*;
+use risingwave_pb::meta::cancel_creating_jobs_request;
 use risingwave_pb::meta::cluster_service_client::ClusterServiceClient;
 use risingwave_pb::meta::heartbeat_request::{extra_info, ExtraInfo};
 use risingwave_pb::meta::heartbeat_service_client::HeartbeatServiceClient;
@@ -390,6 +392,14 @@ impl MetaClient {
         Ok(resp.version)
     }
 
+    pub async fn comment_on(&self, comment: PbComment) -> Result<CatalogVersion> {
+        let request = CommentOnRequest {
+            comment: Some(comment),
+        };
+        let resp = self.inner.comment_on(request).await?;
+        Ok(resp.version)
+    }
+
     pub async fn replace_table(
         &self,
         table: PbTable,
@@ -641,7 +651,23 @@ impl MetaClient {
     }
 
     pub async fn cancel_creating_jobs(&self, infos: Vec<CreatingJobInfo>) -> Result<()> {
-        let request = CancelCreatingJobsRequest { infos };
+        let request = CancelCreatingJobsRequest {
+            jobs: Some(cancel_creating_jobs_request::Jobs::Infos(
+                cancel_creating_jobs_request::CreatingJobInfos { infos },
+            )),
+        };
+        let _ = self.inner.cancel_creating_jobs(request).await?;
+        Ok(())
+    }
+
+    /// Cancel creating jobs directly by the job id shown in `rw_ddl_progress`, for the
+    /// `CANCEL JOBS` statement.
+    pub async fn cancel_creating_jobs_by_ids(&self, job_ids: Vec<u32>) -> Result<()> {
+        let request = CancelCreatingJobsRequest {
+            jobs: Some(cancel_creating_jobs_request::Jobs::Ids(
+                cancel_creating_jobs_request::CreatingJobIds { job_ids },
+            )),
+        };
         let _ = self.inner.cancel_creating_jobs(request).await?;
         Ok(())
     }
@@ -690,6 +716,25 @@ impl MetaClient {
             .await
     }
 
+    pub async fn risectl_list_compact_tasks(&self) -> Result<Vec<CompactTaskAssignment>> {
+        let request = RiseCtlListCompactTasksRequest {};
+        let resp = self.inner.rise_ctl_list_compact_tasks(request).await?;
+        Ok(resp.task_assignments)
+    }
+
+    pub async fn list_compact_task_progress(&self) -> Result<Vec<CompactTaskProgress>> {
+        let request = ListCompactTaskProgressRequest {};
+        let resp = self.inner.list_compact_task_progress(request).await?;
+        Ok(resp.task_progress)
+    }
+
+    /// Returns the outstanding compaction debt, for an external autoscaler deciding whether to
+    /// scale the compactor deployment in or out.
+    pub async fn get_scale_compactor(&self) -> Result<GetScaleCompactorResponse> {
+        let request = GetScaleCompactorRequest {};
+        self.inner.get_scale_compactor(request).await
+    }
+
     pub async fn risectl_get_pinned_snapshots_summary(
         &self,
     ) -> Result<RiseCtlGetPinnedSnapshotsSummaryResponse> {
@@ -847,6 +892,13 @@ impl MetaClient {
         Ok(resp)
     }
 
+    /// Fetches the telemetry report that would be uploaded next, without sending it anywhere.
+    pub async fn get_telemetry_report_preview(&self) -> Result<String> {
+        let req = GetTelemetryReportPreviewRequest {};
+        let resp = self.inner.get_telemetry_report_preview(req).await?;
+        Ok(resp.report_json)
+    }
+
     pub async fn get_system_params(&self) -> Result<SystemParamsReader> {
         let req = GetSystemParamsRequest {};
         let resp = self.inner.get_system_params(req).await?;
@@ -877,6 +929,24 @@ impl MetaClient {
         let resp = self.inner.split_compaction_group(req).await?;
         Ok(resp.new_group_id)
     }
+
+    pub async fn create_retained_snapshot(&self, name: String) -> Result<HummockRetainedSnapshot> {
+        let req = CreateRetainedSnapshotRequest { name };
+        let resp = self.inner.create_retained_snapshot(req).await?;
+        Ok(resp.snapshot.unwrap())
+    }
+
+    pub async fn list_retained_snapshots(&self) -> Result<Vec<HummockRetainedSnapshot>> {
+        let req = ListRetainedSnapshotsRequest {};
+        let resp = self.inner.list_retained_snapshots(req).await?;
+        Ok(resp.snapshots)
+    }
+
+    pub async fn drop_retained_snapshot(&self, id: u64) -> Result<()> {
+        let req = DropRetainedSnapshotRequest { id };
+        self.inner.drop_retained_snapshot(req).await?;
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -1016,15 +1086,17 @@ impl HummockMetaClient for MetaClient {
         compaction_group_id: u64,
         table_id: u32,
         level: u32,
+        sst_ids: Vec<u64>,
+        key_range: Option<KeyRange>,
     ) -> Result<()> {
-        // TODO: support key_range parameter
         let req = TriggerManualCompactionRequest {
             compaction_group_id,
             table_id,
             // if table_id not exist, manual_compaction will include all the sst
             // without check internal_table_id
             level,
-            ..Default::default()
+            sst_ids,
+            key_range,
         };
 
         self.inner.trigger_manual_compaction(req).await?;
@@ -1429,6 +1501,7 @@ macro_rules! for_all_meta_rpc {
             ,{ stream_client, list_table_fragments, ListTableFragmentsRequest, ListTableFragmentsResponse }
             ,{ ddl_client, create_table, CreateTableRequest, CreateTableResponse }
              ,{ ddl_client, alter_relation_name, AlterRelationNameRequest, AlterRelationNameResponse }
+            ,{ ddl_client, comment_on, CommentOnRequest, CommentOnResponse }
             ,{ ddl_client, create_materialized_view, CreateMaterializedViewRequest, CreateMaterializedViewResponse }
             ,{ ddl_client, create_view, CreateViewRequest, CreateViewResponse }
             ,{ ddl_client, create_source, CreateSourceRequest, CreateSourceResponse }
@@ -1473,12 +1546,18 @@ macro_rules! for_all_meta_rpc {
             ,{ hummock_client, report_full_scan_task, ReportFullScanTaskRequest, ReportFullScanTaskResponse }
             ,{ hummock_client, trigger_full_gc, TriggerFullGcRequest, TriggerFullGcResponse }
             ,{ hummock_client, rise_ctl_get_pinned_versions_summary, RiseCtlGetPinnedVersionsSummaryRequest, RiseCtlGetPinnedVersionsSummaryResponse }
+            ,{ hummock_client, rise_ctl_list_compact_tasks, RiseCtlListCompactTasksRequest, RiseCtlListCompactTasksResponse }
+            ,{ hummock_client, list_compact_task_progress, ListCompactTaskProgressRequest, ListCompactTaskProgressResponse }
             ,{ hummock_client, rise_ctl_get_pinned_snapshots_summary, RiseCtlGetPinnedSnapshotsSummaryRequest, RiseCtlGetPinnedSnapshotsSummaryResponse }
+            ,{ hummock_client, get_scale_compactor, GetScaleCompactorRequest, GetScaleCompactorResponse }
             ,{ hummock_client, rise_ctl_list_compaction_group, RiseCtlListCompactionGroupRequest, RiseCtlListCompactionGroupResponse }
             ,{ hummock_client, rise_ctl_update_compaction_config, RiseCtlUpdateCompactionConfigRequest, RiseCtlUpdateCompactionConfigResponse }
             ,{ hummock_client, init_metadata_for_replay, InitMetadataForReplayRequest, InitMetadataForReplayResponse }
             ,{ hummock_client, set_compactor_runtime_config, SetCompactorRuntimeConfigRequest, SetCompactorRuntimeConfigResponse }
             ,{ hummock_client, split_compaction_group, SplitCompactionGroupRequest, SplitCompactionGroupResponse }
+            ,{ hummock_client, create_retained_snapshot, CreateRetainedSnapshotRequest, CreateRetainedSnapshotResponse }
+            ,{ hummock_client, list_retained_snapshots, ListRetainedSnapshotsRequest, ListRetainedSnapshotsResponse }
+            ,{ hummock_client, drop_retained_snapshot, DropRetainedSnapshotRequest, DropRetainedSnapshotResponse }
             ,{ user_client, create_user, CreateUserRequest, CreateUserResponse }
             ,{ user_client, update_user, UpdateUserRequest, UpdateUserResponse }
             ,{ user_client, drop_user, DropUserRequest, DropUserResponse }
@@ -1494,6 +1573,7 @@ macro_rules! for_all_meta_rpc {
             ,{ backup_client, delete_meta_snapshot, DeleteMetaSnapshotRequest, DeleteMetaSnapshotResponse}
             ,{ backup_client, get_meta_snapshot_manifest, GetMetaSnapshotManifestRequest, GetMetaSnapshotManifestResponse}
             ,{ telemetry_client, get_telemetry_info, GetTelemetryInfoRequest, TelemetryInfoResponse}
+            ,{ telemetry_client, get_telemetry_report_preview, GetTelemetryReportPreviewRequest, GetTelemetryReportPreviewResponse}
             ,{ system_params_client, get_system_params, GetSystemParamsRequest, GetSystemParamsResponse }
             ,{ system_params_client, set_system_param, SetSystemParamRequest, SetSystemParamResponse }
         }
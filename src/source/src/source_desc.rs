@@ -193,6 +193,7 @@ pub mod test_utils {
                         field_descs: vec![],
                         type_name: "".to_string(),
                         generated_column: None,
+                        default_column: None,
                     }
                     .to_protobuf(),
                 ),
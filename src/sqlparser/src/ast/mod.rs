@@ -37,9 +37,9 @@ pub use self::ddl::{
 };
 pub use self::operator::{BinaryOperator, UnaryOperator};
 pub use self::query::{
-    Cte, Distinct, Fetch, Join, JoinConstraint, JoinOperator, LateralView, OrderByExpr, Query,
-    Select, SelectItem, SetExpr, SetOperator, TableAlias, TableFactor, TableWithJoins, Top, Values,
-    With,
+    AsOf, Cte, Distinct, Fetch, Join, JoinConstraint, JoinOperator, LateralView, OrderByExpr,
+    Query, Select, SelectItem, SetExpr, SetOperator, TableAlias, TableFactor, TableWithJoins, Top,
+    Values, With,
 };
 pub use self::statement::*;
 pub use self::value::{DateTimeField, DollarQuotedString, TrimWhereField, Value};
@@ -760,6 +760,7 @@ pub enum ShowObject {
     Sink { schema: Option<Ident> },
     Columns { table: ObjectName },
     Connection,
+    Jobs,
 }
 
 impl fmt::Display for ShowObject {
@@ -793,6 +794,7 @@ impl fmt::Display for ShowObject {
             ShowObject::Connection => f.write_str("CONNECTIONS"), /* TODO: format schema after
                                                                    * adding database_id and
                                                                    * schema_id */
+            ShowObject::Jobs => f.write_str("JOBS"),
         }
     }
 }
@@ -828,6 +830,7 @@ impl fmt::Display for ShowCreateType {
 pub enum CommentObject {
     Column,
     Table,
+    MaterializedView,
 }
 
 impl fmt::Display for CommentObject {
@@ -835,6 +838,7 @@ impl fmt::Display for CommentObject {
         match self {
             CommentObject::Column => f.write_str("COLUMN"),
             CommentObject::Table => f.write_str("TABLE"),
+            CommentObject::MaterializedView => f.write_str("MATERIALIZED VIEW"),
         }
     }
 }
@@ -845,6 +849,10 @@ pub enum ExplainType {
     Logical,
     Physical,
     DistSql,
+    /// Emit the distributed stream fragment graph as Graphviz DOT.
+    Dot,
+    /// Emit the plan as a machine-readable JSON document.
+    Json,
 }
 
 impl fmt::Display for ExplainType {
@@ -853,6 +861,8 @@ impl fmt::Display for ExplainType {
             ExplainType::Logical => f.write_str("Logical"),
             ExplainType::Physical => f.write_str("Physical"),
             ExplainType::DistSql => f.write_str("DistSQL"),
+            ExplainType::Dot => f.write_str("DOT"),
+            ExplainType::Json => f.write_str("JSON"),
         }
     }
 }
@@ -916,6 +926,8 @@ pub enum Statement {
         columns: Vec<Ident>,
         /// A SQL query that specifies what to insert
         source: Box<Query>,
+        /// ON CONFLICT
+        on_conflict: Option<OnConflict>,
         /// Define output of this insert statement
         returning: Vec<SelectItem>,
     },
@@ -951,6 +963,8 @@ pub enum Statement {
     CreateView {
         or_replace: bool,
         materialized: bool,
+        /// The view is only visible within the current session and is dropped at its end.
+        temporary: bool,
         /// View name
         name: ObjectName,
         columns: Vec<Ident>,
@@ -975,6 +989,10 @@ pub enum Statement {
         source_watermarks: Vec<SourceWatermark>,
         /// Append only table.
         append_only: bool,
+        /// `ON CONFLICT`, controlling how the table handles primary key conflicts. Defaults to
+        /// `DO UPDATE` (i.e. overwrite) for tables with a primary key, and is not allowed on
+        /// append-only tables since they have none.
+        on_conflict: Option<OnConflict>,
         /// `AS ( query )`
         query: Option<Box<Query>>,
     },
@@ -1007,6 +1025,17 @@ pub enum Statement {
         /// Optional parameters.
         params: CreateFunctionBody,
     },
+    /// CREATE AGGREGATE
+    ///
+    /// Postgres: https://www.postgresql.org/docs/15/sql-createaggregate.html
+    CreateAggregate {
+        or_replace: bool,
+        name: ObjectName,
+        args: Option<Vec<OperateFunctionArg>>,
+        returns: DataType,
+        /// Optional parameters.
+        params: CreateFunctionBody,
+    },
     /// ALTER TABLE
     AlterTable {
         /// Table name
@@ -1170,6 +1199,31 @@ pub enum Statement {
     ///
     /// Note: RisingWave specific statement.
     Flush,
+    /// CANCEL JOBS job_id [, job_id]*
+    ///
+    /// Cancel the given (comma separated) DDL jobs, as listed by `SHOW JOBS`.
+    ///
+    /// Note: RisingWave specific statement.
+    CancelJobs(Vec<u32>),
+    /// `DECLARE name CURSOR FOR query`
+    ///
+    /// Note: this is a PostgreSQL-specific statement.
+    DeclareCursor {
+        cursor_name: Ident,
+        query: Box<Query>,
+    },
+    /// `FETCH [ count | NEXT | ALL ] FROM name`
+    ///
+    /// Note: this is a PostgreSQL-specific statement.
+    FetchCursor {
+        cursor_name: Ident,
+        /// Number of rows to fetch. `None` means `FETCH NEXT`, i.e. a single row.
+        count: Option<u64>,
+    },
+    /// `CLOSE { name | ALL }`
+    ///
+    /// Note: this is a PostgreSQL-specific statement.
+    CloseCursor { cursor_name: Option<Ident> },
 }
 
 impl fmt::Display for Statement {
@@ -1217,6 +1271,7 @@ impl fmt::Display for Statement {
                 table_name,
                 columns,
                 source,
+                on_conflict,
                 returning,
             } => {
                 write!(f, "INSERT INTO {table_name} ", table_name = table_name,)?;
@@ -1224,6 +1279,9 @@ impl fmt::Display for Statement {
                     write!(f, "({}) ", display_comma_separated(columns))?;
                 }
                 write!(f, "{}", source)?;
+                if let Some(on_conflict) = on_conflict {
+                    write!(f, " {}", on_conflict)?;
+                }
                 if !returning.is_empty() {
                     write!(f, " RETURNING ({})", display_comma_separated(returning))?;
                 }
@@ -1320,19 +1378,40 @@ impl fmt::Display for Statement {
                 write!(f, "{params}")?;
                 Ok(())
             }
+            Statement::CreateAggregate {
+                or_replace,
+                name,
+                args,
+                returns,
+                params,
+            } => {
+                write!(
+                    f,
+                    "CREATE {or_replace}AGGREGATE {name}",
+                    or_replace = if *or_replace { "OR REPLACE " } else { "" },
+                )?;
+                if let Some(args) = args {
+                    write!(f, "({})", display_comma_separated(args))?;
+                }
+                write!(f, " RETURNS {returns}")?;
+                write!(f, "{params}")?;
+                Ok(())
+            }
             Statement::CreateView {
                 name,
                 or_replace,
                 columns,
                 query,
                 materialized,
+                temporary,
                 with_options,
                 emit_mode,
             } => {
                 write!(
                     f,
-                    "CREATE {or_replace}{materialized}VIEW {name}",
+                    "CREATE {or_replace}{temporary}{materialized}VIEW {name}",
                     or_replace = if *or_replace { "OR REPLACE " } else { "" },
+                    temporary = if *temporary { "TEMPORARY " } else { "" },
                     materialized = if *materialized { "MATERIALIZED " } else { "" },
                     name = name
                 )?;
@@ -1358,6 +1437,7 @@ impl fmt::Display for Statement {
                 source_schema,
                 source_watermarks,
                 append_only,
+                on_conflict,
                 query,
             } => {
                 // We want to allow the following options
@@ -1384,6 +1464,9 @@ impl fmt::Display for Statement {
                 if *append_only {
                     write!(f, " APPEND ONLY")?;
                 }
+                if let Some(on_conflict) = on_conflict {
+                    write!(f, " {}", on_conflict)?;
+                }
                 if !with_options.is_empty() {
                     write!(f, " WITH ({})", display_comma_separated(with_options))?;
                 }
@@ -1634,6 +1717,27 @@ impl fmt::Display for Statement {
             Statement::Flush => {
                 write!(f, "FLUSH")
             }
+            Statement::CancelJobs(job_ids) => {
+                write!(f, "CANCEL JOBS {}", display_comma_separated(job_ids))
+            }
+            Statement::DeclareCursor { cursor_name, query } => {
+                write!(f, "DECLARE {} CURSOR FOR {}", cursor_name, query)
+            }
+            Statement::FetchCursor { cursor_name, count } => {
+                write!(f, "FETCH ")?;
+                match count {
+                    Some(count) => write!(f, "{}", count)?,
+                    None => write!(f, "NEXT")?,
+                }
+                write!(f, " FROM {}", cursor_name)
+            }
+            Statement::CloseCursor { cursor_name } => {
+                write!(f, "CLOSE ")?;
+                match cursor_name {
+                    Some(name) => write!(f, "{}", name),
+                    None => write!(f, "ALL"),
+                }
+            }
             Statement::BEGIN { modes } => {
                 write!(f, "BEGIN")?;
                 if !modes.is_empty() {
@@ -1645,6 +1749,27 @@ impl fmt::Display for Statement {
     }
 }
 
+/// The `ON CONFLICT` clause of an `INSERT` statement.
+///
+/// Unlike PostgreSQL, a conflicting row can't be partially patched with arbitrary `SET`
+/// expressions: the table's primary key conflict handling always replaces the whole row with the
+/// newly inserted one, so `DO UPDATE` takes no `SET` list.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OnConflict {
+    DoNothing,
+    DoUpdate,
+}
+
+impl fmt::Display for OnConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DoNothing => write!(f, "ON CONFLICT DO NOTHING"),
+            Self::DoUpdate => write!(f, "ON CONFLICT DO UPDATE"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[non_exhaustive]
@@ -1937,6 +2062,8 @@ pub struct Function {
     // aggregate functions may contain order_by_clause
     pub order_by: Vec<OrderByExpr>,
     pub filter: Option<Box<Expr>>,
+    // ordered-set aggregate functions, e.g. `percentile_cont(0.5) WITHIN GROUP (ORDER BY x)`
+    pub within_group: Option<Box<OrderByExpr>>,
 }
 
 impl Function {
@@ -1948,6 +2075,7 @@ impl Function {
             distinct: false,
             order_by: vec![],
             filter: None,
+            within_group: None,
         }
     }
 }
@@ -1970,6 +2098,9 @@ impl fmt::Display for Function {
         if let Some(o) = &self.over {
             write!(f, " OVER ({})", o)?;
         }
+        if let Some(within_group) = &self.within_group {
+            write!(f, " WITHIN GROUP (ORDER BY {})", within_group)?;
+        }
         if let Some(filter) = &self.filter {
             write!(f, " FILTER(WHERE {})", filter)?;
         }
@@ -90,6 +90,10 @@ pub enum BinaryOperator {
     LongArrow,
     HashArrow,
     HashLongArrow,
+    /// `@>`, e.g. `ARRAY[1,2,3] @> ARRAY[2,3]` (PostgreSQL-specific)
+    PGContains,
+    /// `&&`, e.g. `ARRAY[1,2,3] && ARRAY[2,4]` (PostgreSQL-specific)
+    PGOverlap,
 }
 
 impl fmt::Display for BinaryOperator {
@@ -129,6 +133,8 @@ impl fmt::Display for BinaryOperator {
             BinaryOperator::LongArrow => "->>",
             BinaryOperator::HashArrow => "#>",
             BinaryOperator::HashLongArrow => "#>>",
+            BinaryOperator::PGContains => "@>",
+            BinaryOperator::PGOverlap => "&&",
         })
     }
 }
@@ -349,6 +349,27 @@ impl fmt::Display for TableWithJoins {
     }
 }
 
+/// A `FOR SYSTEM_TIME AS OF ...` clause attached to a table reference.
+///
+/// `ProcessTime` (`FOR SYSTEM_TIME AS OF NOW()`) always resolves to the latest committed
+/// state and is used to mark the build side of a temporal join. `TimestampString` binds the
+/// scan to a historical snapshot instead, for point-in-time reads of a single table.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AsOf {
+    ProcessTime,
+    TimestampString(String),
+}
+
+impl fmt::Display for AsOf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AsOf::ProcessTime => write!(f, " FOR SYSTEM_TIME AS OF NOW()"),
+            AsOf::TimestampString(s) => write!(f, " FOR SYSTEM_TIME AS OF '{}'", s),
+        }
+    }
+}
+
 /// A table name or a parenthesized subquery with an optional alias
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -356,7 +377,7 @@ pub enum TableFactor {
     Table {
         name: ObjectName,
         alias: Option<TableAlias>,
-        for_system_time_as_of_now: bool,
+        as_of: Option<AsOf>,
     },
     Derived {
         lateral: bool,
@@ -381,14 +402,10 @@ pub enum TableFactor {
 impl fmt::Display for TableFactor {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            TableFactor::Table {
-                name,
-                alias,
-                for_system_time_as_of_now,
-            } => {
+            TableFactor::Table { name, alias, as_of } => {
                 write!(f, "{}", name)?;
-                if *for_system_time_as_of_now {
-                    write!(f, " FOR SYSTEM_TIME AS OF NOW()")?;
+                if let Some(as_of) = as_of {
+                    write!(f, "{}", as_of)?;
                 }
                 if let Some(alias) = alias {
                     write!(f, " AS {}", alias)?;
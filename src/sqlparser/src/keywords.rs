@@ -71,6 +71,7 @@ define_keywords!(
     ABS,
     ACTION,
     ADD,
+    AGGREGATE,
     ALL,
     ALLOCATE,
     ALTER,
@@ -107,6 +108,7 @@ define_keywords!(
     CACHE,
     CALL,
     CALLED,
+    CANCEL,
     CARDINALITY,
     CASCADE,
     CASCADED,
@@ -134,6 +136,7 @@ define_keywords!(
     COMMITTED,
     CONCURRENTLY,
     CONDITION,
+    CONFLICT,
     CONFLUENT,
     CONNECT,
     CONNECTION,
@@ -191,6 +194,7 @@ define_keywords!(
     DISTRIBUTED,
     DISTSQL,
     DO,
+    DOT,
     DOUBLE,
     DROP,
     DYNAMIC,
@@ -271,7 +275,9 @@ define_keywords!(
     IS,
     ISNULL,
     ISOLATION,
+    JOBS,
     JOIN,
+    JSON,
     KEY,
     LANGUAGE,
     LARGE,
@@ -321,6 +327,7 @@ define_keywords!(
     NOSCAN,
     NOSUPERUSER,
     NOT,
+    NOTHING,
     NOTNULL,
     NTH_VALUE,
     NTILE,
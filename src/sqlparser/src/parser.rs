@@ -247,6 +247,10 @@ impl Parser {
                 Keyword::PREPARE => Ok(self.parse_prepare()?),
                 Keyword::COMMENT => Ok(self.parse_comment()?),
                 Keyword::FLUSH => Ok(Statement::Flush),
+                Keyword::CANCEL => Ok(self.parse_cancel_jobs()?),
+                Keyword::DECLARE => Ok(self.parse_declare_cursor()?),
+                Keyword::FETCH => Ok(self.parse_fetch_cursor()?),
+                Keyword::CLOSE => Ok(self.parse_close_cursor()?),
                 _ => self.expected(
                     "an SQL statement",
                     Token::Word(w).with_location(token.location),
@@ -698,6 +702,16 @@ impl Parser {
             None
         };
 
+        let within_group = if self.parse_keywords(&[Keyword::WITHIN, Keyword::GROUP]) {
+            self.expect_token(&Token::LParen)?;
+            self.expect_keywords(&[Keyword::ORDER, Keyword::BY])?;
+            let order_by_expr = self.parse_order_by_expr()?;
+            self.expect_token(&Token::RParen)?;
+            Some(Box::new(order_by_expr))
+        } else {
+            None
+        };
+
         let filter = if self.parse_keyword(Keyword::FILTER) {
             self.expect_token(&Token::LParen)?;
             self.expect_keyword(Keyword::WHERE)?;
@@ -715,6 +729,7 @@ impl Parser {
             distinct,
             order_by,
             filter,
+            within_group,
         }))
     }
 
@@ -1227,6 +1242,8 @@ impl Parser {
             Token::LongArrow => Some(BinaryOperator::LongArrow),
             Token::HashArrow => Some(BinaryOperator::HashArrow),
             Token::HashLongArrow => Some(BinaryOperator::HashLongArrow),
+            Token::AtArrow => Some(BinaryOperator::PGContains),
+            Token::Overlap => Some(BinaryOperator::PGOverlap),
             Token::Word(w) => match w.keyword {
                 Keyword::AND => Some(BinaryOperator::And),
                 Keyword::OR => Some(BinaryOperator::Or),
@@ -1467,7 +1484,9 @@ impl Parser {
             | Token::Arrow
             | Token::LongArrow
             | Token::HashArrow
-            | Token::HashLongArrow => Ok(P::Other),
+            | Token::HashLongArrow
+            | Token::AtArrow
+            | Token::Overlap => Ok(P::Other),
             Token::Word(w) if w.keyword == Keyword::AT => {
                 match (self.peek_nth_token(1).token, self.peek_nth_token(2).token) {
                     (Token::Word(w), Token::Word(w2))
@@ -1749,9 +1768,13 @@ impl Parser {
         if self.parse_keyword(Keyword::TABLE) {
             self.parse_create_table(or_replace, temporary)
         } else if self.parse_keyword(Keyword::VIEW) {
-            self.parse_create_view(false, or_replace)
+            self.parse_create_view(false, temporary, or_replace)
         } else if self.parse_keywords(&[Keyword::MATERIALIZED, Keyword::VIEW]) {
-            self.parse_create_view(true, or_replace)
+            if temporary {
+                parser_err!("CREATE TEMPORARY MATERIALIZED VIEW is not supported".to_string())
+            } else {
+                self.parse_create_view(true, false, or_replace)
+            }
         } else if self.parse_keywords(&[Keyword::MATERIALIZED, Keyword::SOURCE]) {
             parser_err!("CREATE MATERIALIZED SOURCE has been deprecated, use CREATE TABLE instead")
         } else if self.parse_keyword(Keyword::SOURCE) {
@@ -1762,9 +1785,11 @@ impl Parser {
             self.parse_create_connection()
         } else if self.parse_keyword(Keyword::FUNCTION) {
             self.parse_create_function(or_replace, temporary)
+        } else if self.parse_keyword(Keyword::AGGREGATE) {
+            self.parse_create_aggregate(or_replace)
         } else if or_replace {
             self.expected(
-                "[EXTERNAL] TABLE or [MATERIALIZED] VIEW or [MATERIALIZED] SOURCE or SINK or FUNCTION after CREATE OR REPLACE",
+                "[EXTERNAL] TABLE or [MATERIALIZED] VIEW or [MATERIALIZED] SOURCE or SINK or FUNCTION or AGGREGATE after CREATE OR REPLACE",
                 self.peek_token(),
             )
         } else if self.parse_keyword(Keyword::INDEX) {
@@ -1803,6 +1828,7 @@ impl Parser {
     pub fn parse_create_view(
         &mut self,
         materialized: bool,
+        temporary: bool,
         or_replace: bool,
     ) -> Result<Statement, ParserError> {
         // Many dialects support `OR ALTER` right after `CREATE`, but we don't (yet).
@@ -1823,6 +1849,7 @@ impl Parser {
             columns,
             query,
             materialized,
+            temporary,
             or_replace,
             with_options,
             emit_mode,
@@ -1917,6 +1944,36 @@ impl Parser {
         })
     }
 
+    // CREATE [OR REPLACE]?
+    // AGGREGATE
+    // <name: ObjectName> ( <args: OperateFunctionArg list> )
+    // RETURNS <return_type: DataType>
+    // <params: CreateFunctionBody>
+    pub fn parse_create_aggregate(&mut self, or_replace: bool) -> Result<Statement, ParserError> {
+        let name = self.parse_object_name()?;
+        self.expect_token(&Token::LParen)?;
+        let args = if self.consume_token(&Token::RParen) {
+            self.prev_token();
+            None
+        } else {
+            Some(self.parse_comma_separated(Parser::parse_function_arg)?)
+        };
+        self.expect_token(&Token::RParen)?;
+
+        self.expect_keyword(Keyword::RETURNS)?;
+        let returns = self.parse_data_type()?;
+
+        let params = self.parse_create_function_body()?;
+
+        Ok(Statement::CreateAggregate {
+            or_replace,
+            name,
+            args,
+            returns,
+            params,
+        })
+    }
+
     fn parse_table_column_def(&mut self) -> Result<TableColumnDef, ParserError> {
         Ok(TableColumnDef {
             name: self.parse_identifier_non_reserved()?,
@@ -2123,6 +2180,8 @@ impl Parser {
             false
         };
 
+        let on_conflict = self.parse_on_conflict()?;
+
         // PostgreSQL supports `WITH ( options )`, before `AS`
         let with_options = self.parse_with_properties()?;
 
@@ -2190,6 +2249,7 @@ impl Parser {
             source_schema,
             source_watermarks,
             append_only,
+            on_conflict,
             query,
         })
     }
@@ -3008,20 +3068,24 @@ impl Parser {
         }
     }
 
-    pub fn parse_for_system_time_as_of_now(&mut self) -> Result<bool, ParserError> {
-        let after_for = self.parse_keyword(Keyword::FOR);
-        if after_for {
-            self.expect_keywords(&[Keyword::SYSTEM_TIME, Keyword::AS, Keyword::OF])?;
-            let ident = self.parse_identifier()?;
-            if ident.real_value() != "now" {
-                return parser_err!(format!("Expected now, found: {}", ident.real_value()));
-            }
-            self.expect_token(&Token::LParen)?;
-            self.expect_token(&Token::RParen)?;
-            Ok(true)
-        } else {
-            Ok(false)
+    /// Parses an optional `FOR SYSTEM_TIME AS OF NOW()` or `FOR SYSTEM_TIME AS OF '<timestamp>'`
+    /// clause following a table reference.
+    pub fn parse_as_of(&mut self) -> Result<Option<AsOf>, ParserError> {
+        if !self.parse_keyword(Keyword::FOR) {
+            return Ok(None);
+        }
+        self.expect_keywords(&[Keyword::SYSTEM_TIME, Keyword::AS, Keyword::OF])?;
+        if let Token::SingleQuotedString(_) = self.peek_token().token {
+            let s = self.parse_literal_string()?;
+            return Ok(Some(AsOf::TimestampString(s)));
         }
+        let ident = self.parse_identifier()?;
+        if ident.real_value() != "now" {
+            return parser_err!(format!("Expected now or a timestamp, found: {}", ident.real_value()));
+        }
+        self.expect_token(&Token::LParen)?;
+        self.expect_token(&Token::RParen)?;
+        Ok(Some(AsOf::ProcessTime))
     }
 
     /// Parse a possibly qualified, possibly quoted identifier, e.g.
@@ -3222,6 +3286,8 @@ impl Parser {
             Keyword::LOGICAL,
             Keyword::PHYSICAL,
             Keyword::DISTSQL,
+            Keyword::DOT,
+            Keyword::JSON,
         ];
 
         let parse_explain_option = |parser: &mut Parser| -> Result<(), ParserError> {
@@ -3234,17 +3300,23 @@ impl Parser {
                         Keyword::LOGICAL,
                         Keyword::PHYSICAL,
                         Keyword::DISTSQL,
+                        Keyword::DOT,
+                        Keyword::JSON,
                     ])?;
                     match explain_type {
                         Keyword::LOGICAL => options.explain_type = ExplainType::Logical,
                         Keyword::PHYSICAL => options.explain_type = ExplainType::Physical,
                         Keyword::DISTSQL => options.explain_type = ExplainType::DistSql,
+                        Keyword::DOT => options.explain_type = ExplainType::Dot,
+                        Keyword::JSON => options.explain_type = ExplainType::Json,
                         _ => unreachable!("{}", keyword),
                     }
                 }
                 Keyword::LOGICAL => options.explain_type = ExplainType::Logical,
                 Keyword::PHYSICAL => options.explain_type = ExplainType::Physical,
                 Keyword::DISTSQL => options.explain_type = ExplainType::DistSql,
+                Keyword::DOT => options.explain_type = ExplainType::Dot,
+                Keyword::JSON => options.explain_type = ExplainType::Json,
                 _ => unreachable!("{}", keyword),
             };
             Ok(())
@@ -3623,6 +3695,9 @@ impl Parser {
                 Keyword::CONNECTIONS => {
                     return Ok(Statement::ShowObjects(ShowObject::Connection));
                 }
+                Keyword::JOBS => {
+                    return Ok(Statement::ShowObjects(ShowObject::Jobs));
+                }
                 _ => {}
             }
         }
@@ -3632,6 +3707,15 @@ impl Parser {
         })
     }
 
+    /// Parse a `CANCEL JOBS job_id [, job_id]*` statement.
+    pub fn parse_cancel_jobs(&mut self) -> Result<Statement, ParserError> {
+        self.expect_keyword(Keyword::JOBS)?;
+        let job_ids = self.parse_comma_separated(Parser::parse_literal_uint)?;
+        Ok(Statement::CancelJobs(
+            job_ids.into_iter().map(|id| id as u32).collect(),
+        ))
+    }
+
     /// Parser `from schema` after `show tables` and `show materialized views`, if not conclude
     /// `from` then use default schema name.
     pub fn parse_from_and_identifier(&mut self) -> Result<Option<Ident>, ParserError> {
@@ -3826,13 +3910,9 @@ impl Parser {
                 let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
                 Ok(TableFactor::TableFunction { name, alias, args })
             } else {
-                let for_system_time_as_of_now = self.parse_for_system_time_as_of_now()?;
+                let as_of = self.parse_as_of()?;
                 let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
-                Ok(TableFactor::Table {
-                    name,
-                    alias,
-                    for_system_time_as_of_now,
-                })
+                Ok(TableFactor::Table { name, alias, as_of })
             }
         }
     }
@@ -4051,16 +4131,34 @@ impl Parser {
         let columns = self.parse_parenthesized_column_list(Optional)?;
 
         let source = Box::new(self.parse_query()?);
+        let on_conflict = self.parse_on_conflict()?;
         let returning = self.parse_returning(Optional)?;
 
         Ok(Statement::Insert {
             table_name,
             columns,
             source,
+            on_conflict,
             returning,
         })
     }
 
+    /// Parse an `ON CONFLICT DO NOTHING` / `ON CONFLICT DO UPDATE` clause, if present. Used by
+    /// both `INSERT` (to describe how this statement wants conflicts handled) and `CREATE TABLE`
+    /// (to configure how the table handles them for all writes).
+    pub fn parse_on_conflict(&mut self) -> Result<Option<OnConflict>, ParserError> {
+        if !self.parse_keywords(&[Keyword::ON, Keyword::CONFLICT]) {
+            return Ok(None);
+        }
+        self.expect_keyword(Keyword::DO)?;
+        if self.parse_keyword(Keyword::NOTHING) {
+            Ok(Some(OnConflict::DoNothing))
+        } else {
+            self.expect_keyword(Keyword::UPDATE)?;
+            Ok(Some(OnConflict::DoUpdate))
+        }
+    }
+
     pub fn parse_update(&mut self) -> Result<Statement, ParserError> {
         let table_name = self.parse_object_name()?;
 
@@ -4293,6 +4391,38 @@ impl Parser {
         }
     }
 
+    fn parse_declare_cursor(&mut self) -> Result<Statement, ParserError> {
+        let cursor_name = self.parse_identifier()?;
+        self.expect_keyword(Keyword::CURSOR)?;
+        self.expect_keyword(Keyword::FOR)?;
+        let query = Box::new(self.parse_query()?);
+        Ok(Statement::DeclareCursor { cursor_name, query })
+    }
+
+    fn parse_fetch_cursor(&mut self) -> Result<Statement, ParserError> {
+        let count = if self.parse_keyword(Keyword::NEXT) {
+            None
+        } else if self.parse_keyword(Keyword::ALL) {
+            Some(u64::MAX)
+        } else if let Token::Number(_) = self.peek_token().token {
+            Some(self.parse_literal_uint()?)
+        } else {
+            None
+        };
+        self.expect_keyword(Keyword::FROM)?;
+        let cursor_name = self.parse_identifier()?;
+        Ok(Statement::FetchCursor { cursor_name, count })
+    }
+
+    fn parse_close_cursor(&mut self) -> Result<Statement, ParserError> {
+        let cursor_name = if self.parse_keyword(Keyword::ALL) {
+            None
+        } else {
+            Some(self.parse_identifier()?)
+        };
+        Ok(Statement::CloseCursor { cursor_name })
+    }
+
     fn parse_deallocate(&mut self) -> Result<Statement, ParserError> {
         let prepare = self.parse_keyword(Keyword::PREPARE);
         let name = self.parse_identifier()?;
@@ -4342,6 +4472,11 @@ impl Parser {
                 let object_name = self.parse_object_name()?;
                 (CommentObject::Table, object_name)
             }
+            Token::Word(w) if w.keyword == Keyword::MATERIALIZED => {
+                self.expect_keyword(Keyword::VIEW)?;
+                let object_name = self.parse_object_name()?;
+                (CommentObject::MaterializedView, object_name)
+            }
             _ => self.expected("comment object_type", token)?,
         };
 
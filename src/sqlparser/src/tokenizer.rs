@@ -111,6 +111,8 @@ pub enum Token {
     RBracket,
     /// Ampersand `&`
     Ampersand,
+    /// Double Ampersand `&&` used for PostgreSQL array/range overlap operator
+    Overlap,
     /// Pipe `|`
     Pipe,
     /// Caret `^`
@@ -142,6 +144,8 @@ pub enum Token {
     DoubleExclamationMark,
     /// AtSign `@` used for PostgreSQL abs operator
     AtSign,
+    /// AtArrow `@>` used for PostgreSQL array/range contains operator
+    AtArrow,
     /// `|/`, a square root math operator in PostgreSQL
     PGSquareRoot,
     /// `||/` , a cube root math operator in PostgreSQL
@@ -195,6 +199,7 @@ impl fmt::Display for Token {
             Token::LBracket => f.write_str("["),
             Token::RBracket => f.write_str("]"),
             Token::Ampersand => f.write_str("&"),
+            Token::Overlap => f.write_str("&&"),
             Token::Caret => f.write_str("^"),
             Token::Pipe => f.write_str("|"),
             Token::LBrace => f.write_str("{"),
@@ -208,6 +213,7 @@ impl fmt::Display for Token {
             Token::ExclamationMarkTilde => f.write_str("!~"),
             Token::ExclamationMarkTildeAsterisk => f.write_str("!~*"),
             Token::AtSign => f.write_str("@"),
+            Token::AtArrow => f.write_str("@>"),
             Token::ShiftLeft => f.write_str("<<"),
             Token::ShiftRight => f.write_str(">>"),
             Token::PGSquareRoot => f.write_str("|/"),
@@ -691,7 +697,16 @@ impl<'a> Tokenizer<'a> {
                 '\\' => self.consume_and_return(chars, Token::Backslash),
                 '[' => self.consume_and_return(chars, Token::LBracket),
                 ']' => self.consume_and_return(chars, Token::RBracket),
-                '&' => self.consume_and_return(chars, Token::Ampersand),
+                '&' => {
+                    chars.next(); // consume the '&'
+                    match chars.peek() {
+                        Some('&') => {
+                            chars.next(); // consume the second '&'
+                            Ok(Some(Token::Overlap))
+                        }
+                        _ => Ok(Some(Token::Ampersand)),
+                    }
+                }
                 '^' => self.consume_and_return(chars, Token::Caret),
                 '{' => self.consume_and_return(chars, Token::LBrace),
                 '}' => self.consume_and_return(chars, Token::RBrace),
@@ -719,7 +734,16 @@ impl<'a> Tokenizer<'a> {
                         _ => Ok(Some(Token::Sharp)),
                     }
                 }
-                '@' => self.consume_and_return(chars, Token::AtSign),
+                '@' => {
+                    chars.next(); // consume the '@'
+                    match chars.peek() {
+                        Some('>') => {
+                            chars.next(); // consume the '>'
+                            Ok(Some(Token::AtArrow))
+                        }
+                        _ => Ok(Some(Token::AtSign)),
+                    }
+                }
                 other => self.consume_and_return(chars, Token::Char(other)),
             },
             None => Ok(None),
@@ -90,6 +90,35 @@ fn parse_insert_values() {
     verified_stmt("INSERT INTO customer WITH foo AS (SELECT 1) SELECT * FROM foo UNION VALUES (1)");
 }
 
+#[test]
+fn parse_insert_on_conflict() {
+    match verified_stmt("INSERT INTO customer VALUES (1, 2, 3) ON CONFLICT DO NOTHING") {
+        Statement::Insert { on_conflict, .. } => {
+            assert_eq!(on_conflict, Some(OnConflict::DoNothing));
+        }
+        _ => unreachable!(),
+    }
+
+    match verified_stmt("INSERT INTO customer VALUES (1, 2, 3) ON CONFLICT DO UPDATE") {
+        Statement::Insert { on_conflict, .. } => {
+            assert_eq!(on_conflict, Some(OnConflict::DoUpdate));
+        }
+        _ => unreachable!(),
+    }
+
+    verified_stmt("INSERT INTO customer VALUES (1, 2, 3) ON CONFLICT DO NOTHING RETURNING (id)");
+}
+
+#[test]
+fn parse_create_table_on_conflict() {
+    match verified_stmt("CREATE TABLE t (v1 INT PRIMARY KEY) ON CONFLICT DO NOTHING") {
+        Statement::CreateTable { on_conflict, .. } => {
+            assert_eq!(on_conflict, Some(OnConflict::DoNothing));
+        }
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_update() {
     let sql = "UPDATE t SET a = 1, b = 2, c = 3, d = DEFAULT WHERE e";
@@ -2154,17 +2183,13 @@ fn parse_delimited_identifiers() {
     );
     // check FROM
     match only(select.from).relation {
-        TableFactor::Table {
-            name,
-            alias,
-            for_system_time_as_of_now,
-        } => {
+        TableFactor::Table { name, alias, as_of } => {
             assert_eq!(vec![Ident::with_quote_unchecked('"', "a table")], name.0);
             assert_eq!(
                 Ident::with_quote_unchecked('"', "alias"),
                 alias.unwrap().name
             );
-            assert!(!for_system_time_as_of_now);
+            assert!(as_of.is_none());
         }
         _ => panic!("Expecting TableFactor::Table"),
     }
@@ -2291,7 +2316,7 @@ fn parse_implicit_join() {
                 relation: TableFactor::Table {
                     name: ObjectName(vec!["t1".into()]),
                     alias: None,
-                    for_system_time_as_of_now: false,
+                    as_of: None,
                 },
                 joins: vec![],
             },
@@ -2299,7 +2324,7 @@ fn parse_implicit_join() {
                 relation: TableFactor::Table {
                     name: ObjectName(vec!["t2".into()]),
                     alias: None,
-                    for_system_time_as_of_now: false,
+                    as_of: None,
                 },
                 joins: vec![],
             }
@@ -2315,13 +2340,13 @@ fn parse_implicit_join() {
                 relation: TableFactor::Table {
                     name: ObjectName(vec!["t1a".into()]),
                     alias: None,
-                    for_system_time_as_of_now: false,
+                    as_of: None,
                 },
                 joins: vec![Join {
                     relation: TableFactor::Table {
                         name: ObjectName(vec!["t1b".into()]),
                         alias: None,
-                        for_system_time_as_of_now: false,
+                        as_of: None,
                     },
                     join_operator: JoinOperator::Inner(JoinConstraint::Natural),
                 }]
@@ -2330,13 +2355,13 @@ fn parse_implicit_join() {
                 relation: TableFactor::Table {
                     name: ObjectName(vec!["t2a".into()]),
                     alias: None,
-                    for_system_time_as_of_now: false,
+                    as_of: None,
                 },
                 joins: vec![Join {
                     relation: TableFactor::Table {
                         name: ObjectName(vec!["t2b".into()]),
                         alias: None,
-                        for_system_time_as_of_now: false,
+                        as_of: None,
                     },
                     join_operator: JoinOperator::Inner(JoinConstraint::Natural),
                 }]
@@ -2355,7 +2380,7 @@ fn parse_cross_join() {
             relation: TableFactor::Table {
                 name: ObjectName(vec![Ident::new_unchecked("t2")]),
                 alias: None,
-                for_system_time_as_of_now: false,
+                as_of: None,
             },
             join_operator: JoinOperator::CrossJoin
         },
@@ -2372,7 +2397,7 @@ fn parse_temporal_join() {
             relation: TableFactor::Table {
                 name: ObjectName(vec![Ident::new_unchecked("t2")]),
                 alias: None,
-                for_system_time_as_of_now: true,
+                as_of: Some(AsOf::ProcessTime),
             },
             join_operator: Inner(JoinConstraint::On(Expr::BinaryOp {
                 left: Box::new(Expr::Identifier("c1".into())),
@@ -2384,6 +2409,20 @@ fn parse_temporal_join() {
     );
 }
 
+#[test]
+fn parse_as_of_timestamp() {
+    let sql = "SELECT * FROM t1 FOR SYSTEM_TIME AS OF '1970-01-01 00:00:00'";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        TableFactor::Table {
+            name: ObjectName(vec![Ident::new_unchecked("t1")]),
+            alias: None,
+            as_of: Some(AsOf::TimestampString("1970-01-01 00:00:00".to_string())),
+        },
+        only(select.from).relation,
+    );
+}
+
 #[test]
 fn parse_joins_on() {
     fn join_with_constraint(
@@ -2395,7 +2434,7 @@ fn parse_joins_on() {
             relation: TableFactor::Table {
                 name: ObjectName(vec![Ident::new_unchecked(relation.into())]),
                 alias,
-                for_system_time_as_of_now: false,
+                as_of: None,
             },
             join_operator: f(JoinConstraint::On(Expr::BinaryOp {
                 left: Box::new(Expr::Identifier("c1".into())),
@@ -2447,7 +2486,7 @@ fn parse_joins_using() {
             relation: TableFactor::Table {
                 name: ObjectName(vec![Ident::new_unchecked(relation.into())]),
                 alias,
-                for_system_time_as_of_now: false,
+                as_of: None,
             },
             join_operator: f(JoinConstraint::Using(vec!["c1".into()])),
         }
@@ -2491,7 +2530,7 @@ fn parse_natural_join() {
             relation: TableFactor::Table {
                 name: ObjectName(vec![Ident::new_unchecked("t2")]),
                 alias: None,
-                for_system_time_as_of_now: false,
+                as_of: None,
             },
             join_operator: f(JoinConstraint::Natural),
         }
@@ -2718,7 +2757,7 @@ fn parse_derived_tables() {
                 relation: TableFactor::Table {
                     name: ObjectName(vec!["t2".into()]),
                     alias: None,
-                    for_system_time_as_of_now: false,
+                    as_of: None,
                 },
                 join_operator: JoinOperator::Inner(JoinConstraint::Natural),
             }],
@@ -2920,9 +2959,11 @@ fn parse_create_view() {
             query,
             or_replace,
             materialized,
+            temporary,
             with_options,
             emit_mode,
         } => {
+            assert!(!temporary);
             assert_eq!("myschema.myview", name.to_string());
             assert_eq!(Vec::<Ident>::new(), columns);
             assert_eq!("SELECT foo FROM bar", query.to_string());
@@ -2935,6 +2976,28 @@ fn parse_create_view() {
     }
 }
 
+#[test]
+fn parse_create_temporary_view() {
+    let sql = "CREATE TEMPORARY VIEW myview AS SELECT foo FROM bar";
+    match verified_stmt(sql) {
+        Statement::CreateView {
+            name,
+            temporary,
+            materialized,
+            ..
+        } => {
+            assert_eq!("myview", name.to_string());
+            assert!(temporary);
+            assert!(!materialized);
+        }
+        _ => unreachable!(),
+    }
+
+    let res = parse_sql_statements("CREATE TEMPORARY MATERIALIZED VIEW myview AS SELECT 1");
+    assert!(format!("{}", res.unwrap_err())
+        .contains("CREATE TEMPORARY MATERIALIZED VIEW is not supported"));
+}
+
 #[test]
 fn parse_create_view_with_options() {
     let sql = "CREATE VIEW v WITH (foo = 'bar', a = 123) AS SELECT 1";
@@ -2969,8 +3032,10 @@ fn parse_create_view_with_columns() {
             with_options,
             query,
             materialized,
+            temporary,
             emit_mode,
         } => {
+            assert!(!temporary);
             assert_eq!("v", name.to_string());
             assert_eq!(
                 columns,
@@ -2996,8 +3061,10 @@ fn parse_create_or_replace_view() {
             with_options,
             query,
             materialized,
+            temporary,
             emit_mode,
         } => {
+            assert!(!temporary);
             assert_eq!("v", name.to_string());
             assert_eq!(columns, vec![]);
             assert_eq!(with_options, vec![]);
@@ -3025,8 +3092,10 @@ fn parse_create_or_replace_materialized_view() {
             with_options,
             query,
             materialized,
+            temporary,
             emit_mode,
         } => {
+            assert!(!temporary);
             assert_eq!("v", name.to_string());
             assert_eq!(columns, vec![]);
             assert_eq!(with_options, vec![]);
@@ -3049,9 +3118,11 @@ fn parse_create_materialized_view() {
             columns,
             query,
             materialized,
+            temporary,
             with_options,
             emit_mode,
         } => {
+            assert!(!temporary);
             assert_eq!("myschema.myview", name.to_string());
             assert_eq!(Vec::<Ident>::new(), columns);
             assert_eq!("SELECT foo FROM bar", query.to_string());
@@ -3074,9 +3145,11 @@ fn parse_create_materialized_view_emit_immediately() {
             columns,
             query,
             materialized,
+            temporary,
             with_options,
             emit_mode,
         } => {
+            assert!(!temporary);
             assert_eq!("myschema.myview", name.to_string());
             assert_eq!(Vec::<Ident>::new(), columns);
             assert_eq!("SELECT foo FROM bar", query.to_string());
@@ -3100,9 +3173,11 @@ fn parse_create_materialized_view_emit_on_window_close() {
             columns,
             query,
             materialized,
+            temporary,
             with_options,
             emit_mode,
         } => {
+            assert!(!temporary);
             assert_eq!("myschema.myview", name.to_string());
             assert_eq!(Vec::<Ident>::new(), columns);
             assert_eq!("SELECT foo FROM bar", query.to_string());
@@ -738,6 +738,19 @@ fn parse_comments() {
         }
         _ => unreachable!(),
     }
+
+    match verified_stmt("COMMENT ON MATERIALIZED VIEW public.mv IS 'comment'") {
+        Statement::Comment {
+            object_type,
+            object_name,
+            comment: Some(comment),
+        } => {
+            assert_eq!("comment", comment);
+            assert_eq!("public.mv", object_name.to_string());
+            assert_eq!(CommentObject::MaterializedView, object_type);
+        }
+        _ => unreachable!(),
+    }
 }
 
 #[test]
@@ -226,10 +226,15 @@ pub async fn generate_splits(compact_task: &mut CompactTask, context: Arc<Compac
         indexes.sort_by(|a, b| KeyComparator::compare_encoded_full_key(a.1.as_ref(), b.1.as_ref()));
         let mut splits: Vec<KeyRange_vec> = vec![];
         splits.push(KeyRange_vec::new(vec![], vec![]));
-        let parallelism = std::cmp::min(
-            indexes.len() as u64,
-            context.storage_opts.max_sub_compaction as u64,
-        );
+        // Prefer the compaction group's configured parallelism so that `ALTER COMPACTION GROUP
+        // SET max_sub_compaction` takes effect; fall back to the compactor's local default for
+        // tasks created before this field existed.
+        let max_sub_compaction = if compact_task.max_sub_compaction > 0 {
+            compact_task.max_sub_compaction
+        } else {
+            context.storage_opts.max_sub_compaction
+        };
+        let parallelism = std::cmp::min(indexes.len() as u64, max_sub_compaction as u64);
         let sub_compaction_data_size = std::cmp::max(compaction_size / parallelism, sstable_size);
         let parallelism = compaction_size / sub_compaction_data_size;
 
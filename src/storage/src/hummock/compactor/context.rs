@@ -49,6 +49,11 @@ pub struct CompactorContext {
 
     pub read_memory_limiter: Arc<MemoryLimiter>,
 
+    /// Budget for the memory a compact task may occupy while reading its input SSTs. A task is
+    /// queued, not started, until enough quota is available, which keeps the compactor from being
+    /// OOM-killed when many large compaction tasks land at once.
+    pub memory_limiter: Arc<MemoryLimiter>,
+
     pub sstable_object_id_manager: SstableObjectIdManagerRef,
 
     pub task_progress_manager: TaskProgressManagerRef,
@@ -85,6 +90,7 @@ impl CompactorContext {
             compaction_executor,
             filter_key_extractor_manager,
             read_memory_limiter: memory_limiter,
+            memory_limiter: MemoryLimiter::unlimit(),
             sstable_object_id_manager,
             task_progress_manager: Default::default(),
             compactor_runtime_config: Arc::new(tokio::sync::Mutex::new(compactor_runtime_config)),
@@ -174,6 +174,9 @@ impl Compactor {
             compact_task.target_level,
             compact_task.compression_algorithm,
         );
+        // Block until enough memory quota is available rather than launching a task that could
+        // OOM-kill the compactor when many large tasks are admitted at once.
+        let _memory_tracker = context.memory_limiter.require_memory(need_quota).await;
 
         let mut multi_filter = build_multi_compaction_filter(&compact_task);
 
@@ -405,6 +408,8 @@ impl Compactor {
                                     task_id,
                                     num_ssts_sealed: progress.num_ssts_sealed.load(Ordering::Relaxed),
                                     num_ssts_uploaded: progress.num_ssts_uploaded.load(Ordering::Relaxed),
+                                    num_bytes_read: progress.num_bytes_read.load(Ordering::Relaxed),
+                                    num_bytes_sealed: progress.num_bytes_sealed.load(Ordering::Relaxed),
                                 });
                             }
 
@@ -565,6 +570,8 @@ impl Compactor {
         while iter.is_valid() {
             let iter_key = iter.key();
             compaction_statistics.iter_total_key_counts += 1;
+            sst_builder
+                .update_read_bytes((iter_key.encoded_len() + iter.value().encoded_len()) as u64);
 
             let is_new_user_key =
                 last_key.is_empty() || iter_key.user_key != last_key.user_key.as_ref();
@@ -694,6 +701,10 @@ impl Compactor {
         let (split_table_outputs, table_stats_map) = if self.options.capacity as u64
             > self.context.storage_opts.min_sst_size_for_streaming_upload
         {
+            self.context
+                .compactor_metrics
+                .compact_task_streaming_upload_sst_counts
+                .inc();
             self.compact_key_range_impl(
                 StreamingSstableWriterFactory::new(self.context.sstable_store.clone()),
                 iter,
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 use std::sync::Arc;
 
 use parking_lot::Mutex;
@@ -26,6 +26,10 @@ pub type TaskProgressManagerRef = Arc<Mutex<HashMap<HummockCompactionTaskId, Arc
 pub struct TaskProgress {
     pub num_ssts_sealed: AtomicU32,
     pub num_ssts_uploaded: AtomicU32,
+    /// Bytes of input key-value pairs that have been consumed from the merge iterator so far.
+    pub num_bytes_read: AtomicU64,
+    /// Bytes of sealed (locally built) SSTs, i.e. output bytes written so far.
+    pub num_bytes_sealed: AtomicU64,
 }
 
 impl TaskProgress {
@@ -36,6 +40,14 @@ impl TaskProgress {
     pub fn inc_ssts_uploaded(&self) {
         self.num_ssts_uploaded.fetch_add(1, Ordering::Relaxed);
     }
+
+    pub fn inc_num_bytes_read(&self, bytes: u64) {
+        self.num_bytes_read.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    pub fn inc_num_bytes_sealed(&self, bytes: u64) {
+        self.num_bytes_sealed.fetch_add(bytes, Ordering::Relaxed);
+    }
 }
 
 /// An RAII object that contains a [`TaskProgress`] and shares it to all the splits of the task.
@@ -599,8 +599,33 @@ impl HummockUploader {
         }
     }
 
+    /// Memory currently buffered per table across unsealed and sealed imms, largest first. This is
+    /// for diagnostics only: the flush order below is always by epoch (oldest first), to preserve
+    /// the key-overlap correctness invariant documented on [`SealedData::seal_new_epoch`], so the
+    /// table holding the most buffered memory is not necessarily the first one spilled.
+    fn table_memory_usage(&self) -> Vec<(TableId, usize)> {
+        let mut sizes: HashMap<TableId, usize> = HashMap::new();
+        for unsealed_data in self.unsealed_data.values() {
+            for imm in &unsealed_data.imms {
+                *sizes.entry(imm.table_id).or_default() += imm.size();
+            }
+        }
+        for (_, imms) in &self.sealed_data.imms {
+            for imm in imms {
+                *sizes.entry(imm.table_id).or_default() += imm.size();
+            }
+        }
+        let mut sizes = sizes.into_iter().collect_vec();
+        sizes.sort_by(|a, b| b.1.cmp(&a.1));
+        sizes
+    }
+
     pub(crate) fn may_flush(&mut self) {
         if self.context.buffer_tracker.need_more_flush() {
+            info_in_release!(
+                "shared buffer over flush threshold, per-table memory usage (largest first): {:?}",
+                self.table_memory_usage()
+            );
             self.sealed_data.flush(&self.context);
         }
 
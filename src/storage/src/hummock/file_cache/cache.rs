@@ -37,6 +37,27 @@ pub struct FileCacheOptions {
     pub cache_file_max_write_size: usize,
 
     pub flush_buffer_hooks: Vec<Arc<dyn FlushBufferHook>>,
+
+    /// Decides whether an entry is worth the disk write/wear of admitting it into the file
+    /// cache. Defaults to admitting everything.
+    pub admission_picker: Arc<dyn AdmissionPicker>,
+}
+
+/// Admission policy for the file cache, run before an entry is queued for the buffer flusher.
+///
+/// This guards disk write bandwidth and SSD wear against entries unlikely to be read again, e.g.
+/// a one-off full-table scan that would otherwise evict a hot working set from the cache.
+pub trait AdmissionPicker: Send + Sync + 'static {
+    fn pick(&self, value_len: usize) -> bool;
+}
+
+/// Admits every entry. This is the default policy.
+pub struct AdmitAll;
+
+impl AdmissionPicker for AdmitAll {
+    fn pick(&self, _value_len: usize) -> bool {
+        true
+    }
 }
 
 #[async_trait]
@@ -139,6 +160,8 @@ where
     buffer: TwoLevelBuffer<K, V>,
     buffer_flusher_notifier: Arc<Notify>,
 
+    admission_picker: Arc<dyn AdmissionPicker>,
+
     metrics: FileCacheMetricsRef,
 }
 
@@ -155,6 +178,7 @@ where
             store: self.store.clone(),
             buffer: self.buffer.clone(),
             buffer_flusher_notifier: self.buffer_flusher_notifier.clone(),
+            admission_picker: self.admission_picker.clone(),
             metrics: self.metrics.clone(),
         }
     }
@@ -206,6 +230,7 @@ where
 
         let buffer = TwoLevelBuffer::new(buffer_capacity);
         let buffer_flusher_notifier = Arc::new(Notify::new());
+        let admission_picker = options.admission_picker;
 
         let buffer_flusher = BufferFlusher {
             buffer: buffer.clone(),
@@ -234,11 +259,17 @@ where
             buffer,
             buffer_flusher_notifier,
 
+            admission_picker,
+
             metrics,
         })
     }
 
     pub fn insert(&self, key: K, value: V) -> Result<()> {
+        if !self.admission_picker.pick(value.len()) {
+            return Ok(());
+        }
+
         let timer = self.metrics.insert_latency.start_timer();
 
         let hash = self.hash_builder.hash_one(&key);
@@ -344,6 +375,7 @@ mod tests {
             cache_file_max_write_size: 4 * 1024 * 1024, // 4 MiB
 
             flush_buffer_hooks,
+            admission_picker: Arc::new(AdmitAll),
         };
         FileCache::open_with_hasher(
             options,
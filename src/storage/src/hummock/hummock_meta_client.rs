@@ -20,7 +20,7 @@ use risingwave_hummock_sdk::table_stats::TableStatsMap;
 use risingwave_hummock_sdk::{HummockSstableObjectId, LocalSstableInfo, SstObjectIdRange};
 use risingwave_pb::hummock::{
     CompactTask, CompactTaskProgress, CompactorWorkload, HummockSnapshot, HummockVersion,
-    VacuumTask,
+    KeyRange, VacuumTask,
 };
 use risingwave_rpc_client::error::Result;
 use risingwave_rpc_client::{CompactTaskItem, HummockMetaClient, MetaClient};
@@ -149,9 +149,11 @@ impl HummockMetaClient for MonitoredHummockMetaClient {
         compaction_group_id: u64,
         table_id: u32,
         level: u32,
+        sst_ids: Vec<u64>,
+        key_range: Option<KeyRange>,
     ) -> Result<()> {
         self.meta_client
-            .trigger_manual_compaction(compaction_group_id, table_id, level)
+            .trigger_manual_compaction(compaction_group_id, table_id, level, sst_ids, key_range)
             .await
     }
 
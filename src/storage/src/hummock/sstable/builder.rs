@@ -608,4 +608,34 @@ pub(super) mod tests {
         test_with_bloom_filter(false).await;
         test_with_bloom_filter(true).await;
     }
+
+    #[tokio::test]
+    async fn test_bloom_filter_table_id_isolation() {
+        // The bloom filter is built over (key prefix, table_id), so a lookup using the right key
+        // prefix but the wrong table_id should not spuriously match.
+        let opts = SstableBuilderOptions {
+            capacity: 0,
+            block_capacity: 4096,
+            restart_interval: 16,
+            bloom_false_positive: 0.01,
+            compression_algorithm: CompressionAlgorithm::None,
+        };
+        let sstable_store = mock_sstable_store();
+        let table = gen_default_test_sstable(opts, 0, sstable_store).await;
+        assert!(table.has_bloom_filter());
+
+        let other_table_id = 1;
+        let mut false_positive_count = 0;
+        for i in 0..1000 {
+            let full_key = test_key_of(i);
+            let hash =
+                Sstable::hash_for_bloom_filter(full_key.user_key.encode().as_slice(), other_table_id);
+            if table.may_match_hash(hash) {
+                false_positive_count += 1;
+            }
+        }
+        // All keys were built with table_id 0, so matching against table_id 1 should be rare
+        // (bounded by the configured false positive rate), not a systematic hit.
+        assert!(false_positive_count < 1000 / 10);
+    }
 }
@@ -56,7 +56,7 @@ pub use delete_range_aggregator::{
 };
 pub use filter::FilterBuilder;
 pub use sstable_object_id_manager::*;
-pub use utils::CompressionAlgorithm;
+pub use utils::{verify_block_checksum, CompressionAlgorithm};
 use utils::{get_length_prefixed_slice, put_length_prefixed_slice};
 use xxhash_rust::{xxh32, xxh64};
 
@@ -107,6 +107,14 @@ where
         }
     }
 
+    /// Records that `bytes` worth of input key-value pairs have been consumed from the merge
+    /// iterator, for progress reporting.
+    pub fn update_read_bytes(&self, bytes: u64) {
+        if let Some(progress) = &self.task_progress {
+            progress.inc_num_bytes_read(bytes);
+        }
+    }
+
     pub fn for_test(builder_factory: F) -> Self {
         Self {
             builder_factory,
@@ -185,6 +193,7 @@ where
 
                 if let Some(progress) = &self.task_progress {
                     progress.inc_ssts_sealed();
+                    progress.inc_num_bytes_sealed(builder_output.sst_info.file_size());
                 }
 
                 if builder_output.bloom_filter_size != 0 {
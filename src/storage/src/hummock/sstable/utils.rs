@@ -68,6 +68,16 @@ pub fn xxhash64_verify(data: &[u8], checksum: u64) -> HummockResult<()> {
     Ok(())
 }
 
+/// Verifies the xxhash64 checksum trailing an encoded block, i.e. the same layout
+/// [`super::block::Block::decode`] checks on read. Called before a freshly built block is handed
+/// off to the object store uploader, so corruption introduced between block encoding and upload
+/// (e.g. a flipped bit in a reused buffer) is caught at write time rather than surfacing later as
+/// a confusing read-time checksum mismatch that looks like object-store bit rot.
+pub fn verify_block_checksum(block: &[u8]) -> HummockResult<()> {
+    let checksum = (&block[block.len() - 8..]).get_u64_le();
+    xxhash64_verify(&block[..block.len() - 8], checksum)
+}
+
 use bytes::{Buf, BufMut};
 
 pub fn put_length_prefixed_slice(buf: &mut Vec<u8>, slice: &[u8]) {
@@ -31,8 +31,8 @@ use zstd::zstd_safe::WriteBuf;
 
 use super::utils::MemoryTracker;
 use super::{
-    Block, BlockCache, BlockMeta, BlockResponse, Sstable, SstableMeta, SstableWriter, TieredCache,
-    TieredCacheKey, TieredCacheValue,
+    verify_block_checksum, Block, BlockCache, BlockMeta, BlockResponse, Sstable, SstableMeta,
+    SstableWriter, TieredCache, TieredCacheKey, TieredCacheValue,
 };
 use crate::hummock::multi_builder::UploadJoinHandle;
 use crate::hummock::{
@@ -593,6 +593,7 @@ impl SstableWriter for BatchUploadWriter {
     type Output = JoinHandle<HummockResult<()>>;
 
     async fn write_block(&mut self, block: &[u8], meta: &BlockMeta) -> HummockResult<()> {
+        verify_block_checksum(block)?;
         self.buf.extend_from_slice(block);
         if let CachePolicy::Fill(_) = self.policy {
             self.block_info.push(Block::decode(
@@ -682,6 +683,7 @@ impl SstableWriter for StreamingUploadWriter {
     type Output = JoinHandle<HummockResult<()>>;
 
     async fn write_block(&mut self, block_data: &[u8], meta: &BlockMeta) -> HummockResult<()> {
+        verify_block_checksum(block_data)?;
         self.data_len += block_data.len();
         let block_data = Bytes::from(block_data.to_vec());
         if let CachePolicy::Fill(_) = self.policy {
@@ -821,8 +821,12 @@ impl HummockVersionReader {
                     }
                     LevelType::Nonoverlapping => {
                         if prune_nonoverlapping_ssts(&level.table_infos, user_key_range_ref)
-                            .next()
-                            .is_some()
+                            .any(|sstable_info| {
+                                sstable_info
+                                    .table_ids
+                                    .binary_search(&table_id.table_id)
+                                    .is_ok()
+                            })
                         {
                             return Ok(true);
                         }
@@ -874,8 +878,13 @@ impl HummockVersionReader {
                     }
                 }
                 LevelType::Nonoverlapping => {
-                    let table_infos =
-                        prune_nonoverlapping_ssts(&level.table_infos, user_key_range_ref);
+                    let table_infos = prune_nonoverlapping_ssts(&level.table_infos, user_key_range_ref)
+                        .filter(|sstable_info| {
+                            sstable_info
+                                .table_ids
+                                .binary_search(&table_id.table_id)
+                                .is_ok()
+                        });
 
                     for table_info in table_infos {
                         stats_guard.local_stats.may_exist_check_sstable_count += 1;
@@ -43,6 +43,7 @@ pub struct CompactorMetrics {
     pub iter_scan_key_counts: GenericCounterVec<AtomicU64>,
     pub write_build_l0_bytes: GenericCounter<AtomicU64>,
     pub sstable_distinct_epoch_count: Histogram,
+    pub compact_task_streaming_upload_sst_counts: GenericCounter<AtomicU64>,
 }
 
 impl CompactorMetrics {
@@ -62,6 +63,14 @@ impl CompactorMetrics {
         )
         .unwrap();
 
+        let compact_task_streaming_upload_sst_counts = register_int_counter_with_registry!(
+            "compactor_compact_task_streaming_upload_sst_counts",
+            "Total number of sst uploads during compaction that used the streaming multipart \
+             uploader instead of buffering the whole sst in memory",
+            registry
+        )
+        .unwrap();
+
         let opts = histogram_opts!(
             "compactor_compact_sst_duration",
             "Total time of compact_key_range that have been issued to state store",
@@ -230,6 +239,7 @@ impl CompactorMetrics {
             iter_scan_key_counts,
             write_build_l0_bytes,
             sstable_distinct_epoch_count,
+            compact_task_streaming_upload_sst_counts,
         }
     }
 
@@ -43,6 +43,7 @@ pub struct HummockStateStoreMetrics {
     pub write_batch_tuple_counts: GenericCounterVec<AtomicU64>,
     pub write_batch_duration: HistogramVec,
     pub write_batch_size: HistogramVec,
+    pub write_delete_range_tuple_counts: GenericCounterVec<AtomicU64>,
 }
 
 impl HummockStateStoreMetrics {
@@ -138,6 +139,14 @@ impl HummockStateStoreMetrics {
         let write_batch_size =
             register_histogram_vec_with_registry!(opts, &["table_id"], registry).unwrap();
 
+        let write_delete_range_tuple_counts = register_int_counter_vec_with_registry!(
+            "state_store_write_delete_range_tuple_counts",
+            "Total number of range tombstones that have been issued to state store, e.g. from dropping a table or a watermark-based state cleanup",
+            &["table_id"],
+            registry
+        )
+        .unwrap();
+
         let read_req_bloom_filter_positive_counts = register_int_counter_vec_with_registry!(
             "state_store_read_req_bloom_filter_positive_counts",
             "Total number of read request with at least one SST bloom filter check returns positive",
@@ -177,6 +186,7 @@ impl HummockStateStoreMetrics {
             write_batch_tuple_counts,
             write_batch_duration,
             write_batch_size,
+            write_delete_range_tuple_counts,
         }
     }
 
@@ -565,6 +565,7 @@ impl StateStoreImpl {
                 cache_meta_fallocate_unit: opts.file_cache_meta_fallocate_unit_mb * 1024 * 1024,
                 cache_file_max_write_size: opts.file_cache_file_max_write_size_mb * 1024 * 1024,
                 flush_buffer_hooks: vec![],
+                admission_picker: Arc::new(crate::hummock::file_cache::cache::AdmitAll),
             };
             let metrics = Arc::new(tiered_cache_metrics_builder.file());
             TieredCache::file(options, metrics)
@@ -0,0 +1,278 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This module implements `StreamingBitAndAgg` and `StreamingBitOrAgg`, one concrete type per
+//! supported integer width.
+//!
+//! Unlike `bit_xor` (which is its own inverse, see `Xorable` in `foldable.rs`), retracting a
+//! previously-seen value from a running AND/OR is not possible from the combined result alone:
+//! e.g. once `AND` has observed a `0` in some bit, nothing in the result tells us whether
+//! retracting that value should flip the bit back to `1`. So we keep, for every bit position, a
+//! count of how many non-null inputs have that bit set; a bit is part of the AND iff its count
+//! equals the total non-null row count, and part of the OR iff its count is greater than zero.
+
+use risingwave_common::array::stream_chunk::Ops;
+use risingwave_common::array::*;
+use risingwave_common::buffer::Bitmap;
+use risingwave_common::types::Datum;
+use risingwave_common::util::iter_util::ZipEqFast;
+
+use super::StreamingAggImpl;
+use crate::executor::error::StreamExecutorResult;
+
+/// Per-bit set-counts and total non-null row count, shared by the AND/OR states below. `i64` is
+/// wide enough to hold the bit pattern of any supported integer type; sign-extending smaller
+/// types is harmless because every value of the same column shares the same extension, and only
+/// the low bits of the final (truncated-back) result are ever used.
+#[derive(Clone, Debug)]
+struct BitCounts {
+    /// `set_counts[i]` is the number of non-null rows with bit `i` set.
+    set_counts: [i64; 64],
+    total: i64,
+}
+
+impl Default for BitCounts {
+    fn default() -> Self {
+        Self {
+            set_counts: [0; 64],
+            total: 0,
+        }
+    }
+}
+
+impl BitCounts {
+    fn apply_one(&mut self, delta: i64, value: Option<i64>) {
+        let Some(value) = value else {
+            return;
+        };
+        self.total += delta;
+        for (bit, count) in self.set_counts.iter_mut().enumerate() {
+            if (value >> bit) & 1 == 1 {
+                *count += delta;
+            }
+        }
+    }
+
+    fn seed_single_row(value: i64) -> Self {
+        let mut counts = Self::default();
+        counts.apply_one(1, Some(value));
+        counts
+    }
+
+    fn and_result(&self) -> Option<i64> {
+        if self.total == 0 {
+            return None;
+        }
+        let mut result = 0i64;
+        for (bit, &count) in self.set_counts.iter().enumerate() {
+            if count == self.total {
+                result |= 1 << bit;
+            }
+        }
+        Some(result)
+    }
+
+    fn or_result(&self) -> Option<i64> {
+        if self.total == 0 {
+            return None;
+        }
+        let mut result = 0i64;
+        for (bit, &count) in self.set_counts.iter().enumerate() {
+            if count > 0 {
+                result |= 1 << bit;
+            }
+        }
+        Some(result)
+    }
+}
+
+macro_rules! impl_bit_agg {
+    ($name:ident, $array:ty, $owned:ty, $variant:ident, $builder:ty, $result_fn:ident) => {
+        #[derive(Clone, Debug, Default)]
+        pub struct $name {
+            counts: BitCounts,
+        }
+
+        impl $name {
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            pub fn with_datum(datum: Datum) -> StreamExecutorResult<Self> {
+                let counts = match datum {
+                    // Only the bit pattern that produced this previous output is recoverable;
+                    // credit a single row so that a later retraction of it clears the state.
+                    Some(scalar) => {
+                        let value: $owned = scalar.try_into()?;
+                        BitCounts::seed_single_row(value as i64)
+                    }
+                    None => BitCounts::default(),
+                };
+                Ok(Self { counts })
+            }
+        }
+
+        impl StreamingAggImpl for $name {
+            fn apply_batch(
+                &mut self,
+                ops: Ops<'_>,
+                visibility: Option<&Bitmap>,
+                data: &[&ArrayImpl],
+            ) -> StreamExecutorResult<()> {
+                let array: &$array = data[0].into();
+                match visibility {
+                    None => {
+                        for (op, value) in ops.iter().zip_eq_fast(array.iter()) {
+                            let delta = match op {
+                                Op::Insert | Op::UpdateInsert => 1,
+                                Op::Delete | Op::UpdateDelete => -1,
+                            };
+                            self.counts.apply_one(delta, value.map(|v| v as i64));
+                        }
+                    }
+                    Some(visibility) => {
+                        for ((op, value), visible) in
+                            ops.iter().zip_eq_fast(array.iter()).zip_eq_fast(visibility.iter())
+                        {
+                            if visible {
+                                let delta = match op {
+                                    Op::Insert | Op::UpdateInsert => 1,
+                                    Op::Delete | Op::UpdateDelete => -1,
+                                };
+                                self.counts.apply_one(delta, value.map(|v| v as i64));
+                            }
+                        }
+                    }
+                }
+                Ok(())
+            }
+
+            fn get_output(&self) -> StreamExecutorResult<Datum> {
+                Ok(self.counts.$result_fn().map(|v| (v as $owned).into()))
+            }
+
+            fn new_builder(&self) -> ArrayBuilderImpl {
+                ArrayBuilderImpl::$variant(<$builder>::new(0))
+            }
+
+            fn reset(&mut self) {
+                self.counts = BitCounts::default();
+            }
+        }
+    };
+}
+
+impl_bit_agg!(
+    StreamingBitAndAgg16,
+    I16Array,
+    i16,
+    Int16,
+    I16ArrayBuilder,
+    and_result
+);
+impl_bit_agg!(
+    StreamingBitAndAgg32,
+    I32Array,
+    i32,
+    Int32,
+    I32ArrayBuilder,
+    and_result
+);
+impl_bit_agg!(
+    StreamingBitAndAgg64,
+    I64Array,
+    i64,
+    Int64,
+    I64ArrayBuilder,
+    and_result
+);
+impl_bit_agg!(
+    StreamingBitOrAgg16,
+    I16Array,
+    i16,
+    Int16,
+    I16ArrayBuilder,
+    or_result
+);
+impl_bit_agg!(
+    StreamingBitOrAgg32,
+    I32Array,
+    i32,
+    Int32,
+    I32ArrayBuilder,
+    or_result
+);
+impl_bit_agg!(
+    StreamingBitOrAgg64,
+    I64Array,
+    i64,
+    Int64,
+    I64ArrayBuilder,
+    or_result
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bit_and_agg() {
+        let mut state = StreamingBitAndAgg64::new();
+        assert_eq!(state.get_output().unwrap(), None);
+
+        state
+            .apply_batch(
+                &[Op::Insert, Op::Insert],
+                None,
+                &[&I64Array::from_iter([Some(0b110), Some(0b100)]).into()],
+            )
+            .unwrap();
+        assert_eq!(state.get_output().unwrap().unwrap().into_int64(), 0b100);
+
+        // retracting the row that cleared the low bit should not change the AND here, since it
+        // was already clear in the other row.
+        state
+            .apply_batch(
+                &[Op::Delete],
+                None,
+                &[&I64Array::from_iter([Some(0b100)]).into()],
+            )
+            .unwrap();
+        assert_eq!(state.get_output().unwrap().unwrap().into_int64(), 0b110);
+    }
+
+    #[test]
+    fn test_bit_or_agg() {
+        let mut state = StreamingBitOrAgg64::new();
+        assert_eq!(state.get_output().unwrap(), None);
+
+        state
+            .apply_batch(
+                &[Op::Insert, Op::Insert],
+                None,
+                &[&I64Array::from_iter([Some(0b001), Some(0b010)]).into()],
+            )
+            .unwrap();
+        assert_eq!(state.get_output().unwrap().unwrap().into_int64(), 0b011);
+
+        state
+            .apply_batch(
+                &[Op::Delete],
+                None,
+                &[&I64Array::from_iter([Some(0b010)]).into()],
+            )
+            .unwrap();
+        assert_eq!(state.get_output().unwrap().unwrap().into_int64(), 0b001);
+    }
+}
@@ -0,0 +1,263 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This module implements `StreamingBoolAndAgg` and `StreamingBoolOrAgg`.
+
+use risingwave_common::array::stream_chunk::Ops;
+use risingwave_common::array::*;
+use risingwave_common::buffer::Bitmap;
+use risingwave_common::types::{Datum, ScalarImpl};
+use risingwave_common::util::iter_util::ZipEqFast;
+
+use super::StreamingAggImpl;
+use crate::executor::error::StreamExecutorResult;
+
+/// Counts of non-null `true`/`false` values seen so far, shared by both
+/// [`StreamingBoolAndAgg`] and [`StreamingBoolOrAgg`] since both need to be able to retract a
+/// previously-seen value, which a single running boolean result can't support.
+#[derive(Clone, Debug, Default)]
+struct BoolCounts {
+    count_true: i64,
+    count_false: i64,
+}
+
+impl BoolCounts {
+    fn apply_batch(
+        &mut self,
+        ops: Ops<'_>,
+        visibility: Option<&Bitmap>,
+        data: &BoolArray,
+    ) -> StreamExecutorResult<()> {
+        let mut apply_one = |op: Op, value: Option<bool>| {
+            let delta = match op {
+                Op::Insert | Op::UpdateInsert => 1,
+                Op::Delete | Op::UpdateDelete => -1,
+            };
+            match value {
+                Some(true) => self.count_true += delta,
+                Some(false) => self.count_false += delta,
+                None => {}
+            }
+        };
+        match visibility {
+            None => {
+                for (op, value) in ops.iter().zip_eq_fast(data.iter()) {
+                    apply_one(op, value);
+                }
+            }
+            Some(visibility) => {
+                for ((op, value), visible) in
+                    ops.iter().zip_eq_fast(data.iter()).zip_eq_fast(visibility.iter())
+                {
+                    if visible {
+                        apply_one(op, value);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn reset(&mut self) {
+        self.count_true = 0;
+        self.count_false = 0;
+    }
+
+    /// Seed the counters from a previously persisted output value. Only the boundary that was
+    /// actually crossed is recoverable this way (same caveat as `StreamingRowCountAgg`'s
+    /// datum-based restore), so we credit the single count that would have produced it.
+    fn from_datum(datum: Datum) -> Self {
+        let mut counts = Self::default();
+        match datum {
+            Some(ScalarImpl::Bool(true)) => counts.count_true = 1,
+            Some(ScalarImpl::Bool(false)) => counts.count_false = 1,
+            Some(other) => panic!(
+                "type mismatch in streaming aggregator init: expected bool, get {}",
+                other.get_ident()
+            ),
+            None => {}
+        }
+        counts
+    }
+}
+
+/// `StreamingBoolAndAgg` returns true iff all non-null inputs seen are true, mirroring
+/// `bool_and`/`every`. It tracks counters rather than a running boolean so that retracting a
+/// previously-seen `false` can correctly flip the result back to true.
+#[derive(Clone, Debug, Default)]
+pub struct StreamingBoolAndAgg {
+    counts: BoolCounts,
+}
+
+impl StreamingBoolAndAgg {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_datum(datum: Datum) -> StreamExecutorResult<Self> {
+        Ok(Self {
+            counts: BoolCounts::from_datum(datum),
+        })
+    }
+}
+
+impl StreamingAggImpl for StreamingBoolAndAgg {
+    fn apply_batch(
+        &mut self,
+        ops: Ops<'_>,
+        visibility: Option<&Bitmap>,
+        data: &[&ArrayImpl],
+    ) -> StreamExecutorResult<()> {
+        self.counts.apply_batch(ops, visibility, data[0].into())
+    }
+
+    fn get_output(&self) -> StreamExecutorResult<Datum> {
+        Ok(if self.counts.count_false > 0 {
+            Some(false.into())
+        } else if self.counts.count_true > 0 {
+            Some(true.into())
+        } else {
+            None
+        })
+    }
+
+    fn new_builder(&self) -> ArrayBuilderImpl {
+        ArrayBuilderImpl::Bool(BoolArrayBuilder::new(0))
+    }
+
+    fn reset(&mut self) {
+        self.counts.reset();
+    }
+}
+
+/// `StreamingBoolOrAgg` returns true iff any non-null input seen is true, mirroring `bool_or`.
+/// Like [`StreamingBoolAndAgg`], it tracks counters to support retraction.
+#[derive(Clone, Debug, Default)]
+pub struct StreamingBoolOrAgg {
+    counts: BoolCounts,
+}
+
+impl StreamingBoolOrAgg {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_datum(datum: Datum) -> StreamExecutorResult<Self> {
+        Ok(Self {
+            counts: BoolCounts::from_datum(datum),
+        })
+    }
+}
+
+impl StreamingAggImpl for StreamingBoolOrAgg {
+    fn apply_batch(
+        &mut self,
+        ops: Ops<'_>,
+        visibility: Option<&Bitmap>,
+        data: &[&ArrayImpl],
+    ) -> StreamExecutorResult<()> {
+        self.counts.apply_batch(ops, visibility, data[0].into())
+    }
+
+    fn get_output(&self) -> StreamExecutorResult<Datum> {
+        Ok(if self.counts.count_true > 0 {
+            Some(true.into())
+        } else if self.counts.count_false > 0 {
+            Some(false.into())
+        } else {
+            None
+        })
+    }
+
+    fn new_builder(&self) -> ArrayBuilderImpl {
+        ArrayBuilderImpl::Bool(BoolArrayBuilder::new(0))
+    }
+
+    fn reset(&mut self) {
+        self.counts.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bool_and_agg() {
+        let mut state = StreamingBoolAndAgg::new();
+        assert_eq!(state.get_output().unwrap(), None);
+
+        state
+            .apply_batch(
+                &[Op::Insert, Op::Insert],
+                None,
+                &[&BoolArray::from_iter([Some(true), Some(true)]).into()],
+            )
+            .unwrap();
+        assert_eq!(state.get_output().unwrap().unwrap().into_bool(), true);
+
+        state
+            .apply_batch(
+                &[Op::Insert],
+                None,
+                &[&BoolArray::from_iter([Some(false)]).into()],
+            )
+            .unwrap();
+        assert_eq!(state.get_output().unwrap().unwrap().into_bool(), false);
+
+        // retracting the `false` row should flip the result back to `true`.
+        state
+            .apply_batch(
+                &[Op::Delete],
+                None,
+                &[&BoolArray::from_iter([Some(false)]).into()],
+            )
+            .unwrap();
+        assert_eq!(state.get_output().unwrap().unwrap().into_bool(), true);
+    }
+
+    #[test]
+    fn test_bool_or_agg() {
+        let mut state = StreamingBoolOrAgg::new();
+        assert_eq!(state.get_output().unwrap(), None);
+
+        state
+            .apply_batch(
+                &[Op::Insert, Op::Insert],
+                None,
+                &[&BoolArray::from_iter([Some(false), Some(false)]).into()],
+            )
+            .unwrap();
+        assert_eq!(state.get_output().unwrap().unwrap().into_bool(), false);
+
+        state
+            .apply_batch(
+                &[Op::Insert],
+                None,
+                &[&BoolArray::from_iter([Some(true)]).into()],
+            )
+            .unwrap();
+        assert_eq!(state.get_output().unwrap().unwrap().into_bool(), true);
+
+        // retracting the `true` row should flip the result back to `false`.
+        state
+            .apply_batch(
+                &[Op::Delete],
+                None,
+                &[&BoolArray::from_iter([Some(true)]).into()],
+            )
+            .unwrap();
+        assert_eq!(state.get_output().unwrap().unwrap().into_bool(), false);
+    }
+}
@@ -262,6 +262,40 @@ where
     }
 }
 
+/// `Xorable` xors all seen values together. Unlike [`Minimizable`]/[`Maximizable`], XOR is its
+/// own inverse, so `retract` is simply `accumulate` again.
+#[derive(Debug)]
+pub struct Xorable<S>
+where
+    S: Scalar + std::ops::BitXor<Output = S>,
+{
+    _phantom: PhantomData<S>,
+}
+
+impl<S> StreamingFoldable<S, S> for Xorable<S>
+where
+    S: Scalar + std::ops::BitXor<Output = S>,
+{
+    fn accumulate(
+        result: Option<&S>,
+        input: Option<S::ScalarRefType<'_>>,
+    ) -> StreamExecutorResult<Option<S>> {
+        Ok(match (result, input) {
+            (Some(x), Some(y)) => Some(x.clone() ^ y.to_owned_scalar()),
+            (None, Some(y)) => Some(y.to_owned_scalar()),
+            (Some(x), None) => Some(x.clone()),
+            (None, None) => None,
+        })
+    }
+
+    fn retract(
+        result: Option<&S>,
+        input: Option<S::ScalarRefType<'_>>,
+    ) -> StreamExecutorResult<Option<S>> {
+        Self::accumulate(result, input)
+    }
+}
+
 impl<R, I, S> StreamingAggInput<I> for StreamingFoldAgg<R, I, S>
 where
     R: Array,
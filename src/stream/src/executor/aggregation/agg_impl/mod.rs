@@ -20,6 +20,8 @@ use std::any::Any;
 pub use approx_count_distinct::*;
 pub use approx_distinct_append::AppendOnlyStreamingApproxCountDistinct;
 use approx_distinct_utils::StreamingApproxCountDistinct;
+pub use bit_agg::*;
+pub use bool_agg::*;
 use dyn_clone::DynClone;
 pub use foldable::*;
 use risingwave_common::array::stream_chunk::Ops;
@@ -39,6 +41,8 @@ use crate::executor::{StreamExecutorError, StreamExecutorResult};
 mod approx_count_distinct;
 mod approx_distinct_append;
 mod approx_distinct_utils;
+mod bit_agg;
+mod bool_agg;
 mod foldable;
 mod row_count;
 
@@ -98,6 +102,10 @@ type StreamingMinAgg<S> = StreamingFoldAgg<S, S, Minimizable<<S as Array>::Owned
 /// `StreamingMaxAgg` get maximum data of the same type.
 type StreamingMaxAgg<S> = StreamingFoldAgg<S, S, Maximizable<<S as Array>::OwnedItem>>;
 
+/// `StreamingBitXorAgg` xors data of the same type. Unlike `BitAnd`/`BitOr`, XOR is its own
+/// inverse, so it needs no extra counter state to support retraction.
+type StreamingBitXorAgg<S> = StreamingFoldAgg<S, S, Xorable<<S as Array>::OwnedItem>>;
+
 /// [postgresql specification of aggregate functions](https://www.postgresql.org/docs/13/functions-aggregate.html)
 /// Most of the general-purpose aggregate functions have one input except for:
 /// 1. `count(*) -> bigint`. The input type of count(*)
@@ -226,6 +234,20 @@ pub fn create_streaming_agg_impl(
                     (Max, timestamptz, timestamptz, StreamingMaxAgg::<I64Array>),
                     (Max, varchar, varchar, StreamingMaxAgg::<Utf8Array>),
                     (Max, bytea, bytea, StreamingMaxAgg::<BytesArray>),
+                    // BoolAnd/BoolOr
+                    (BoolAnd, boolean, boolean, StreamingBoolAndAgg),
+                    (BoolOr, boolean, boolean, StreamingBoolOrAgg),
+                    // BitAnd/BitOr (need per-bit counters to support retraction)
+                    (BitAnd, int16, int16, StreamingBitAndAgg16),
+                    (BitAnd, int32, int32, StreamingBitAndAgg32),
+                    (BitAnd, int64, int64, StreamingBitAndAgg64),
+                    (BitOr, int16, int16, StreamingBitOrAgg16),
+                    (BitOr, int32, int32, StreamingBitOrAgg32),
+                    (BitOr, int64, int64, StreamingBitOrAgg64),
+                    // BitXor is its own inverse, so the generic retractable fold suffices.
+                    (BitXor, int16, int16, StreamingBitXorAgg::<I16Array>),
+                    (BitXor, int32, int32, StreamingBitXorAgg::<I32Array>),
+                    (BitXor, int64, int64, StreamingBitXorAgg::<I64Array>),
                 ]
             )
         }
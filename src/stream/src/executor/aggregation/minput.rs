@@ -32,6 +32,7 @@ use risingwave_storage::StateStore;
 use super::agg_state_cache::{AggStateCache, GenericAggStateCache, StateCacheInputBatch};
 use super::minput_agg_impl::array_agg::ArrayAgg;
 use super::minput_agg_impl::extreme::ExtremeAgg;
+use super::minput_agg_impl::ordered_set::{ModeAgg, PercentileContAgg, PercentileDiscAgg};
 use super::minput_agg_impl::string_agg::StringAgg;
 use super::AggCall;
 use crate::common::cache::{OrderedStateCache, TopNStateCache};
@@ -86,6 +87,14 @@ impl<S: StateStore> MaterializedInputState<S> {
                     OrderType::descending()
                 };
                 (vec![arg_col_indices[0]], vec![order_type])
+            } else if agg_call.kind == AggKind::LastValue {
+                // `last_value` picks the last row in the given order, which is the same as
+                // picking the first row (via `ExtremeAgg`) in the reverse order.
+                agg_call
+                    .column_orders
+                    .iter()
+                    .map(|p| (p.column_index, p.order_type.reverse()))
+                    .unzip()
             } else {
                 agg_call
                     .column_orders
@@ -125,7 +134,7 @@ impl<S: StateStore> MaterializedInputState<S> {
         let cache_key_serializer = OrderedRowSerde::new(cache_key_data_types, order_types);
 
         let cache: Box<dyn AggStateCache + Send + Sync> = match agg_call.kind {
-            AggKind::Min | AggKind::Max | AggKind::FirstValue => Box::new(
+            AggKind::Min | AggKind::Max | AggKind::FirstValue | AggKind::LastValue => Box::new(
                 GenericAggStateCache::new(TopNStateCache::new(extreme_cache_size), ExtremeAgg),
             ),
             AggKind::StringAgg => Box::new(GenericAggStateCache::new(
@@ -136,6 +145,17 @@ impl<S: StateStore> MaterializedInputState<S> {
                 OrderedStateCache::new(),
                 ArrayAgg,
             )),
+            AggKind::Mode => {
+                Box::new(GenericAggStateCache::new(OrderedStateCache::new(), ModeAgg))
+            }
+            AggKind::PercentileCont => Box::new(GenericAggStateCache::new(
+                OrderedStateCache::new(),
+                PercentileContAgg,
+            )),
+            AggKind::PercentileDisc => Box::new(GenericAggStateCache::new(
+                OrderedStateCache::new(),
+                PercentileDiscAgg,
+            )),
             _ => panic!(
                 "Agg kind `{}` is not expected to have materialized input state",
                 agg_call.kind
@@ -17,6 +17,7 @@ use smallvec::SmallVec;
 
 pub mod array_agg;
 pub mod extreme;
+pub mod ordered_set;
 pub mod string_agg;
 
 /// Trait that defines aggregators that aggregate over an iterator of cached values.
@@ -0,0 +1,127 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use num_traits::ToPrimitive;
+use risingwave_common::types::{Datum, DatumRef, ScalarImpl, ScalarRefImpl};
+use smallvec::SmallVec;
+
+use super::MInputAggregator;
+
+/// Aggregator for `mode() WITHIN GROUP (ORDER BY ..)`: the most frequent non-null value.
+///
+/// The cache iterates values in sorted order, so equal values always form a contiguous run;
+/// this lets us find the run with the most elements in a single pass instead of hashing.
+pub struct ModeAgg;
+
+impl MInputAggregator for ModeAgg {
+    type Value = Datum;
+
+    fn convert_cache_value(&self, value: SmallVec<[DatumRef<'_>; 2]>) -> Self::Value {
+        value[0].map(ScalarRefImpl::into_scalar_impl)
+    }
+
+    fn aggregate<'a>(&'a self, values: impl Iterator<Item = &'a Self::Value>) -> Datum {
+        values
+            .filter(|v| v.is_some())
+            .group_by(|v| (*v).clone())
+            .into_iter()
+            .map(|(v, group)| (v, group.count()))
+            .max_by_key(|(_, count)| *count)
+            .and_then(|(v, _)| v)
+    }
+}
+
+/// A value cached for ordered-set aggregates that take a constant fraction argument, i.e.
+/// `percentile_cont`/`percentile_disc`. The fraction is bound once but, like `string_agg`'s
+/// delimiter, is carried alongside every cached row for simplicity.
+pub struct OrderedSetAggData {
+    value: Datum,
+    fraction: f64,
+}
+
+fn convert_ordered_set_value(value: SmallVec<[DatumRef<'_>; 2]>) -> OrderedSetAggData {
+    OrderedSetAggData {
+        value: value[0].map(ScalarRefImpl::into_scalar_impl),
+        fraction: value[1]
+            .map(|d| d.into_float64().into_inner())
+            .unwrap_or_default(),
+    }
+}
+
+fn scalar_to_f64(scalar: &ScalarImpl) -> Option<f64> {
+    match scalar {
+        ScalarImpl::Int16(v) => v.to_f64(),
+        ScalarImpl::Int32(v) => v.to_f64(),
+        ScalarImpl::Int64(v) => v.to_f64(),
+        ScalarImpl::Decimal(v) => v.to_f64(),
+        ScalarImpl::Float32(v) => Some(v.into_inner() as f64),
+        ScalarImpl::Float64(v) => Some(v.into_inner()),
+        _ => None,
+    }
+}
+
+/// Aggregator for `percentile_disc(fraction) WITHIN GROUP (ORDER BY ..)`: picks the smallest
+/// value whose rank (1-based) is at least `ceil(fraction * n)`, i.e. the nearest-rank value.
+pub struct PercentileDiscAgg;
+
+impl MInputAggregator for PercentileDiscAgg {
+    type Value = OrderedSetAggData;
+
+    fn convert_cache_value(&self, value: SmallVec<[DatumRef<'_>; 2]>) -> Self::Value {
+        convert_ordered_set_value(value)
+    }
+
+    fn aggregate<'a>(&'a self, values: impl Iterator<Item = &'a Self::Value>) -> Datum {
+        let values = values.collect_vec();
+        let n = values.len();
+        if n == 0 {
+            return None;
+        }
+        let fraction = values[0].fraction;
+        let rank = ((fraction * n as f64).ceil() as usize).clamp(1, n);
+        values[rank - 1].value.clone()
+    }
+}
+
+/// Aggregator for `percentile_cont(fraction) WITHIN GROUP (ORDER BY ..)`: linearly interpolates
+/// between the two nearest ranks.
+pub struct PercentileContAgg;
+
+impl MInputAggregator for PercentileContAgg {
+    type Value = OrderedSetAggData;
+
+    fn convert_cache_value(&self, value: SmallVec<[DatumRef<'_>; 2]>) -> Self::Value {
+        convert_ordered_set_value(value)
+    }
+
+    fn aggregate<'a>(&'a self, values: impl Iterator<Item = &'a Self::Value>) -> Datum {
+        let values = values.collect_vec();
+        let n = values.len();
+        if n == 0 {
+            return None;
+        }
+        let fraction = values[0].fraction;
+        let rank = fraction * (n - 1) as f64;
+        let lo = rank.floor() as usize;
+        let hi = (rank.ceil() as usize).min(n - 1);
+        let lo_val = values[lo].value.as_ref().and_then(scalar_to_f64)?;
+        if lo == hi {
+            return Some(ScalarImpl::Float64(lo_val.into()));
+        }
+        let hi_val = values[hi].value.as_ref().and_then(scalar_to_f64)?;
+        let interpolated = lo_val + (hi_val - lo_val) * (rank - lo as f64);
+        Some(ScalarImpl::Float64(interpolated.into()))
+    }
+}
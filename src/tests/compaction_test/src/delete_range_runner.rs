@@ -142,12 +142,14 @@ async fn compaction_test(
         parent_id: 0,
         member_table_ids: vec![1],
         compaction_config: Some(compaction_config.clone()),
+        write_throughput: 0,
     };
     let group2 = CompactionGroupInfo {
         id: StaticCompactionGroupId::MaterializedView as _,
         parent_id: 0,
         member_table_ids: vec![2],
         compaction_config: Some(compaction_config.clone()),
+        write_throughput: 0,
     };
     hummock_manager_ref
         .init_metadata_for_version_replay(
@@ -557,6 +559,7 @@ fn run_compactor_thread(
         compaction_executor: Arc::new(CompactionExecutor::new(None)),
         filter_key_extractor_manager,
         read_memory_limiter: MemoryLimiter::unlimit(),
+        memory_limiter: MemoryLimiter::unlimit(),
         sstable_object_id_manager,
         task_progress_manager: Default::default(),
         compactor_runtime_config: Arc::new(tokio::sync::Mutex::new(CompactorRuntimeConfig {
@@ -71,6 +71,7 @@ impl TestSuite {
         self.max_row().await?;
         self.multiple_on_going_portal().await?;
         self.create_with_parameter().await?;
+        self.null_param().await?;
         Ok(())
     }
 
@@ -374,4 +375,18 @@ impl TestSuite {
 
         Ok(())
     }
+
+    // A NULL parameter should bind without a provided type hint and keep its SQL NULL-ness
+    // through the typed placeholder cast.
+    async fn null_param(&self) -> anyhow::Result<()> {
+        let client = self.create_client().await?;
+
+        let none: Option<i32> = None;
+        for row in client.query("select $1::INT;", &[&none]).await? {
+            let data: Option<i32> = row.try_get(0)?;
+            test_eq!(data, None);
+        }
+
+        Ok(())
+    }
 }
@@ -496,6 +496,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                 }
             }
             A::FirstValue => None,
+            A::LastValue => None,
             A::ApproxCountDistinct => {
                 if self.is_distinct_allowed {
                     None
@@ -621,6 +622,7 @@ fn make_simple_func(func_name: &str, exprs: &[Expr]) -> Function {
         distinct: false,
         order_by: vec![],
         filter: None,
+        within_group: None,
     }
 }
 
@@ -645,6 +647,7 @@ fn make_agg_func(
         distinct,
         order_by,
         filter,
+        within_group: None,
     }
 }
 
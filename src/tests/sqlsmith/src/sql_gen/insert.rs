@@ -42,6 +42,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             table_name,
             columns: vec![],
             source: Box::new(source),
+            on_conflict: None,
             returning: vec![],
         }
     }
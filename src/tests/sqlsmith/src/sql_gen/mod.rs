@@ -185,6 +185,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
         let mview = Statement::CreateView {
             or_replace: false,
             materialized: true,
+            temporary: false,
             name,
             columns: vec![],
             query,
@@ -74,7 +74,7 @@ pub(crate) fn create_table_factor_from_table(table: &Table) -> TableFactor {
     TableFactor::Table {
         name: ObjectName(vec![Ident::new_unchecked(&table.name)]),
         alias: None,
-        for_system_time_as_of_now: false,
+        as_of: None,
     }
 }
 
@@ -31,8 +31,15 @@ pub struct ArrowFlightUdfClient {
 #[cfg(not(madsim))]
 impl ArrowFlightUdfClient {
     /// Connect to a UDF service.
+    ///
+    /// `addr` may use the `grpc://` scheme (as written in `CREATE FUNCTION ... USING LINK`) in
+    /// addition to the `http://`/`https://` schemes that the underlying gRPC transport expects.
     pub async fn connect(addr: &str) -> Result<Self> {
-        let client = FlightServiceClient::connect(addr.to_string()).await?;
+        let addr = match addr.strip_prefix("grpc://") {
+            Some(rest) => format!("http://{rest}"),
+            None => addr.to_string(),
+        };
+        let client = FlightServiceClient::connect(addr).await?;
         Ok(Self { client })
     }
 
@@ -24,7 +24,7 @@ use bytes::{Bytes, BytesMut};
 use futures::stream::StreamExt;
 use futures::Stream;
 use itertools::Itertools;
-use openssl::ssl::{SslAcceptor, SslContext, SslContextRef, SslMethod};
+use openssl::ssl::{SslAcceptor, SslContext, SslContextRef, SslMethod, SslVerifyMode};
 use risingwave_common::types::DataType;
 use risingwave_sqlparser::parser::Parser;
 use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
@@ -83,6 +83,9 @@ pub struct TlsConfig {
     pub cert: PathBuf,
     /// The path to the TLS key.
     pub key: PathBuf,
+    /// The path to the CA certificate used to verify client certificates.
+    /// If set, clients are required to present a certificate signed by this CA.
+    pub ca_cert: Option<PathBuf>,
 }
 
 impl TlsConfig {
@@ -97,8 +100,13 @@ impl TlsConfig {
             // The path is mounted from project root.
             cert: path_to_cur_proj.join(cert),
             key: path_to_cur_proj.join(key),
+            ca_cert: None,
         }
     }
+
+    pub fn new(cert: PathBuf, key: PathBuf, ca_cert: Option<PathBuf>) -> Self {
+        Self { cert, key, ca_cert }
+    }
 }
 
 impl<S, SM, VS, PS, PO> Drop for PgProtocol<S, SM, VS, PS, PO>
@@ -847,6 +855,16 @@ fn build_ssl_ctx_from_config(tls_config: &TlsConfig) -> PsqlResult<SslContext> {
     acceptor
         .set_certificate_chain_file(cert_path)
         .map_err(|e| PsqlError::Internal(e.into()))?;
+
+    if let Some(ca_cert_path) = &tls_config.ca_cert {
+        // A CA certificate was configured, so require and verify client certificates signed by
+        // it, rather than accepting any client as we do by default.
+        acceptor
+            .set_ca_file(ca_cert_path)
+            .map_err(|e| PsqlError::Internal(e.into()))?;
+        acceptor.set_verify(SslVerifyMode::PEER | SslVerifyMode::FAIL_IF_NO_PEER_CERT);
+    }
+
     let acceptor = acceptor.build();
 
     Ok(acceptor.into_context())
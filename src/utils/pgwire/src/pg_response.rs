@@ -73,6 +73,7 @@ pub enum StatementType {
     ALTER_SOURCE,
     ALTER_SYSTEM,
     REVOKE_PRIVILEGE,
+    COMMENT,
     // Introduce ORDER_BY statement type cuz Calcite unvalidated AST has SqlKind.ORDER_BY. Note
     // that Statement Type is not designed to be one to one mapping with SqlKind.
     ORDER_BY,
@@ -83,6 +84,8 @@ pub enum StatementType {
     UPDATE_USER,
     ABORT,
     FLUSH,
+    ANALYZE,
+    CANCEL_JOBS,
     OTHER,
     // EMPTY is used when query statement is empty (e.g. ";").
     EMPTY,
@@ -90,6 +93,8 @@ pub enum StatementType {
     COMMIT,
     ROLLBACK,
     SET_TRANSACTION,
+    DECLARE_CURSOR,
+    CLOSE_CURSOR,
 }
 
 impl std::fmt::Display for StatementType {
@@ -158,6 +163,7 @@ impl StatementType {
             Statement::CreateSource { .. } => Ok(StatementType::CREATE_SOURCE),
             Statement::CreateSink { .. } => Ok(StatementType::CREATE_SINK),
             Statement::CreateFunction { .. } => Ok(StatementType::CREATE_FUNCTION),
+            Statement::CreateAggregate { .. } => Ok(StatementType::CREATE_FUNCTION),
             Statement::CreateDatabase { .. } => Ok(StatementType::CREATE_DATABASE),
             Statement::CreateUser { .. } => Ok(StatementType::CREATE_USER),
             Statement::CreateView { materialized, .. } => {
@@ -201,6 +207,12 @@ impl StatementType {
             },
             Statement::Explain { .. } => Ok(StatementType::EXPLAIN),
             Statement::Flush => Ok(StatementType::FLUSH),
+            Statement::Analyze { .. } => Ok(StatementType::ANALYZE),
+            Statement::CancelJobs(_) => Ok(StatementType::CANCEL_JOBS),
+            Statement::DeclareCursor { .. } => Ok(StatementType::DECLARE_CURSOR),
+            Statement::FetchCursor { .. } => Ok(StatementType::FETCH),
+            Statement::CloseCursor { .. } => Ok(StatementType::CLOSE_CURSOR),
+            Statement::Comment { .. } => Ok(StatementType::COMMENT),
             _ => Err("unsupported statement type".to_string()),
         }
     }
@@ -244,6 +256,7 @@ impl StatementType {
                 | StatementType::INSERT_RETURNING
                 | StatementType::DELETE_RETURNING
                 | StatementType::UPDATE_RETURNING
+                | StatementType::FETCH
         )
     }
 
@@ -370,6 +383,11 @@ where
         self.values_stream.as_mut().expect("no values stream")
     }
 
+    /// Consume `self` and take ownership of the underlying values stream.
+    pub fn into_values_stream(self) -> VS {
+        self.values_stream.expect("no values stream")
+    }
+
     /// Run the callback if there is one.
     ///
     /// This should only be called after the values stream has been exhausted. Multiple calls to